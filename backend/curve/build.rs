@@ -0,0 +1,93 @@
+//! Generates the curve LUT into `OUT_DIR/lut.rs`.
+//!
+//! The sample count comes from the `lut-*` features (1025 by default); the `TOKAMAK_LUT_SAMPLES`,
+//! `TOKAMAK_LUT_X_MAX` and `TOKAMAK_LUT_GEOM_RATIO` environment variables override it, and
+//! `TOKAMAK_LUT_INDEX_BITS` sizes the lookup indexes emitted next to the table (none with the
+//! `lut-binary-search` feature). The
+//! default configuration must reproduce the committed `lut.rs` sample for sample, so host
+//! float drift or a generator change cannot silently move the on-chain curve.
+
+#[path = "generator.rs"]
+#[allow(dead_code)]
+mod generator;
+
+use std::{env, fs, path::PathBuf};
+
+use generator::Config;
+
+/// Sample-count features, smallest first.
+const SAMPLE_FEATURES: [(&str, usize); 3] = [
+    ("CARGO_FEATURE_LUT_257", 257),
+    ("CARGO_FEATURE_LUT_513", 513),
+    ("CARGO_FEATURE_LUT_2049", 2049),
+];
+
+const ENV: [&str; 4] = [
+    "TOKAMAK_LUT_SAMPLES",
+    "TOKAMAK_LUT_X_MAX",
+    "TOKAMAK_LUT_GEOM_RATIO",
+    "TOKAMAK_LUT_INDEX_BITS",
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=generator.rs");
+    println!("cargo:rerun-if-changed=lut.rs");
+    for name in ENV {
+        println!("cargo:rerun-if-env-changed={name}");
+    }
+
+    let config = config().unwrap_or_else(|e| panic!("invalid LUT configuration: {e}"));
+    let lut = generator::generate(&config);
+    if config.same_table(&Config::DEFAULT) {
+        check_committed(&lut);
+    }
+
+    let (slope_lo, slope_hi) = generator::end_slopes(&config);
+    let mut source = generator::render(&lut);
+    source.push_str(&format!(
+        "\n/// Price at the bottom of the table, `σ(-x_max)`, Q0.32.\n\
+         pub(crate) const LUT_SLOPE_LO: u64 = {slope_lo};\n\
+         /// Price at the top of the table, `σ(x_max)`, Q0.32.\n\
+         pub(crate) const LUT_SLOPE_HI: u64 = {slope_hi};\n"
+    ));
+    if env::var_os("CARGO_FEATURE_LUT_BINARY_SEARCH").is_none() {
+        let x: Vec<u64> = lut.x.iter().map(|&x| x as u64).collect();
+        source.push('\n');
+        source.push_str(&generator::render_index(
+            "X",
+            &generator::index(&x, config.index_bits),
+        ));
+        source.push('\n');
+        source.push_str(&generator::render_index(
+            "S",
+            &generator::index(&lut.s, config.index_bits),
+        ));
+    }
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("lut.rs");
+    fs::write(out, source).unwrap();
+}
+
+fn config() -> Result<Config, String> {
+    let mut config = Config::DEFAULT;
+    let mut features = SAMPLE_FEATURES
+        .iter()
+        .filter(|(feature, _)| env::var_os(feature).is_some());
+    if let Some(&(_, samples)) = features.next() {
+        if features.next().is_some() {
+            return Err("enable at most one lut-* feature".into());
+        }
+        config.samples = samples;
+    }
+    config.with_env(|name| env::var(name).ok())
+}
+
+fn check_committed(lut: &generator::Lut) {
+    let committed = fs::read_to_string("lut.rs").expect("read committed lut.rs");
+    let committed = generator::parse(&committed).expect("parse committed lut.rs");
+    if committed.x != lut.x || committed.s != lut.s {
+        panic!(
+            "committed lut.rs does not match the default generator output; \
+             regenerate with `cargo run -p tokamak-curve --bin lutgen --release`"
+        );
+    }
+}
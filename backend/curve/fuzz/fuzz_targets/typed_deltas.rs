@@ -0,0 +1,65 @@
+//! The typed `Delta` helpers agree with the two's complement ones on every input both accept,
+//! and every typed error marks an input the raw helpers would wrap or misread.
+//!
+//! ```bash
+//! cd backend/curve && cargo +nightly fuzz run typed_deltas
+//! ```
+
+#![no_main]
+
+use curve::{
+    consts::{LUT_X_MAX, LUT_X_MIN},
+    delta::Delta,
+    error::CurveError,
+    fixed::Q824,
+    shape::{CurveShape, SHAPES},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, u32, i64, u64, i32)| {
+    let (id, x0, dc, cmax, dx) = input;
+    let shape = SHAPES[id as usize % SHAPES.len()];
+    let x0 = Q824(x0 % (LUT_X_MAX.0 + 1));
+    let s0 = shape.evaluate_cost(x0);
+
+    // Capacity → saturation
+    match shape.try_dx_for_dc(x0, s0, dc.into(), cmax) {
+        Ok((tdx, tds)) => {
+            // A cost delta past i64 reads as the opposite direction in two's complement
+            let tds = tds.map(|s| s.0);
+            if tds.to_signed().is_some() {
+                let (dx, ds) = shape.dx_for_dc(x0, s0, dc as u64, cmax);
+                assert_eq!(tdx.map(|x| x.0).to_twos_complement(), dx);
+                assert_eq!(tds.to_twos_complement(), ds);
+            }
+            assert_eq!(tdx.is_withdrawal(), dc < 0);
+            assert!(tdx.checked_apply(x0).is_ok_and(|x1| x1 <= LUT_X_MAX));
+        }
+        Err(CurveError::ZeroCapacity) => assert_eq!(cmax, 0),
+        Err(CurveError::Overflow) => {
+            let exact = dc.unsigned_abs() as u128 * shape.s_max().0 as u128 / cmax as u128;
+            assert!(exact >= u64::MAX as u128);
+        }
+        Err(err) => panic!("unexpected {err:?}"),
+    }
+
+    // Saturation → capacity
+    let step = Delta::<u32>::from_signed(dx).map(Q824);
+    let x1 = x0.0 as i64 + dx as i64;
+    let domain = LUT_X_MIN.0 as i64..=LUT_X_MAX.0 as i64;
+    match shape.try_dc_for_dx(x0, step, cmax) {
+        Ok(tdc) => {
+            assert!(domain.contains(&x1));
+            if tdc.to_signed().is_some() {
+                assert_eq!(
+                    tdc.to_twos_complement(),
+                    shape.dc_for_dx(x0, dx as u32, cmax)
+                );
+            }
+        }
+        Err(CurveError::OutOfDomain) => {
+            assert!(!domain.contains(&x1));
+        }
+        Err(err) => panic!("unexpected {err:?}"),
+    }
+});
@@ -0,0 +1,333 @@
+//! LUT generator shared by `build.rs` and the `lutgen` binary.
+//!
+//! Generates a lookup table for cumulative sigmoid mass over
+//! `x ∈ [0, 2·x_max]` in fixed-point with an inflection point at `x_max`:
+//! - x: Q8.24 (stored as u32)
+//! - s: Q16.48 (stored as u64)
+//!
+//! The sigmoid is the standard logistic function. We generate it symmetrically
+//! around 0, then shift all x-values by `x_max` to move the inflection point
+//! to the middle of the domain while keeping cumulative cost values unchanged.
+//! Samples are spaced geometrically, densest at the inflection point.
+
+const X_FRAC_BITS: u32 = 24; // Q8.24
+const S_FRAC_BITS: u32 = 48; // Q16.48
+
+/// Table shape: sample count, half-span and geometric spacing ratio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Number of samples; odd, so the table is symmetric with a single sample at the inflection.
+    pub samples: usize,
+    /// Half-span of the domain; the inflection point sits at `x_max`.
+    pub x_max: f64,
+    /// Ratio between consecutive sample gaps moving away from the inflection point.
+    pub geom_ratio: f64,
+    /// Buckets in the lookup indexes, as a power of two; does not change the table.
+    pub index_bits: u32,
+}
+
+impl Config {
+    /// The committed on-chain table.
+    pub const DEFAULT: Self = Self {
+        samples: 1025,
+        x_max: 3.0,
+        geom_ratio: 1.005,
+        index_bits: 8,
+    };
+
+    /// Apply `TOKAMAK_LUT_SAMPLES`, `TOKAMAK_LUT_X_MAX` and `TOKAMAK_LUT_GEOM_RATIO` overrides.
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        fn parsed<T: std::str::FromStr>(name: &str, value: String) -> Result<T, String> {
+            value
+                .trim()
+                .parse()
+                .map_err(|_| format!("{name}: cannot parse {value:?}"))
+        }
+        if let Some(v) = var("TOKAMAK_LUT_SAMPLES") {
+            self.samples = parsed("TOKAMAK_LUT_SAMPLES", v)?;
+        }
+        if let Some(v) = var("TOKAMAK_LUT_X_MAX") {
+            self.x_max = parsed("TOKAMAK_LUT_X_MAX", v)?;
+        }
+        if let Some(v) = var("TOKAMAK_LUT_GEOM_RATIO") {
+            self.geom_ratio = parsed("TOKAMAK_LUT_GEOM_RATIO", v)?;
+        }
+        if let Some(v) = var("TOKAMAK_LUT_INDEX_BITS") {
+            self.index_bits = parsed("TOKAMAK_LUT_INDEX_BITS", v)?;
+        }
+        self.validate().map(|()| self)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.samples < 3 || self.samples.is_multiple_of(2) {
+            return Err(format!(
+                "sample count must be odd and >= 3, got {}",
+                self.samples
+            ));
+        }
+        if !(self.x_max > 0.0 && self.x_max < 64.0) {
+            return Err(format!("x_max must be in (0, 64), got {}", self.x_max));
+        }
+        if self.geom_ratio.is_nan() || self.geom_ratio < 1.0 {
+            return Err(format!("geom_ratio must be >= 1, got {}", self.geom_ratio));
+        }
+        if !(1..=16).contains(&self.index_bits) {
+            return Err(format!(
+                "index_bits must be in [1, 16], got {}",
+                self.index_bits
+            ));
+        }
+        if self.samples > u16::MAX as usize {
+            return Err(format!(
+                "sample count must fit the u16 index, got {}",
+                self.samples
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether both configurations generate the same samples (the index may differ).
+    pub fn same_table(&self, other: &Self) -> bool {
+        self.samples == other.samples
+            && self.x_max == other.x_max
+            && self.geom_ratio == other.geom_ratio
+    }
+}
+
+/// Generated table: strictly increasing `x` with the cumulative cost `s` at each sample.
+pub struct Lut {
+    pub x: Vec<u32>,
+    pub s: Vec<u64>,
+}
+
+/// Numerically stable softplus used for the cumulative sigmoid integral.
+fn softplus(x: f64) -> f64 {
+    if x > 0.0 {
+        x + (1.0 + (-x).exp()).ln()
+    } else {
+        (1.0 + x.exp()).ln()
+    }
+}
+
+/// Logistic price `σ(x)`: the slope of the cumulative cost.
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Convert a floating-point value to unsigned Q16.48.
+fn to_q16_48(v: f64) -> u64 {
+    let scaled = (v * ((1u128 << S_FRAC_BITS) as f64)).round();
+    if scaled <= 0.0 {
+        0
+    } else if scaled >= u64::MAX as f64 {
+        u64::MAX
+    } else {
+        scaled as u64
+    }
+}
+
+fn to_q8_24(v: f64) -> i32 {
+    (v * ((1u64 << X_FRAC_BITS) as f64)).round() as i32
+}
+
+/// Geometric spacing from 0 to `x_max`, inclusive.
+fn geometric_distance(config: &Config, i: usize, count: usize) -> f64 {
+    if i == 0 {
+        return 0.0;
+    }
+    if i == count - 1 {
+        return config.x_max;
+    }
+    if config.geom_ratio == 1.0 {
+        return config.x_max * i as f64 / (count - 1) as f64;
+    }
+    let num = config.geom_ratio.powi(i as i32) - 1.0;
+    let den = config.geom_ratio.powi((count - 1) as i32) - 1.0;
+    config.x_max * (num / den)
+}
+
+/// Build the strictly increasing non-negative grid in Q8.24, as i32.
+fn build_positive_x_grid(config: &Config) -> Vec<i32> {
+    // samples = 2 * (half - 1) + 1  =>  half = (samples + 1) / 2
+    let half = config.samples.div_ceil(2);
+    let xmax_q = to_q8_24(config.x_max);
+
+    let mut grid = Vec::with_capacity(half);
+    grid.push(0);
+
+    let mut prev = 0i32;
+    for i in 1..(half - 1) {
+        let d = geometric_distance(config, i, half);
+        let mut x_q = to_q8_24(d);
+
+        // Enforce strict monotonicity after rounding.
+        if x_q <= prev {
+            x_q = prev.saturating_add(1);
+        }
+        if x_q >= xmax_q {
+            x_q = xmax_q - 1;
+        }
+
+        grid.push(x_q);
+        prev = x_q;
+    }
+    grid.push(xmax_q);
+
+    for w in grid.windows(2) {
+        assert!(w[0] < w[1], "x grid is not strictly increasing");
+    }
+
+    grid
+}
+
+/// Generate the table for `config`.
+pub fn generate(config: &Config) -> Lut {
+    let positive = build_positive_x_grid(config);
+
+    // Mirror around zero: negatives (excluding 0), 0, positives (excluding 0).
+    let mut full_x_grid: Vec<i32> = Vec::with_capacity(config.samples);
+    for &x in positive.iter().skip(1).rev() {
+        full_x_grid.push(-x);
+    }
+    full_x_grid.push(0);
+    full_x_grid.extend(positive.iter().skip(1).copied());
+    assert_eq!(full_x_grid.len(), config.samples);
+
+    // Baseline so cumulative cost starts at zero at -x_max.
+    let s_baseline = softplus(-config.x_max);
+
+    // Shift x-values by x_max (move inflection point from 0 to x_max)
+    let x_offset_q = to_q8_24(config.x_max);
+    let x = full_x_grid
+        .iter()
+        .map(|&x| (x + x_offset_q) as u32)
+        .collect();
+    let s = full_x_grid
+        .iter()
+        .map(|&x_q| {
+            let x = (x_q as f64) / ((1u64 << X_FRAC_BITS) as f64);
+            to_q16_48(softplus(x) - s_baseline)
+        })
+        .collect();
+    Lut { x, s }
+}
+
+/// Price at the table ends (`σ(-x_max)`, `σ(x_max)`) as Q0.32, used to extend the curve
+/// linearly past the table.
+pub fn end_slopes(config: &Config) -> (u64, u64) {
+    let q32 = |v: f64| (v * (1u64 << 32) as f64).round() as u64;
+    (q32(logistic(-config.x_max)), q32(logistic(config.x_max)))
+}
+
+/// Worst absolute error of linear interpolation against the exact curve, probed at every
+/// segment midpoint, as a fraction of the full cumulative span.
+pub fn max_interpolation_error(config: &Config, lut: &Lut) -> f64 {
+    let scale = (1u64 << X_FRAC_BITS) as f64;
+    let s_scale = (1u128 << S_FRAC_BITS) as f64;
+    let s_baseline = softplus(-config.x_max);
+    let span = softplus(config.x_max) - s_baseline;
+    let mut worst = 0.0f64;
+    for i in 1..lut.x.len() {
+        let (x0, x1) = (lut.x[i - 1] as f64 / scale, lut.x[i] as f64 / scale);
+        let (s0, s1) = (lut.s[i - 1] as f64 / s_scale, lut.s[i] as f64 / s_scale);
+        let mid = (x0 + x1) / 2.0;
+        let exact = softplus(mid - config.x_max) - s_baseline;
+        worst = worst.max(((s0 + s1) / 2.0 - exact).abs() / span);
+    }
+    worst
+}
+
+/// Uniform-grid index over a sorted table: value `v` falls in bucket `v >> shift`, and
+/// `segments[b]` is the table segment holding the first value of bucket `b`. A value in bucket
+/// `b` therefore lies within segments `segments[b]..=segments[b + 1]`.
+pub struct Index {
+    pub shift: u32,
+    pub segments: Vec<u16>,
+}
+
+/// Index `values` (strictly increasing) with about `2^bits` buckets across its range.
+pub fn index(values: &[u64], bits: u32) -> Index {
+    let max = *values.last().unwrap();
+    let shift = (u64::BITS - max.leading_zeros()).saturating_sub(bits);
+    let buckets = (max >> shift) as usize + 2;
+    let last_segment = values.len() - 2;
+    let segments = (0..buckets)
+        .map(|b| {
+            let start = (b as u64) << shift;
+            let segment = values.partition_point(|&v| v <= start).saturating_sub(1);
+            segment.min(last_segment) as u16
+        })
+        .collect();
+    Index { shift, segments }
+}
+
+/// Comparisons a binary search over `len` sorted values needs at most.
+pub fn bisect_probes(len: usize) -> u32 {
+    usize::BITS - len.leading_zeros()
+}
+
+/// Worst and mean comparisons an indexed lookup needs, over buckets of equal width.
+pub fn indexed_probes(index: &Index) -> (u32, f64) {
+    let probes: Vec<u32> = index
+        .segments
+        .windows(2)
+        .map(|w| bisect_probes((w[1] - w[0]) as usize + 2))
+        .collect();
+    let worst = probes.iter().copied().max().unwrap_or(0);
+    let mean = probes.iter().sum::<u32>() as f64 / probes.len() as f64;
+    (worst, mean)
+}
+
+/// Render an index as Rust source: `{name}_INDEX_SHIFT` and `{name}_INDEX`.
+pub fn render_index(name: &str, index: &Index) -> String {
+    use std::fmt::Write;
+
+    let (shift, n) = (index.shift, index.segments.len());
+    let mut out = format!(
+        "/// `{name}_INDEX` bucket of a value `v`: `v >> {name}_INDEX_SHIFT`.\n\
+         pub(crate) const {name}_INDEX_SHIFT: u32 = {shift};\n\
+         /// First `{name}_LUT` segment of each bucket.\n\
+         pub(crate) static {name}_INDEX: [u16; {n}] = [\n"
+    );
+    for segment in &index.segments {
+        writeln!(out, "    {segment},").unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// Render the table as Rust source: `X_LUT` and `S_LUT` statics, one sample per line.
+pub fn render(lut: &Lut) -> String {
+    use std::fmt::Write;
+
+    let n = lut.x.len();
+    let mut out = String::new();
+    writeln!(out, "pub(crate) static X_LUT: [u32; {n}] = [").unwrap();
+    for x in &lut.x {
+        writeln!(out, "    {x},").unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+    writeln!(out, "pub(crate) static S_LUT: [u64; {n}] = [").unwrap();
+    for s in &lut.s {
+        writeln!(out, "    {s},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+/// Parse a rendered (or rustfmt-formatted) table back into samples.
+pub fn parse(source: &str) -> Option<Lut> {
+    fn values<T: std::str::FromStr>(source: &str, name: &str) -> Option<Vec<T>> {
+        let body = source.split(name).nth(1)?;
+        let body = body.split("= [").nth(1)?.split("];").next()?;
+        body.split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| v.parse().ok())
+            .collect()
+    }
+    Some(Lut {
+        x: values(source, "X_LUT")?,
+        s: values(source, "S_LUT")?,
+    })
+}
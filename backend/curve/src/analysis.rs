@@ -0,0 +1,192 @@
+//! Host-side error analysis of the curve: how far the interpolated LUT strays from the exact
+//! softplus integral, how far conversions drift on a round trip, and whether a deposit
+//! followed by a withdrawal can ever return more capacity than went in.
+//!
+//! Compiled for tests and behind the `analysis` feature; uses `f64` and `std`.
+//! Errors and drifts are measured in raw Q16.48 (cost) and Q8.24 (saturation) units.
+
+use crate::{
+    consts::{LUT_X_MAX, LUT_X_MIN},
+    fixed::Q824,
+    lut::X_LUT,
+    math::evaluate_cost,
+    shape::CurveShape,
+};
+
+const S_SCALE: f64 = (1u64 << 48) as f64;
+
+/// Interpolation error within one LUT segment `[X_LUT[segment], X_LUT[segment + 1]]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SegmentError {
+    pub segment: usize,
+    /// Largest `|evaluate_cost(x) - exact(x)|` probed, in Q16.48 units.
+    pub max_abs: f64,
+    /// Largest absolute error relative to the exact cost (where the exact cost is positive).
+    pub max_rel: f64,
+    /// Saturation of the largest absolute error.
+    pub worst_x: Q824,
+}
+
+/// Worst-case figures across a whole analysis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ErrorBounds {
+    pub max_abs: f64,
+    pub max_rel: f64,
+    /// Segment holding the largest absolute error.
+    pub worst_segment: usize,
+}
+
+/// Round-trip drift of `dx_for_dc` followed by `dc_for_dx`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundTrip {
+    /// Largest `|x_for_s(evaluate_cost(x)) - x|` seen.
+    pub max_x_drift: Q824,
+    /// Largest `|dc_for_dx(x0, dx_for_dc(x0, dc)) - dc|` seen, in capacity units.
+    pub max_dc_drift: u64,
+    /// `(x0, dc)` of the largest capacity drift.
+    pub worst: (Q824, u64),
+}
+
+/// Outcome of the "deposit then withdraw never yields more than deposited" check.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Leak {
+    pub trials: usize,
+    /// Trials whose withdrawal returned more capacity than was deposited.
+    pub violations: usize,
+    /// Largest excess returned, in capacity units.
+    pub max_excess: u64,
+    /// `(x0, dc)` of the largest excess.
+    pub worst: Option<(Q824, u64)>,
+}
+
+impl Leak {
+    /// True if no trial returned more than it deposited.
+    pub fn holds(&self) -> bool {
+        self.violations == 0
+    }
+}
+
+/// Numerically stable softplus: the integral of the logistic price.
+fn softplus(x: f64) -> f64 {
+    if x > 0.0 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
+}
+
+/// Exact cumulative cost of the master sigmoid at `x`, in Q16.48 units.
+pub fn exact_cost(x: Q824) -> f64 {
+    let centre = LUT_X_MAX.to_f64() / 2.0;
+    let x = x.clamp(LUT_X_MIN, LUT_X_MAX).to_f64();
+    (softplus(x - centre) - softplus(-centre)) * S_SCALE
+}
+
+/// Interpolation error of every LUT segment, probed at `probes` evenly spaced interior points
+/// plus both ends.
+pub fn segment_errors(probes: u32) -> Vec<SegmentError> {
+    X_LUT
+        .windows(2)
+        .enumerate()
+        .map(|(segment, w)| {
+            let (x0, x1) = (w[0], w[1]);
+            let mut error = SegmentError {
+                segment,
+                worst_x: Q824(x0),
+                ..Default::default()
+            };
+            for k in 0..=probes + 1 {
+                let x = x0 + ((x1 - x0) as u64 * k as u64 / (probes as u64 + 1)) as u32;
+                let exact = exact_cost(Q824(x));
+                let abs = (evaluate_cost(x) as f64 - exact).abs();
+                if abs > error.max_abs {
+                    error.max_abs = abs;
+                    error.worst_x = Q824(x);
+                }
+                if exact > 0.0 {
+                    error.max_rel = error.max_rel.max(abs / exact);
+                }
+            }
+            error
+        })
+        .collect()
+}
+
+/// Worst interpolation error over all segments.
+pub fn interpolation_bounds(probes: u32) -> ErrorBounds {
+    segment_errors(probes)
+        .iter()
+        .fold(ErrorBounds::default(), |mut bounds, segment| {
+            if segment.max_abs > bounds.max_abs {
+                bounds.max_abs = segment.max_abs;
+                bounds.worst_segment = segment.segment;
+            }
+            bounds.max_rel = bounds.max_rel.max(segment.max_rel);
+            bounds
+        })
+}
+
+/// Capacity worth of one Q8.24 saturation step at the steepest sampled price, rounded up:
+/// the resolution below which capacity round trips cannot be exact.
+pub fn step_value<S: CurveShape>(shape: &S, cmax: u64) -> u64 {
+    const H: u32 = 1 << 12;
+    let cost = |x: u32| shape.evaluate_cost(Q824(x)).0;
+    let steepest = (LUT_X_MIN.0..LUT_X_MAX.0 - H)
+        .step_by(H as usize)
+        .map(|x| cost(x + H) - cost(x))
+        .max()
+        .unwrap_or(0);
+    shape.dc_for_ds(steepest.div_ceil(H as u64), cmax) + 1
+}
+
+/// Round-trip drift for each `(x0, dc)` trial on a curve of capacity `cmax`.
+/// `x0` must lie in the domain.
+pub fn round_trip<S: CurveShape>(
+    shape: &S,
+    cmax: u64,
+    trials: impl IntoIterator<Item = (Q824, u64)>,
+) -> RoundTrip {
+    let mut result = RoundTrip::default();
+    for (x0, dc) in trials {
+        let s0 = shape.evaluate_cost(x0);
+        let x_drift = shape.x_for_s(s0).abs_diff(x0);
+        result.max_x_drift = result.max_x_drift.max(x_drift);
+
+        let (dx, _) = shape.dx_for_dc(x0, s0, dc, cmax);
+        if x0.0.wrapping_add(dx) == LUT_X_MAX.0 {
+            // Clamped at the top of the domain: a partial move, not a round trip
+            continue;
+        }
+        let back = shape.dc_for_dx(x0, dx, cmax);
+        let dc_drift = (back as i64).wrapping_sub(dc as i64).unsigned_abs();
+        if dc_drift > result.max_dc_drift {
+            result.max_dc_drift = dc_drift;
+            result.worst = (x0, dc);
+        }
+    }
+    result
+}
+
+/// Deposit `dc` at `x0`, then withdraw the saturation it bought: the capacity released by
+/// moving back must not exceed `dc`. `x0` must lie in the domain.
+pub fn deposit_withdraw<S: CurveShape>(
+    shape: &S,
+    cmax: u64,
+    trials: impl IntoIterator<Item = (Q824, u64)>,
+) -> Leak {
+    let mut leak = Leak::default();
+    for (x0, dc) in trials {
+        leak.trials += 1;
+        let (dx, _) = shape.dx_for_dc(x0, shape.evaluate_cost(x0), dc, cmax);
+        let x1 = Q824(x0.0.wrapping_add(dx));
+        let returned = shape.dc_for_dx(x1, dx.wrapping_neg(), cmax).wrapping_neg();
+        if returned > dc {
+            leak.violations += 1;
+            if returned - dc > leak.max_excess {
+                leak.max_excess = returned - dc;
+                leak.worst = Some((x0, dc));
+            }
+        }
+    }
+    leak
+}
@@ -0,0 +1,144 @@
+//! Signed deltas along a curve.
+//!
+//! A [`Delta`] carries the direction of a move next to its magnitude, in place of the two's
+//! complement `u32` / `u64` encoding of the raw [`CurveShape`](crate::shape::CurveShape)
+//! helpers. The `try_*` helpers take and return deltas, and applying one is checked: a result
+//! that leaves its type surfaces as a [`CurveError`] instead of wrapping.
+
+use core::ops::Neg;
+
+use crate::{
+    error::CurveError,
+    fixed::{Q1648, Q824},
+};
+
+/// Move along a curve: a capacity (`Delta<u64>`), cost (`Delta<Q1648>`) or saturation
+/// (`Delta<Q824>`) change. A zero move may point either way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Delta<T> {
+    /// Up the curve: capacity in, saturation and cost bought.
+    Deposit(T),
+    /// Down the curve: capacity out, saturation and cost shed.
+    Withdrawal(T),
+}
+
+impl<T: Copy> Delta<T> {
+    /// Size of the move, regardless of direction.
+    #[inline]
+    pub fn magnitude(self) -> T {
+        match self {
+            Self::Deposit(m) | Self::Withdrawal(m) => m,
+        }
+    }
+
+    #[inline]
+    pub fn is_withdrawal(self) -> bool {
+        matches!(self, Self::Withdrawal(_))
+    }
+
+    /// Same direction, magnitude mapped through `f`.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Delta<U> {
+        match self {
+            Self::Deposit(m) => Delta::Deposit(f(m)),
+            Self::Withdrawal(m) => Delta::Withdrawal(f(m)),
+        }
+    }
+}
+
+impl<T: Checked> Delta<T> {
+    /// `base` moved by this delta.
+    #[inline]
+    pub fn checked_apply(self, base: T) -> Result<T, CurveError> {
+        match self {
+            Self::Deposit(m) => base.checked_add(m).ok_or(CurveError::Overflow),
+            Self::Withdrawal(m) => base.checked_sub(m).ok_or(CurveError::Underflow),
+        }
+    }
+}
+
+impl<T> Neg for Delta<T> {
+    type Output = Self;
+
+    /// Same magnitude, opposite direction.
+    fn neg(self) -> Self {
+        match self {
+            Self::Deposit(m) => Self::Withdrawal(m),
+            Self::Withdrawal(m) => Self::Deposit(m),
+        }
+    }
+}
+
+macro_rules! impl_delta {
+    ($unsigned:ty, $signed:ty) => {
+        impl Delta<$unsigned> {
+            /// Delta of a signed value: negative values withdraw.
+            #[inline]
+            pub const fn from_signed(v: $signed) -> Self {
+                match v < 0 {
+                    true => Self::Withdrawal(v.unsigned_abs()),
+                    false => Self::Deposit(v as $unsigned),
+                }
+            }
+
+            /// Signed value, or `None` if the magnitude does not fit the signed type.
+            #[inline]
+            pub fn to_signed(self) -> Option<$signed> {
+                match self {
+                    Self::Deposit(m) => <$signed>::try_from(m).ok(),
+                    Self::Withdrawal(m) => (0 as $signed).checked_sub_unsigned(m),
+                }
+            }
+
+            /// Decode the two's complement encoding of the raw curve helpers.
+            #[inline]
+            pub const fn from_twos_complement(v: $unsigned) -> Self {
+                Self::from_signed(v as $signed)
+            }
+
+            /// Two's complement encoding for the raw curve helpers. Wraps if the magnitude
+            /// does not fit the signed type.
+            #[inline]
+            pub const fn to_twos_complement(self) -> $unsigned {
+                match self {
+                    Self::Deposit(m) => m,
+                    Self::Withdrawal(m) => m.wrapping_neg(),
+                }
+            }
+        }
+
+        impl From<$signed> for Delta<$unsigned> {
+            #[inline]
+            fn from(v: $signed) -> Self {
+                Self::from_signed(v)
+            }
+        }
+    };
+}
+
+impl_delta!(u32, i32);
+impl_delta!(u64, i64);
+
+/// Values a [`Delta`] can move.
+pub trait Checked: Copy {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {$(
+        impl Checked for $t {
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_checked!(u32, u64, Q824, Q1648);
@@ -0,0 +1,31 @@
+//! Typed failures of checked curve arithmetic.
+
+use core::fmt;
+
+/// Why a checked curve conversion or delta application was rejected.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CurveError {
+    /// A result exceeds the range of its type.
+    Overflow,
+    /// A withdrawal exceeds the value it is taken from.
+    Underflow,
+    /// Total capacity is zero, so capacity cannot be mapped onto the curve.
+    ZeroCapacity,
+    /// A saturation lies outside `[LUT_X_MIN, LUT_X_MAX]`.
+    OutOfDomain,
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CurveError::*;
+        let msg = match self {
+            Overflow => "curve delta overflows its type",
+            Underflow => "withdrawal exceeds the value it is taken from",
+            ZeroCapacity => "curve capacity is zero",
+            OutOfDomain => "saturation is outside the curve domain",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for CurveError {}
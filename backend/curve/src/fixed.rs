@@ -0,0 +1,128 @@
+//! Fixed-point value types: saturation (Q8.24) and cumulative cost (Q16.48).
+//!
+//! Both are `#[repr(transparent)]` over their raw bits, so account layouts are unchanged, but
+//! the compiler no longer lets a saturation be added to a cost. Arithmetic stays in the raw
+//! domain: `Add`/`Sub` panic on overflow in debug builds like the integers they wrap; use the
+//! checked or saturating forms where overflow is possible. `from_f64`, `to_f64` and `Display`
+//! are for host-side use.
+
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+/// Fixed-point (8 integer, 24 fractional bits): saturation and shares.
+/// Conversion: `q824 = actual_value * 2^24`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+pub struct Q824(pub u32);
+
+/// Fixed-point (16 integer, 48 fractional bits): cumulative cost and pressure.
+/// Conversion: `q1648 = actual_value * 2^48`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+pub struct Q1648(pub u64);
+
+macro_rules! impl_fixed {
+    ($name:ident, $bits:ty, $frac:expr) => {
+        impl $name {
+            /// Fractional bits.
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self(0);
+            /// 1.0.
+            pub const ONE: Self = Self(1 << $frac);
+            pub const MAX: Self = Self(<$bits>::MAX);
+
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_add(rhs.0) {
+                    Some(v) => Some(Self(v)),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.0.checked_sub(rhs.0) {
+                    Some(v) => Some(Self(v)),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+
+            /// Distance between two values.
+            #[inline]
+            pub const fn abs_diff(self, rhs: Self) -> Self {
+                Self(self.0.abs_diff(rhs.0))
+            }
+
+            /// Nearest representable value; negative and NaN inputs give zero, values past the
+            /// range saturate.
+            pub fn from_f64(v: f64) -> Self {
+                let scaled = v * (1u64 << $frac) as f64 + 0.5;
+                // `as` saturates and maps NaN to zero
+                Self(scaled as $bits)
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        /// Decimal value; honours the formatter's precision (`{:.3}`).
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+
+impl_fixed!(Q824, u32, 24);
+impl_fixed!(Q1648, u64, 48);
@@ -1,8 +1,18 @@
 //! # Curve: Lookup table generation and interpolation for element valuation curves.
+//!
+//! `no_std` unless the `std` feature is enabled, so it builds for SBF as is.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(any(test, feature = "analysis"))]
+pub mod analysis;
 pub mod consts;
+pub mod delta;
+pub mod error;
+pub mod fixed;
 pub mod lut;
 pub mod math;
+pub mod shape;
 
 #[cfg(test)]
 mod tests;
@@ -1,1144 +1,44 @@
-pub(crate) static X_LUT: [u32; 1025] = [
-    0, 271531, 541711, 810548, 1078046, 1344214, 1609057, 1872583, 2134798, 2395709, 2655321,
-    2913641, 3170677, 3426433, 3680918, 3934136, 4186094, 4436799, 4686257, 4934473, 5181455,
-    5427207, 5671738, 5915051, 6157154, 6398053, 6637753, 6876260, 7113581, 7349722, 7584687,
-    7818483, 8051117, 8282593, 8512917, 8742095, 8970133, 9197037, 9422812, 9647463, 9870997,
-    10093419, 10314734, 10534948, 10754067, 10972095, 11189039, 11404903, 11619693, 11833415,
-    12046073, 12257674, 12468222, 12677722, 12886180, 13093601, 13299990, 13505352, 13709692,
-    13913016, 14115328, 14316634, 14516938, 14716245, 14914561, 15111891, 15308238, 15503609,
-    15698008, 15891440, 16083909, 16275421, 16465980, 16655590, 16844258, 17031987, 17218782,
-    17404647, 17589588, 17773609, 17956714, 18138909, 18320197, 18500582, 18680071, 18858666,
-    19036373, 19213196, 19389139, 19564207, 19738404, 19911734, 20084202, 20255812, 20426568,
-    20596474, 20765535, 20933755, 21101139, 21267689, 21433411, 21598308, 21762385, 21925646,
-    22088094, 22249734, 22410570, 22570606, 22729845, 22888293, 23045952, 23202827, 23358921,
-    23514239, 23668783, 23822559, 23975570, 24127820, 24279312, 24430051, 24580040, 24729282,
-    24877782, 25025543, 25172569, 25318864, 25464430, 25609273, 25753395, 25896799, 26039491,
-    26181472, 26322747, 26463320, 26603193, 26742370, 26880854, 27018650, 27155760, 27292188,
-    27427937, 27563011, 27697412, 27831145, 27964213, 28096619, 28228366, 28359457, 28489897,
-    28619687, 28748832, 28877334, 29005197, 29132424, 29259017, 29384981, 29510319, 29635032,
-    29759125, 29882601, 30005463, 30127713, 30249355, 30370392, 30490827, 30610662, 30729902,
-    30848548, 30966603, 31084072, 31200956, 31317259, 31432982, 31548131, 31662706, 31776711,
-    31890149, 32003023, 32115335, 32227089, 32338286, 32448930, 32559024, 32668570, 32777571,
-    32886029, 32993949, 33101331, 33208179, 33314495, 33420282, 33525544, 33630281, 33734497,
-    33838195, 33941377, 34044046, 34146204, 34247853, 34348997, 34449638, 34549778, 34649419,
-    34748565, 34847218, 34945380, 35043054, 35140241, 35236945, 35333168, 35428913, 35524181,
-    35618974, 35713297, 35807150, 35900536, 35993458, 36085917, 36177916, 36269458, 36360544,
-    36451177, 36541359, 36631092, 36720379, 36809222, 36897623, 36985583, 37073107, 37160195,
-    37246849, 37333073, 37418867, 37504235, 37589178, 37673698, 37757798, 37841479, 37924745,
-    38007595, 38090034, 38172063, 38253683, 38334897, 38415708, 38496116, 38576124, 38655734,
-    38734948, 38813768, 38892196, 38970234, 39047883, 39125146, 39202025, 39278521, 39354637,
-    39430374, 39505734, 39580719, 39655332, 39729572, 39803444, 39876948, 39950087, 40022861,
-    40095274, 40167326, 40239020, 40310357, 40381339, 40451968, 40522245, 40592173, 40661753,
-    40730987, 40799877, 40868423, 40936629, 41004495, 41072024, 41139217, 41206075, 41272601,
-    41338796, 41404662, 41470199, 41535411, 41600299, 41664863, 41729106, 41793030, 41856636,
-    41919925, 41982899, 42045560, 42107910, 42169949, 42231679, 42293102, 42354220, 42415034,
-    42475545, 42535755, 42595665, 42655278, 42714594, 42773615, 42832342, 42890777, 42948921,
-    43006776, 43064343, 43121624, 43178619, 43235332, 43291762, 43347911, 43403781, 43459373,
-    43514688, 43569728, 43624495, 43678989, 43733211, 43787164, 43840849, 43894266, 43947418,
-    44000306, 44052930, 44105292, 44157394, 44209237, 44260821, 44312149, 44363222, 44414041,
-    44464607, 44514921, 44564985, 44614800, 44664367, 44713687, 44762762, 44811593, 44860181,
-    44908527, 44956633, 45004499, 45052127, 45099518, 45146674, 45193595, 45240282, 45286737,
-    45332961, 45378955, 45424721, 45470258, 45515569, 45560655, 45605516, 45650154, 45694570,
-    45738765, 45782740, 45826497, 45870035, 45913357, 45956464, 45999356, 46042034, 46084501,
-    46126756, 46168801, 46210636, 46252264, 46293684, 46334899, 46375908, 46416713, 46457315,
-    46497716, 46537915, 46577914, 46617714, 46657317, 46696722, 46735931, 46774945, 46813765,
-    46852392, 46890827, 46929070, 46967124, 47004987, 47042663, 47080151, 47117453, 47154569,
-    47191500, 47228248, 47264812, 47301195, 47337397, 47373419, 47409261, 47444925, 47480412,
-    47515722, 47550857, 47585817, 47620603, 47655215, 47689656, 47723925, 47758024, 47791953,
-    47825713, 47859305, 47892731, 47925990, 47959083, 47992012, 48024777, 48057379, 48089818,
-    48122097, 48154214, 48186172, 48217971, 48249612, 48281096, 48312422, 48343593, 48374609,
-    48405470, 48436178, 48466734, 48497137, 48527389, 48557490, 48587442, 48617244, 48646899,
-    48676405, 48705765, 48734979, 48764048, 48792972, 48821752, 48850389, 48878883, 48907236,
-    48935447, 48963519, 48991450, 49019243, 49046897, 49074414, 49101794, 49129038, 49156146,
-    49183119, 49209958, 49236663, 49263236, 49289677, 49315985, 49342163, 49368211, 49394129,
-    49419919, 49445580, 49471113, 49496519, 49521799, 49546953, 49571982, 49596886, 49621667,
-    49646324, 49670859, 49695271, 49719562, 49743732, 49767782, 49791712, 49815524, 49839216,
-    49862791, 49886249, 49909590, 49932815, 49955924, 49978918, 50001798, 50024564, 50047217,
-    50069757, 50092185, 50114501, 50136706, 50158801, 50180786, 50202661, 50224428, 50246087,
-    50267637, 50289081, 50310417, 50331648, 50352879, 50374215, 50395659, 50417209, 50438868,
-    50460635, 50482510, 50504495, 50526590, 50548795, 50571111, 50593539, 50616079, 50638732,
-    50661498, 50684378, 50707372, 50730481, 50753706, 50777047, 50800505, 50824080, 50847772,
-    50871584, 50895514, 50919564, 50943734, 50968025, 50992437, 51016972, 51041629, 51066410,
-    51091314, 51116343, 51141497, 51166777, 51192183, 51217716, 51243377, 51269167, 51295085,
-    51321133, 51347311, 51373619, 51400060, 51426633, 51453338, 51480177, 51507150, 51534258,
-    51561502, 51588882, 51616399, 51644053, 51671846, 51699777, 51727849, 51756060, 51784413,
-    51812907, 51841544, 51870324, 51899248, 51928317, 51957531, 51986891, 52016397, 52046052,
-    52075854, 52105806, 52135907, 52166159, 52196562, 52227118, 52257826, 52288687, 52319703,
-    52350874, 52382200, 52413684, 52445325, 52477124, 52509082, 52541199, 52573478, 52605917,
-    52638519, 52671284, 52704213, 52737306, 52770565, 52803991, 52837583, 52871343, 52905272,
-    52939371, 52973640, 53008081, 53042693, 53077479, 53112439, 53147574, 53182884, 53218371,
-    53254035, 53289877, 53325899, 53362101, 53398484, 53435048, 53471796, 53508727, 53545843,
-    53583145, 53620633, 53658309, 53696172, 53734226, 53772469, 53810904, 53849531, 53888351,
-    53927365, 53966574, 54005979, 54045582, 54085382, 54125381, 54165580, 54205981, 54246583,
-    54287388, 54328397, 54369612, 54411032, 54452660, 54494495, 54536540, 54578795, 54621262,
-    54663940, 54706832, 54749939, 54793261, 54836799, 54880556, 54924531, 54968726, 55013142,
-    55057780, 55102641, 55147727, 55193038, 55238575, 55284341, 55330335, 55376559, 55423014,
-    55469701, 55516622, 55563778, 55611169, 55658797, 55706663, 55754769, 55803115, 55851703,
-    55900534, 55949609, 55998929, 56048496, 56098311, 56148375, 56198689, 56249255, 56300074,
-    56351147, 56402475, 56454059, 56505902, 56558004, 56610366, 56662990, 56715878, 56769030,
-    56822447, 56876132, 56930085, 56984307, 57038801, 57093568, 57148608, 57203923, 57259515,
-    57315385, 57371534, 57427964, 57484677, 57541672, 57598953, 57656520, 57714375, 57772519,
-    57830954, 57889681, 57948702, 58008018, 58067631, 58127541, 58187751, 58248262, 58309076,
-    58370194, 58431617, 58493347, 58555386, 58617736, 58680397, 58743371, 58806660, 58870266,
-    58934190, 58998433, 59062997, 59127885, 59193097, 59258634, 59324500, 59390695, 59457221,
-    59524079, 59591272, 59658801, 59726667, 59794873, 59863419, 59932309, 60001543, 60071123,
-    60141051, 60211328, 60281957, 60352939, 60424276, 60495970, 60568022, 60640435, 60713209,
-    60786348, 60859852, 60933724, 61007964, 61082577, 61157562, 61232922, 61308659, 61384775,
-    61461271, 61538150, 61615413, 61693062, 61771100, 61849528, 61928348, 62007562, 62087172,
-    62167180, 62247588, 62328399, 62409613, 62491233, 62573262, 62655701, 62738551, 62821817,
-    62905498, 62989598, 63074118, 63159061, 63244429, 63330223, 63416447, 63503101, 63590189,
-    63677713, 63765673, 63854074, 63942917, 64032204, 64121937, 64212119, 64302752, 64393838,
-    64485380, 64577379, 64669838, 64762760, 64856146, 64949999, 65044322, 65139115, 65234383,
-    65330128, 65426351, 65523055, 65620242, 65717916, 65816078, 65914731, 66013877, 66113518,
-    66213658, 66314299, 66415443, 66517092, 66619250, 66721919, 66825101, 66928799, 67033015,
-    67137752, 67243014, 67348801, 67455117, 67561965, 67669347, 67777267, 67885725, 67994726,
-    68104272, 68214366, 68325010, 68436207, 68547961, 68660273, 68773147, 68886585, 69000590,
-    69115165, 69230314, 69346037, 69462340, 69579224, 69696693, 69814748, 69933394, 70052634,
-    70172469, 70292904, 70413941, 70535583, 70657833, 70780695, 70904171, 71028264, 71152977,
-    71278315, 71404279, 71530872, 71658099, 71785962, 71914464, 72043609, 72173399, 72303839,
-    72434930, 72566677, 72699083, 72832151, 72965884, 73100285, 73235359, 73371108, 73507536,
-    73644646, 73782442, 73920926, 74060103, 74199976, 74340549, 74481824, 74623805, 74766497,
-    74909901, 75054023, 75198866, 75344432, 75490727, 75637753, 75785514, 75934014, 76083256,
-    76233245, 76383984, 76535476, 76687726, 76840737, 76994513, 77149057, 77304375, 77460469,
-    77617344, 77775003, 77933451, 78092690, 78252726, 78413562, 78575202, 78737650, 78900911,
-    79064988, 79229885, 79395607, 79562157, 79729541, 79897761, 80066822, 80236728, 80407484,
-    80579094, 80751562, 80924892, 81099089, 81274157, 81450100, 81626923, 81804630, 81983225,
-    82162714, 82343099, 82524387, 82706582, 82889687, 83073708, 83258649, 83444514, 83631309,
-    83819038, 84007706, 84197316, 84387875, 84579387, 84771856, 84965288, 85159687, 85355058,
-    85551405, 85748735, 85947051, 86146358, 86346662, 86547968, 86750280, 86953604, 87157944,
-    87363306, 87569695, 87777116, 87985574, 88195074, 88405622, 88617223, 88829881, 89043603,
-    89258393, 89474257, 89691201, 89909229, 90128348, 90348562, 90569877, 90792299, 91015833,
-    91240484, 91466259, 91693163, 91921201, 92150379, 92380703, 92612179, 92844813, 93078609,
-    93313574, 93549715, 93787036, 94025543, 94265243, 94506142, 94748245, 94991558, 95236089,
-    95481841, 95728823, 95977039, 96226497, 96477202, 96729160, 96982378, 97236863, 97492619,
-    97749655, 98007975, 98267587, 98528498, 98790713, 99054239, 99319082, 99585250, 99852748,
-    100121585, 100391765, 100663296,
-];
+//! Curve lookup table, generated at build time by `build.rs`.
+//!
+//! With the default configuration this is the committed `curve/lut.rs`; the `lut-*` features
+//! and `TOKAMAK_LUT_*` environment variables trade table size against interpolation error.
+//!
+//! Lookups go through a uniform-grid index per column instead of a binary search over the
+//! whole table: the value's bucket bounds the segments it can fall in (a handful near the
+//! inflection, one or two elsewhere), and only those are searched. Results are exactly those of
+//! `binary_search` over the full column, which the `lut-binary-search` feature restores as the
+//! baseline for measuring the index.
 
-pub(crate) static S_LUT: [u64; 1025] = [
-    0,
-    217723764872,
-    437718982438,
-    659990765015,
-    884540659046,
-    1111374118397,
-    1340492981210,
-    1571901404762,
-    1805601572923,
-    2041596299223,
-    2279886320583,
-    2520473916147,
-    2763362091582,
-    3008549822034,
-    3256040592784,
-    3505832813377,
-    3757927530053,
-    4012325572827,
-    4269026521881,
-    4528028637918,
-    4789333052123,
-    5052936427409,
-    5318840519730,
-    5587040343855,
-    5857536100516,
-    6130325574563,
-    6405405142218,
-    6682772038011,
-    6962424433886,
-    7244359116598,
-    7528570195725,
-    7815055114929,
-    8103811144340,
-    8394831589138,
-    8688111958901,
-    8983647536225,
-    9281433380380,
-    9581464331074,
-    9883733665210,
-    10188234381237,
-    10494961963354,
-    10803908963669,
-    11115067666516,
-    11428431523369,
-    11743993793531,
-    12061744616611,
-    12381678260452,
-    12703784380695,
-    13028055355374,
-    13354483392341,
-    13683057426308,
-    14013770804501,
-    14346612020492,
-    14681570881027,
-    15018638600272,
-    15357804606942,
-    15699058120721,
-    16042388156904,
-    16387783531119,
-    16735234582284,
-    17084728060344,
-    17436253966849,
-    17789798649734,
-    18145350000096,
-    18502897551600,
-    18862428918090,
-    19223927853003,
-    19587385281984,
-    19952786468159,
-    20320118362828,
-    20689365851136,
-    21060517503151,
-    21433557905295,
-    21808471443845,
-    22185248330668,
-    22563870718416,
-    22944324589469,
-    23326593760065,
-    23710666046085,
-    24096525041841,
-    24484154178817,
-    24873541025545,
-    25264666657713,
-    25657514111136,
-    26052072905545,
-    26448321525656,
-    26846247160051,
-    27245832496845,
-    27647060105118,
-    28049914751812,
-    28454378846452,
-    28860434696078,
-    29268066884655,
-    29677257593349,
-    30087988917480,
-    30500242871980,
-    30914003854812,
-    31329253799863,
-    31745977077517,
-    32164150520460,
-    32583760909517,
-    33004787456376,
-    33427214416282,
-    33851023486851,
-    34276193706923,
-    34702709303051,
-    35130551896564,
-    35559703094180,
-    35990141779498,
-    36421854952270,
-    36854818758122,
-    37289017512537,
-    37724430028862,
-    38161040694872,
-    38598825476586,
-    39037771655258,
-    39477858057405,
-    39919066369276,
-    40361375389461,
-    40804769782113,
-    41249228403163,
-    41694730079236,
-    42141259648181,
-    42588796023535,
-    43037321165612,
-    43486817112159,
-    43937259772393,
-    44388637476408,
-    44840926224391,
-    45294105108620,
-    45748162813695,
-    46203072230157,
-    46658819043000,
-    47115385880520,
-    47572748928214,
-    48030890924766,
-    48489791411672,
-    48949436667801,
-    49409803089989,
-    49870873820858,
-    50332628734874,
-    50795051201626,
-    51258117830392,
-    51721815646390,
-    52186124922448,
-    52651026007945,
-    53116499332682,
-    53582525410705,
-    54049092019718,
-    54516172765391,
-    54983755700006,
-    55451818189511,
-    55920344965458,
-    56389317255746,
-    56858712678961,
-    57328520101293,
-    57798721154729,
-    58269290022606,
-    58740216060559,
-    59211481269073,
-    59683067777840,
-    60154953978039,
-    60627126094670,
-    61099566663637,
-    61572258362232,
-    62045180054816,
-    62518322603056,
-    62991661189739,
-    63465179021876,
-    63938867548453,
-    64412702289646,
-    64886671013008,
-    65360749412887,
-    65834933822130,
-    66309196097842,
-    66783524697288,
-    67257904143477,
-    67732319134807,
-    68206750312493,
-    68681186924931,
-    69155605655964,
-    69629996099270,
-    70104343799995,
-    70578630151489,
-    71052841014544,
-    71526958060369,
-    72000976279117,
-    72474868905832,
-    72948626901005,
-    73422232577752,
-    73895672853402,
-    74368939354753,
-    74842005887559,
-    75314864406971,
-    75787502590170,
-    76259903734963,
-    76732055876981,
-    77203942634589,
-    77675547758179,
-    78146864471857,
-    78617876916509,
-    79088569369385,
-    79558926245656,
-    80028941601306,
-    80498600252150,
-    80967887155758,
-    81436792223550,
-    81905295929872,
-    82373393366538,
-    82841070190388,
-    83308317096010,
-    83775115186357,
-    84241450564959,
-    84707324333412,
-    85172713137802,
-    85637608584678,
-    86102002512475,
-    86565876938417,
-    87029224050083,
-    87492036268847,
-    87954296081035,
-    88415996261636,
-    88877124698153,
-    89337669437503,
-    89797623847691,
-    90256976353526,
-    90715715541076,
-    91173824940754,
-    91631309115824,
-    92088146742327,
-    92544327057523,
-    92999850047592,
-    93454694768389,
-    93908861609877,
-    94362335231835,
-    94815105752183,
-    95267168839521,
-    95718509596622,
-    96169129482802,
-    96619003074488,
-    97068137640780,
-    97516518934600,
-    97964132804392,
-    98410976216935,
-    98857046378849,
-    99302324096300,
-    99746806894798,
-    100190486957408,
-    100633356635851,
-    101075408450582,
-    101516635090837,
-    101957029414670,
-    102396578779555,
-    102835282015983,
-    103273132488603,
-    103710118009665,
-    104146237968073,
-    104581480479287,
-    105015839531516,
-    105449309281636,
-    105881889861136,
-    106313558345472,
-    106744326814827,
-    107174178436602,
-    107603114031458,
-    108031116985534,
-    108458194344351,
-    108884329762674,
-    109309524747495,
-    109733769127761,
-    110157058805225,
-    110579389848050,
-    111000752486642,
-    111421149092560,
-    111840570195283,
-    112259012477231,
-    112676472785678,
-    113092935965139,
-    113508411295396,
-    113922883885882,
-    114336357276076,
-    114748822938500,
-    115160272446023,
-    115570709826596,
-    115980126946533,
-    116388521980109,
-    116795880816007,
-    117202214335979,
-    117607514942593,
-    118011768868595,
-    118414981237254,
-    118817151092250,
-    119218271321855,
-    119618334912670,
-    120017341293057,
-    120415290048626,
-    120812180921594,
-    121208001035155,
-    121602750355432,
-    121996429003911,
-    122389037256769,
-    122780569098816,
-    123171018610902,
-    123560386443774,
-    123948666912659,
-    124335867432761,
-    124721976078015,
-    125106994021489,
-    125490916042341,
-    125873743572136,
-    126255471619649,
-    126636101873037,
-    127015629568997,
-    127394056650319,
-    127771371952920,
-    128147590912577,
-    128522695949189,
-    128896689468438,
-    129269574020981,
-    129641345607498,
-    130012000318988,
-    130381541057196,
-    130749970866355,
-    131117279438582,
-    131483463262490,
-    131848532461346,
-    132212483778701,
-    132575307247014,
-    132937013369470,
-    133297599190182,
-    133657055002775,
-    134015384835818,
-    134372592854537,
-    134728676485219,
-    135083626351974,
-    135437453813013,
-    135790156586242,
-    136141732473437,
-    136492179359944,
-    136841495214383,
-    137189685049307,
-    137536747035248,
-    137882679424759,
-    138227480552102,
-    138571155841960,
-    138913703826699,
-    139255123119115,
-    139595412412103,
-    139934577534569,
-    140272610305735,
-    140609516736187,
-    140945295870461,
-    141279953933803,
-    141613483046989,
-    141945882487753,
-    142277158747729,
-    142607311292412,
-    142936339663392,
-    143264250648557,
-    143591029610700,
-    143916690669745,
-    144241233701304,
-    144564651440197,
-    144886951102685,
-    145208132785561,
-    145528196658578,
-    145847142964087,
-    146164979285721,
-    146481691482558,
-    146797294561249,
-    147111789082701,
-    147425168366788,
-    147737433083536,
-    148048598637923,
-    148358651216375,
-    148667599050856,
-    148975435756462,
-    149282177096825,
-    149587809449564,
-    149892348754764,
-    150195781483920,
-    150498116337407,
-    150799354698059,
-    151099505449026,
-    151398555246230,
-    151696513093823,
-    151993380636575,
-    152289167057486,
-    152583859192427,
-    152877466342595,
-    153169990408713,
-    153461433354557,
-    153751797206584,
-    154041084053551,
-    154329288505281,
-    154616427846336,
-    154902481699425,
-    155187475049771,
-    155471395163625,
-    155754252055646,
-    156036040643817,
-    156316763474690,
-    156596430768988,
-    156875029970440,
-    157152579041007,
-    157429073146740,
-    157704515126374,
-    157978900216142,
-    158252239011261,
-    158524534521529,
-    158795782126714,
-    159065992617557,
-    159335161467924,
-    159603291884581,
-    159870379408399,
-    160136442789095,
-    160401469947100,
-    160665472028111,
-    160928444755580,
-    161190391635871,
-    161451316228432,
-    161711229925376,
-    161970120839597,
-    162227992662798,
-    162484856943204,
-    162740709687570,
-    162995554742034,
-    163249388174552,
-    163502229582440,
-    163754059452238,
-    164004897489354,
-    164254739929728,
-    164503590895578,
-    164751454558034,
-    164998319367962,
-    165244205331023,
-    165489108863302,
-    165733026397641,
-    165975970214154,
-    166217944747831,
-    166458938619489,
-    166698964249913,
-    166938018245402,
-    167176113134180,
-    167413237638835,
-    167649412339109,
-    167884618043266,
-    168118875428520,
-    168352181306006,
-    168584540498937,
-    168815949870288,
-    169046414309336,
-    169275938748875,
-    169504520139238,
-    169732171510750,
-    169958881845356,
-    170184672298211,
-    170409523853843,
-    170633457758454,
-    170856463085002,
-    171078553115936,
-    171299725039676,
-    171519984146190,
-    171739327671141,
-    171957760965072,
-    172175289418644,
-    172391910347352,
-    172607637322822,
-    172822467726062,
-    173036390815883,
-    173249428402761,
-    173461577944598,
-    173672836911520,
-    173883219107353,
-    174092713898033,
-    174301326982899,
-    174509064098764,
-    174715931019605,
-    174921925363794,
-    175127052959977,
-    175331311468300,
-    175534714982572,
-    175737253005384,
-    175938939696133,
-    176139764585649,
-    176339741898163,
-    176538869434000,
-    176737153251852,
-    176934591191413,
-    177131197622097,
-    177326953909213,
-    177521882738142,
-    177715982043400,
-    177909249769549,
-    178101692160114,
-    178293307197106,
-    178484101172860,
-    178674080412493,
-    178863242961784,
-    179051595193584,
-    179239135189730,
-    179425869370216,
-    179611795852451,
-    179796921102978,
-    179981251619700,
-    180164785580716,
-    180347521173069,
-    180529473316361,
-    180710640248026,
-    180891011841532,
-    181070611469405,
-    181249420660484,
-    181427462852516,
-    181605617733380,
-    181784767222877,
-    181964938214908,
-    182146115626202,
-    182328326415881,
-    182511563933261,
-    182695829940908,
-    182881143073652,
-    183067505144450,
-    183254917975203,
-    183443391845224,
-    183632937065186,
-    183823555517680,
-    184015257559928,
-    184208045110093,
-    184401928572540,
-    184596909901490,
-    184792999549042,
-    184990208000077,
-    185188537272584,
-    185387997900254,
-    185588591938525,
-    185790321452881,
-    185993214089589,
-    186197254928908,
-    186402463155856,
-    186608840930739,
-    186816398972081,
-    187025139479557,
-    187235081783368,
-    187446219575468,
-    187658572250892,
-    187872133529833,
-    188086922872914,
-    188302942619989,
-    188520203719628,
-    188738708553459,
-    188958468124721,
-    189179493473817,
-    189401795678624,
-    189625368595990,
-    189850231976521,
-    190076388350091,
-    190303840258798,
-    190532616223814,
-    190762710209112,
-    190994124833684,
-    191226880080336,
-    191460978633573,
-    191696431879950,
-    191933251246140,
-    192171439496480,
-    192411008118218,
-    192651959922934,
-    192894315182611,
-    193138068047075,
-    193383247601772,
-    193629839307742,
-    193877872341478,
-    194127340943819,
-    194378265638872,
-    194630649476304,
-    194884504300537,
-    195139841999510,
-    195396665710248,
-    195654987386754,
-    195914810217893,
-    196176163858099,
-    196439033944676,
-    196703450229530,
-    196969407212857,
-    197236925920865,
-    197506009747255,
-    197776679825674,
-    198048930758089,
-    198322774886726,
-    198598233485903,
-    198875310122778,
-    199154008381266,
-    199434358587354,
-    199716355512960,
-    200000011764170,
-    200285339995982,
-    200572343972456,
-    200861054319832,
-    201151456997138,
-    201443582738706,
-    201737435476580,
-    202033028141838,
-    202330364727725,
-    202629467240448,
-    202930348765520,
-    203233004412831,
-    203537456344700,
-    203843717778895,
-    204151801986268,
-    204461713243367,
-    204773473958927,
-    205087079435301,
-    205402561254420,
-    205719923875413,
-    206039180868730,
-    206360336760505,
-    206683414314429,
-    207008418150734,
-    207335362036838,
-    207664268931956,
-    207995143599855,
-    208327999979016,
-    208662842903388,
-    208999704742658,
-    209338581275881,
-    209679495836766,
-    210022462655977,
-    210367486815531,
-    210714591855201,
-    211063773731520,
-    211415074568129,
-    211768481187887,
-    212124026637880,
-    212481716308285,
-    212841564887561,
-    213203587126467,
-    213567797838443,
-    213934211899982,
-    214302853564319,
-    214673719218367,
-    215046833233558,
-    215422210742338,
-    215799876294987,
-    216179826458367,
-    216562085903760,
-    216946670025548,
-    217333603677788,
-    217722883611769,
-    218114544219054,
-    218508582344808,
-    218905032557047,
-    219303901240675,
-    219705213718160,
-    220108967025860,
-    220515196090445,
-    220923917019105,
-    221335136491966,
-    221748870725337,
-    222165155044215,
-    222583986812306,
-    223005392000409,
-    223429387143389,
-    223855988848709,
-    224285213796792,
-    224717088325809,
-    225151619700940,
-    225588824823789,
-    226028739906080,
-    226471362807804,
-    226916720295505,
-    227364829604809,
-    227815708047437,
-    228269382686187,
-    228725871020541,
-    229185180929723,
-    229647339742919,
-    230112365192809,
-    230580284823239,
-    231051106814987,
-    231524858893579,
-    232001559143336,
-    232481225728678,
-    232963876894455,
-    233449540770416,
-    233938235799515,
-    234429980506415,
-    234924793497800,
-    235422703315549,
-    235923728768258,
-    236427888747797,
-    236935202229606,
-    237445688272995,
-    237959385851401,
-    238476304340714,
-    238996463091863,
-    239519891494054,
-    240046619073150,
-    240576655535526,
-    241110020614285,
-    241646754137890,
-    242186866073882,
-    242730376455926,
-    243277325492403,
-    243827733471916,
-    244381610702348,
-    244938987693898,
-    245499895099253,
-    246064353604630,
-    246632383986565,
-    247204017248833,
-    247779284540184,
-    248358186663503,
-    248940775291877,
-    249527061589389,
-    250117077167681,
-    250710843567272,
-    251308392654437,
-    251909746197617,
-    252514936319679,
-    253123985018681,
-    253736924675987,
-    254353767217662,
-    254974555560150,
-    255599312165440,
-    256228069937217,
-    256860851571847,
-    257497679861736,
-    258138588085139,
-    258783609675002,
-    259432778219634,
-    260086106595841,
-    260743628615838,
-    261405378248447,
-    262071389619738,
-    262741686520326,
-    263416292839155,
-    264095243086977,
-    264778582472171,
-    265466324761991,
-    266158494331085,
-    266855147406565,
-    267556298099748,
-    268261981790791,
-    268972223390596,
-    269687069202860,
-    270406544431930,
-    271130674382500,
-    271859505846739,
-    272593053715214,
-    273331375817007,
-    274074487307603,
-    274822424894160,
-    275575225448690,
-    276332915218930,
-    277095542164969,
-    277863132833617,
-    278635724711143,
-    279413355450226,
-    280196051999135,
-    280983863182526,
-    281776805345634,
-    282574938551802,
-    283378279414891,
-    284186877445888,
-    285000749477392,
-    285819967315300,
-    286644537106940,
-    287474509052471,
-    288309922549449,
-    289150817164097,
-    289997221558171,
-    290849186674415,
-    291706741477795,
-    292569926158094,
-    293438792216469,
-    294313369072662,
-    295193697423051,
-    296079818132832,
-    296971772235982,
-    297869600935212,
-    298773345601889,
-    299683059042151,
-    300598760449832,
-    301520502949960,
-    302448339907404,
-    303382302250936,
-    304322421004850,
-    305268772786126,
-    306221366241121,
-    307180267019383,
-    308145506795355,
-    309117140232085,
-    310095210766085,
-    311079750514792,
-    312070826197107,
-    313068458757175,
-    314072715317680,
-    315083640195794,
-    316101254715476,
-    317125638199542,
-    318156823894795,
-    319194856769599,
-    320239781953577,
-    321291656410372,
-    322350525647499,
-    323416435331887,
-    324489443020195,
-    325569583003313,
-    326656913191515,
-    327751491728870,
-    328853353375370,
-    329962556624228,
-    331079160200543,
-    332203187458229,
-    333334721181770,
-    334473808822068,
-    335620486053386,
-    336774812512784,
-    337936836097854,
-    339106628825646,
-    340284226999030,
-    341469691038234,
-    342663069540120,
-    343864411243305,
-    345073789200669,
-    346291252551473,
-    347516850572899,
-    348750632678493,
-    349992672753013,
-    351243020600499,
-    352501726157018,
-    353768851718587,
-    355044447541831,
-    356328576281217,
-    357621313090458,
-    358922684163800,
-    360232764983309,
-    361551618963304,
-    362879297345101,
-    364215876277073,
-    365561382524176,
-    366915904982337,
-    368279495534779,
-    369652218652358,
-    371034126496156,
-    372425283852675,
-    373825768237101,
-    375235619742807,
-    376654916230261,
-    378083710612680,
-    379522068523314,
-    380970055770802,
-    382427751006743,
-    383895182374032,
-    385372454203118,
-    386859607576869,
-    388356721836596,
-    389863838239339,
-    391381049222192,
-    392908421909792,
-    394445997896568,
-    395993870206933,
-    397552093530958,
-    399120735522999,
-    400699863978800,
-    402289559782225,
-    403889878096527,
-    405500887123662,
-    407122655194793,
-    408755276840365,
-    410398794654257,
-    412053277336130,
-    413718819905874,
-    415395478299335,
-    417083321620262,
-    418782432245967,
-    420492866364934,
-    422214719810961,
-    423948035755594,
-    425692910307209,
-    427449413283408,
-    429217614586038,
-    430997584197368,
-    432789392176218,
-    434593135383450,
-    436408870764218,
-    438236682057797,
-    440076639721369,
-    441928827721176,
-    443793303214814,
-    445670163809494,
-    447559480295368,
-    449461337042616,
-    451375791420173,
-    453302927908607,
-    455242844709004,
-    457195585708039,
-    459161262884408,
-    461139947477481,
-    463131697041503,
-    465136623909676,
-    467154785727215,
-    469186267565237,
-    471231154572292,
-    473289518166491,
-    475361457396065,
-    477447043766037,
-    479546348752124,
-    481659471576253,
-    483786483771482,
-    485927470760426,
-    488082504048216,
-    490251697016894,
-    492435107254873,
-    494632834260458,
-    496844949551950,
-    499071552699946,
-    501312701113252,
-    503568508455412,
-    505839046174475,
-    508124399772587,
-    510424654737283,
-    512739910728090,
-    515070239037816,
-    517415725077709,
-    519776468478604,
-    522152540360461,
-    524544054605751,
-    526951067936220,
-    529373679867564,
-    531811975574760,
-    534266054545925,
-    536736001874991,
-    539221902575502,
-    541723841575156,
-    544241918176068,
-    546776217180062,
-    549326823286477,
-    551893835614451,
-    554477338703768,
-    557077416975177,
-    559694183903392,
-    562327694558129,
-    564978076860169,
-    567645414882561,
-    570329777881265,
-    573031278951045,
-    575750001769281,
-    578486029850525,
-    581239476042192,
-    584010423636908,
-    586798970538373,
-    589605184925583,
-    592429194027183,
-    595271080565760,
-    598130927063756,
-    601008845614399,
-    603904918441408,
-    606819242479563,
-    609751899574602,
-    612703016241392,
-    615672659058144,
-    618660924297094,
-    621667923087873,
-    624693751425464,
-    627738490070796,
-    630802249685615,
-    633885110628840,
-    636987183224849,
-    640108562544094,
-    643249343457361,
-    646409620631043,
-    649589488522508,
-    652789056580885,
-    656008418885508,
-    659247654051669,
-    662506886172654,
-    665786193422615,
-    669085684256996,
-    672405466961059,
-    675745618988027,
-    679106263510569,
-    682487477497940,
-    685889368354133,
-    689312043290970,
-    692755593910197,
-    696220111545786,
-    699705718154050,
-    703212504622620,
-    706740561560673,
-    710289994791797,
-    713860909920018,
-    717453412326036,
-    721067607163569,
-    724703568236301,
-    728361415714614,
-    732041269595358,
-    735743202877819,
-    739467319438538,
-    743213738546083,
-    746982563629220,
-    750773882197807,
-    754587797130290,
-    758424442443665,
-    762283873454811,
-    766166239402718,
-    770071610733337,
-    774000120533211,
-    777951854427717,
-    781926913499969,
-    785925414360464,
-    789947473403474,
-    793993159328318,
-    798062619644659,
-    802155922486215,
-    806273199065048,
-    810414564542523,
-    814580102067802,
-    818769926267866,
-    822984135622141,
-    827222860191078,
-    831486182022615,
-    835774246657830,
-    840087119659144,
-    844424930131968,
-];
+include!(concat!(env!("OUT_DIR"), "/lut.rs"));
+
+/// Position of `x` in `X_LUT`, as `X_LUT.binary_search(&x)` reports it.
+#[inline]
+pub(crate) fn search_x(x: u32) -> Result<usize, usize> {
+    #[cfg(feature = "lut-binary-search")]
+    return X_LUT.binary_search(&x);
+    #[cfg(not(feature = "lut-binary-search"))]
+    search(&X_LUT, &X_INDEX, (x >> X_INDEX_SHIFT) as usize, x)
+}
+
+/// Position of `s` in `S_LUT`, as `S_LUT.binary_search(&s)` reports it.
+#[inline]
+pub(crate) fn search_s(s: u64) -> Result<usize, usize> {
+    #[cfg(feature = "lut-binary-search")]
+    return S_LUT.binary_search(&s);
+    #[cfg(not(feature = "lut-binary-search"))]
+    search(&S_LUT, &S_INDEX, (s >> S_INDEX_SHIFT) as usize, s)
+}
+
+/// Binary search over the segments `index` allows for `bucket`. Buckets past the index (values
+/// above the column) search the last segment, which reports them past the end.
+#[cfg(not(feature = "lut-binary-search"))]
+#[inline]
+fn search<T: Ord>(column: &[T], index: &[u16], bucket: usize, value: T) -> Result<usize, usize> {
+    let bucket = bucket.min(index.len() - 2);
+    let lo = index[bucket] as usize;
+    let hi = index[bucket + 1] as usize + 1;
+    match column[lo..=hi].binary_search(&value) {
+        Ok(i) => Ok(lo + i),
+        Err(i) => Err(lo + i),
+    }
+}
@@ -5,14 +5,19 @@
 //! `ds = dc * Smax / Cmax`. This keeps the curve shape constant while allowing
 //! different total capacities.
 //!
-//! All values use unsigned arithmetic:
-//! - `x`: u32 in range [0, 6] (Q8.24)
-//! - `s`: u64 cumulative cost (Q16.48)
-//! - `dx`, `ds`, `dc`: unsigned deltas, with two's complement for negation
+//! These free functions price the master LUT sigmoid; [`crate::shape`] generalises them
+//! to other curve shapes.
+//!
+//! Positions are typed, deltas use unsigned arithmetic:
+//! - `x`: [`Q824`] in range [0, 6]
+//! - `s`: [`Q1648`] cumulative cost
+//! - `dx`, `ds`, `dc`: raw unsigned deltas, with two's complement for negation
 
 use crate::{
-    consts::{LUT_S_MAX, LUT_X_MAX, LUT_X_MIN},
-    lut::{S_LUT, X_LUT},
+    consts::{LUT_X_MAX, LUT_X_MIN},
+    fixed::{Q1648, Q824},
+    lut::{search_s, search_x, S_LUT, X_LUT},
+    shape::{CurveShape, Sigmoid},
 };
 
 /// Calculates `dx` from a capacity delta `dc` by scaling through `ds`,
@@ -21,8 +26,8 @@ use crate::{
 /// `x0 + dx` stays within `[LUT_X_MIN, LUT_X_MAX]`, allowing partial movement.
 ///
 /// Arguments:
-/// - `x0`: Current x position in Q8.24 (u32).
-/// - `s0`: Current cumulative cost at `x0` in Q16.48 (u64).
+/// - `x0`: Current x position.
+/// - `s0`: Current cumulative cost at `x0`.
 /// - `dc`: Delta capacity (u64, interpreted as two's complement for negative deltas).
 /// - `cmax`: Total capacity that maps to `Smax` (u64).
 ///
@@ -34,10 +39,10 @@ use crate::{
 /// - `s0` equals the LUT evaluation at `x0`.
 /// - `cmax > 0`.
 /// - `dc * Smax` fits in `u128`.
-pub fn dx_for_dc(x0: u32, s0: u64, dc: u64, cmax: u64) -> (u32, u64) {
-    let ds = ds_for_dc(dc, cmax);
-    let dx = dx_for_ds(x0, s0, ds);
-    (dx, ds)
+///
+/// Uses the master [`Sigmoid`]; see [`CurveShape::dx_for_dc`] for other shapes.
+pub fn dx_for_dc(x0: Q824, s0: Q1648, dc: u64, cmax: u64) -> (u32, u64) {
+    Sigmoid.dx_for_dc(x0, s0, dc, cmax)
 }
 
 /// Calculates `dc` (delta capacity) for moving from `x0` to `x0 + dx`,
@@ -46,7 +51,7 @@ pub fn dx_for_dc(x0: u32, s0: u64, dc: u64, cmax: u64) -> (u32, u64) {
 /// `[LUT_X_MIN, LUT_X_MAX]`.
 ///
 /// Arguments:
-/// - `x0`: Current x position in Q8.24 (u32).
+/// - `x0`: Current x position.
 /// - `dx`: Delta x in Q8.24 (u32, two's complement for negative).
 /// - `cmax`: Total capacity that maps to `Smax` (u64).
 ///
@@ -56,55 +61,18 @@ pub fn dx_for_dc(x0: u32, s0: u64, dc: u64, cmax: u64) -> (u32, u64) {
 /// - `x0` is within `[LUT_X_MIN, LUT_X_MAX]`.
 /// - `cmax > 0`.
 /// - `ds * Cmax` fits in `u128`.
-pub fn dc_for_dx(x0: u32, dx: u32, cmax: u64) -> u64 {
-    let ds = ds_for_dx(x0, dx);
-    dc_for_ds(ds, cmax)
-}
-
-/// Calculates `ds` (delta cumulative cost) for moving from `x0` to `x0 + dx`,
-/// given that the move stays within the LUT domain.
-///
-/// Arguments:
-/// - `x0`: Current x position in Q8.24 (u32).
-/// - `dx`: Delta x in Q8.24 (u32, two's complement for negative).
-///
-/// Returns: `ds` as u64 (two's complement encoded for negative values).
 ///
-/// Constraints:
-/// - `x0 + dx` is within `[LUT_X_MIN, LUT_X_MAX]`.
-pub(crate) fn ds_for_dx(x0: u32, dx: u32) -> u64 {
-    let x1 = x0.wrapping_add(dx);
-    let s1 = evaluate_cost(x1);
-    let s0 = evaluate_cost(x0);
-    s1.wrapping_sub(s0)
-}
-
-/// Calculates `dx` given `x0`, its cumulative cost `s0`, and a desired `ds`.
-/// If the target cumulative cost goes out of bounds, `dx` is clamped so that
-/// `x0 + dx` stays within `[LUT_X_MIN, LUT_X_MAX]`, allowing partial movement.
-///
-/// Arguments:
-/// - `x0`: Current x position in Q8.24 (u32).
-/// - `s0`: Current cumulative cost at `x0` in Q16.48 (u64).
-/// - `ds`: Desired delta in cumulative cost (u64, two's complement for negative).
-///
-/// Returns: `dx` as u32 (two's complement encoded for negative values).
-///
-/// Constraints:
-/// - `x0` is within `[LUT_X_MIN, LUT_X_MAX]`.
-/// - `s0` equals the LUT evaluation at `x0`.
-pub(crate) fn dx_for_ds(x0: u32, s0: u64, ds: u64) -> u32 {
-    let s = s0.wrapping_add(ds);
-    let x1 = x_for_s(s);
-    x1.wrapping_sub(x0)
+/// Uses the master [`Sigmoid`]; see [`CurveShape::dc_for_dx`] for other shapes.
+pub fn dc_for_dx(x0: Q824, dx: u32, cmax: u64) -> u64 {
+    Sigmoid.dc_for_dx(x0, dx, cmax)
 }
 
 /// Calculates the cumulative cost at `x` (Q8.24).
 /// Out-of-bounds values are clamped to the domain edges for graceful degradation.
 #[inline]
 pub(crate) fn evaluate_cost(x: u32) -> u64 {
-    let x = x.clamp(LUT_X_MIN, LUT_X_MAX);
-    match X_LUT.binary_search(&x) {
+    let x = x.clamp(LUT_X_MIN.0, LUT_X_MAX.0);
+    match search_x(x) {
         Ok(i) => S_LUT[i],
         Err(i) => {
             // x is between X_LUT[i-1] and X_LUT[i]
@@ -118,31 +86,6 @@ pub(crate) fn evaluate_cost(x: u32) -> u64 {
     }
 }
 
-/// Calculates `ds` (delta cumulative cost) from a capacity delta `dc`,
-/// given that the total capacity `cmax` maps to `Smax`.
-///
-/// Arguments:
-/// - `dc`: Delta capacity (u64, two's complement for negative).
-/// - `cmax`: Total capacity that maps to `Smax` (u64).
-///
-/// Returns: `ds` as u64 (two's complement encoded for negative).
-///
-/// Constraints:
-/// - `cmax > 0`.
-/// - `dc * Smax` fits in `u128`.
-pub(crate) fn ds_for_dc(dc: u64, cmax: u64) -> u64 {
-    let s_max = LUT_S_MAX as u128;
-    let num = dc as u128 * s_max;
-    div_round_u128(num, cmax as u128) as u64
-}
-
-/// Calculates `dc` (delta capacity) from a cumulative cost delta `ds`,
-/// given that the total capacity `cmax` maps to `Smax`.
-fn dc_for_ds(ds: u64, cmax: u64) -> u64 {
-    let num = ds as u128 * cmax as u128;
-    div_round_u128(num, LUT_S_MAX as u128) as u64
-}
-
 /// Integer (floor) lerp of s between (x0,s0) and (x1,s1) at x.
 /// Preconditions (must be enforced by caller):
 ///   - x1 > x0
@@ -157,16 +100,17 @@ fn interp_s_for_x(x: u32, x0: u32, s0: u64, x1: u32, s1: u64) -> u64 {
     (s0 as u128 + (ds * t + dx / 2) / dx) as u64
 }
 
+/// Calculates the x (Q8.24) whose cumulative cost is `s_target`, clamped to the domain.
 #[inline]
-fn x_for_s(s_target: u64) -> u32 {
-    match S_LUT.binary_search(&s_target) {
+pub(crate) fn x_for_s(s_target: u64) -> u32 {
+    match search_s(s_target) {
         Ok(i) => X_LUT[i],
         Err(i) => {
             // Clamp out-of-bounds cumulative costs to domain edges
             if i == 0 {
-                LUT_X_MIN
+                LUT_X_MIN.0
             } else if i >= S_LUT.len() {
-                LUT_X_MAX
+                LUT_X_MAX.0
             } else {
                 let x0 = X_LUT[i - 1];
                 let x1 = X_LUT[i];
@@ -193,6 +137,6 @@ fn interp_x_for_s(s: u64, x0: u32, s0: u64, x1: u32, s1: u64) -> u32 {
 }
 
 /// Round-to-nearest division for unsigned 128-bit integers (ties away from zero).
-fn div_round_u128(num: u128, den: u128) -> u128 {
+pub(crate) fn div_round_u128(num: u128, den: u128) -> u128 {
     (num + (den / 2)) / den
 }
@@ -0,0 +1,566 @@
+//! Curve shapes: the cumulative-cost function an element prices saturation with.
+//!
+//! A shape maps saturation `x` (Q8.24, `[LUT_X_MIN, LUT_X_MAX]`) to cumulative cost `s`
+//! (Q16.48) and back. Capacity maps linearly onto each shape's own span `[0, s_max]`, so the
+//! delta helpers on [`CurveShape`] work unchanged for every shape.
+//!
+//! Elements select a preset by id through [`Shape::from_id`]; id 0 is the master [`Sigmoid`],
+//! so zeroed curves keep the original pricing.
+
+use crate::{
+    consts::{LUT_S_MAX, LUT_X_MAX, LUT_X_MIN},
+    delta::Delta,
+    error::CurveError,
+    fixed::{Q1648, Q824},
+    lut::{LUT_SLOPE_HI, LUT_SLOPE_LO},
+    math::{div_round_u128, evaluate_cost, x_for_s},
+};
+
+/// 1.0 in Q8.24.
+const ONE: u32 = Q824::ONE.0;
+/// Raw domain bounds for the fixed-point internals.
+const X_MIN: u32 = LUT_X_MIN.0;
+const X_MAX: u32 = LUT_X_MAX.0;
+const S_MAX: u64 = LUT_S_MAX.0;
+
+/// Cumulative cost as a function of saturation.
+pub trait CurveShape {
+    /// Calculates the cumulative cost at `x`.
+    /// Non-decreasing; out-of-bounds values are clamped to the domain edges.
+    fn evaluate_cost(&self, x: Q824) -> Q1648;
+
+    /// Calculates the saturation whose cumulative cost is `s`, rounded to nearest.
+    /// Out-of-bounds costs are clamped to the domain edges. The default bisects
+    /// [`evaluate_cost`](Self::evaluate_cost); shapes with a closed-form inverse override it.
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        let cost = |x: u32| self.evaluate_cost(Q824(x)).0;
+        let (s, mut lo, mut hi) = (s.0, X_MIN, X_MAX);
+        let (mut s_lo, mut s_hi) = (cost(lo), cost(hi));
+        if s <= s_lo {
+            return Q824(lo);
+        }
+        if s >= s_hi {
+            return Q824(hi);
+        }
+        // Invariant: s_lo < s < s_hi
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            match cost(mid) {
+                s_mid if s_mid < s => (lo, s_lo) = (mid, s_mid),
+                s_mid if s_mid > s => (hi, s_hi) = (mid, s_mid),
+                _ => return Q824(mid),
+            }
+        }
+        if s - s_lo <= s_hi - s {
+            Q824(lo)
+        } else {
+            Q824(hi)
+        }
+    }
+
+    /// Cumulative cost of the whole domain; total capacity maps onto `[0, s_max]`.
+    fn s_max(&self) -> Q1648 {
+        self.evaluate_cost(LUT_X_MAX)
+    }
+
+    /// Calculates `dx` from a capacity delta `dc` by scaling through `ds`,
+    /// given that the current curve state is `(x0, s0)` and total capacity is `cmax`.
+    /// If the resulting movement would go out of bounds, `dx` is clamped so that
+    /// `x0 + dx` stays within `[LUT_X_MIN, LUT_X_MAX]`, allowing partial movement.
+    ///
+    /// Returns: `(dx, ds)` where dx is two's complement encoded (u32), ds is u64.
+    ///
+    /// Constraints:
+    /// - `x0` is within `[LUT_X_MIN, LUT_X_MAX]`.
+    /// - `s0` equals [`evaluate_cost`](Self::evaluate_cost) at `x0`.
+    /// - `cmax > 0`.
+    fn dx_for_dc(&self, x0: Q824, s0: Q1648, dc: u64, cmax: u64) -> (u32, u64) {
+        let ds = self.ds_for_dc(dc, cmax);
+        let dx = self.dx_for_ds(x0, s0, ds);
+        (dx, ds)
+    }
+
+    /// Calculates `dc` (delta capacity) for moving from `x0` to `x0 + dx`.
+    /// Returns `dc` two's complement encoded for negative values.
+    ///
+    /// Constraints:
+    /// - `x0 + dx` is within `[LUT_X_MIN, LUT_X_MAX]`.
+    /// - `cmax > 0`.
+    fn dc_for_dx(&self, x0: Q824, dx: u32, cmax: u64) -> u64 {
+        let ds = self.ds_for_dx(x0, dx);
+        self.dc_for_ds(ds, cmax)
+    }
+
+    /// Calculates `ds` (two's complement) for moving from `x0` to `x0 + dx`.
+    fn ds_for_dx(&self, x0: Q824, dx: u32) -> u64 {
+        let x1 = Q824(x0.0.wrapping_add(dx));
+        self.evaluate_cost(x1)
+            .0
+            .wrapping_sub(self.evaluate_cost(x0).0)
+    }
+
+    /// Calculates `dx` (two's complement) given `x0`, its cumulative cost `s0`, and `ds`.
+    /// The target is clamped to the domain, allowing partial movement.
+    ///
+    /// Rounds against the mover: a deposit never buys saturation whose cost exceeds `s0 + ds`,
+    /// and a withdrawal never sheds saturation whose cost exceeds `|ds|`.
+    fn dx_for_ds(&self, x0: Q824, s0: Q1648, ds: u64) -> u32 {
+        // Cost is flat between some adjacent steps: without this a zero move could round to one
+        if ds == 0 {
+            return 0;
+        }
+        let withdrawal = (ds as i64) < 0;
+        let target = match withdrawal {
+            true => s0.0.saturating_sub(ds.wrapping_neg()),
+            false => s0.0.saturating_add(ds),
+        };
+        let cost = |x: u32| self.evaluate_cost(Q824(x)).0;
+        let (x0, mut x1) = (x0.0, self.x_for_s(Q1648(target)).0);
+        if withdrawal {
+            x1 = x1.min(x0);
+            while x1 < x0 && cost(x1) < target {
+                x1 += 1;
+            }
+        } else {
+            x1 = x1.max(x0);
+            while x1 > x0 && cost(x1) > target {
+                x1 -= 1;
+            }
+        }
+        x1.wrapping_sub(x0)
+    }
+
+    /// Calculates `ds` (two's complement) from a capacity delta `dc` (two's complement),
+    /// given that `cmax` maps to `s_max`.
+    fn ds_for_dc(&self, dc: u64, cmax: u64) -> u64 {
+        scale_signed(dc, self.s_max().0, cmax)
+    }
+
+    /// Calculates `dc` (two's complement) from a cumulative cost delta `ds` (two's
+    /// complement), given that `cmax` maps to `s_max`.
+    fn dc_for_ds(&self, ds: u64, cmax: u64) -> u64 {
+        scale_signed(ds, cmax, self.s_max().0)
+    }
+
+    // ----- Typed deltas: same rounding, explicit direction, checked ranges -----
+
+    /// [`dx_for_dc`](Self::dx_for_dc) on typed deltas. Returns `(dx, ds)`; `dx` is clamped
+    /// to the domain, allowing partial movement.
+    ///
+    /// Fails with [`CurveError::ZeroCapacity`] if `cmax` is zero, [`CurveError::OutOfDomain`]
+    /// if `x0` is outside the domain, and [`CurveError::Overflow`] if `ds` exceeds `u64`.
+    fn try_dx_for_dc(
+        &self,
+        x0: Q824,
+        s0: Q1648,
+        dc: Delta<u64>,
+        cmax: u64,
+    ) -> Result<(Delta<Q824>, Delta<Q1648>), CurveError> {
+        let ds = self.try_ds_for_dc(dc, cmax)?;
+        let dx = self.try_dx_for_ds(x0, s0, ds)?;
+        Ok((dx, ds))
+    }
+
+    /// [`dc_for_dx`](Self::dc_for_dx) on typed deltas. Fails with
+    /// [`CurveError::OutOfDomain`] unless both `x0` and `x0 + dx` lie in the domain.
+    fn try_dc_for_dx(
+        &self,
+        x0: Q824,
+        dx: Delta<Q824>,
+        cmax: u64,
+    ) -> Result<Delta<u64>, CurveError> {
+        let ds = self.try_ds_for_dx(x0, dx)?;
+        self.try_dc_for_ds(ds, cmax)
+    }
+
+    /// [`ds_for_dx`](Self::ds_for_dx) on typed deltas: the cost between `x0` and `x0 + dx`,
+    /// both of which must lie in the domain.
+    fn try_ds_for_dx(&self, x0: Q824, dx: Delta<Q824>) -> Result<Delta<Q1648>, CurveError> {
+        let x1 = dx
+            .checked_apply(x0)
+            .ok()
+            .filter(|&x1| in_domain(x0) && in_domain(x1))
+            .ok_or(CurveError::OutOfDomain)?;
+        let (s0, s1) = (self.evaluate_cost(x0), self.evaluate_cost(x1));
+        Ok(match dx {
+            Delta::Deposit(_) => Delta::Deposit(s1 - s0),
+            Delta::Withdrawal(_) => Delta::Withdrawal(s0 - s1),
+        })
+    }
+
+    /// [`dx_for_ds`](Self::dx_for_ds) on typed deltas, rounding against the mover. The target
+    /// is clamped to the domain; `x0` must lie in it.
+    fn try_dx_for_ds(
+        &self,
+        x0: Q824,
+        s0: Q1648,
+        ds: Delta<Q1648>,
+    ) -> Result<Delta<Q824>, CurveError> {
+        if !in_domain(x0) {
+            return Err(CurveError::OutOfDomain);
+        }
+        Ok(match ds {
+            _ if ds.magnitude() == Q1648::ZERO => ds.map(|_| Q824::ZERO),
+            Delta::Deposit(m) => {
+                let target = s0.saturating_add(m);
+                let mut x1 = self.x_for_s(target).max(x0);
+                while x1 > x0 && self.evaluate_cost(x1) > target {
+                    x1.0 -= 1;
+                }
+                Delta::Deposit(x1 - x0)
+            }
+            Delta::Withdrawal(m) => {
+                let target = s0.saturating_sub(m);
+                let mut x1 = self.x_for_s(target).min(x0);
+                while x1 < x0 && self.evaluate_cost(x1) < target {
+                    x1.0 += 1;
+                }
+                Delta::Withdrawal(x0 - x1)
+            }
+        })
+    }
+
+    /// [`ds_for_dc`](Self::ds_for_dc) on typed deltas. Fails with
+    /// [`CurveError::ZeroCapacity`] if `cmax` is zero and [`CurveError::Overflow`] if `ds`
+    /// exceeds `u64`.
+    fn try_ds_for_dc(&self, dc: Delta<u64>, cmax: u64) -> Result<Delta<Q1648>, CurveError> {
+        if cmax == 0 {
+            return Err(CurveError::ZeroCapacity);
+        }
+        let m = scale_checked(dc.magnitude(), self.s_max().0, cmax).ok_or(CurveError::Overflow)?;
+        Ok(dc.map(|_| Q1648(m)))
+    }
+
+    /// [`dc_for_ds`](Self::dc_for_ds) on typed deltas. Fails with [`CurveError::Overflow`] if
+    /// `dc` exceeds `u64`.
+    fn try_dc_for_ds(&self, ds: Delta<Q1648>, cmax: u64) -> Result<Delta<u64>, CurveError> {
+        let m =
+            scale_checked(ds.magnitude().0, cmax, self.s_max().0).ok_or(CurveError::Overflow)?;
+        Ok(ds.map(|_| m))
+    }
+}
+
+fn in_domain(x: Q824) -> bool {
+    (LUT_X_MIN..=LUT_X_MAX).contains(&x)
+}
+
+/// `magnitude · num / den` rounded to nearest, or `None` if it exceeds `u64`.
+fn scale_checked(magnitude: u64, num: u64, den: u64) -> Option<u64> {
+    let scaled = div_round_u128(magnitude as u128 * num as u128, den as u128);
+    u64::try_from(scaled).ok()
+}
+
+/// `delta · num / den` rounded to nearest, for a two's complement `delta`. The magnitude is
+/// scaled and the sign restored, so withdrawals mirror deposits exactly.
+fn scale_signed(delta: u64, num: u64, den: u64) -> u64 {
+    let magnitude = (delta as i64).unsigned_abs();
+    let scaled = div_round_u128(magnitude as u128 * num as u128, den as u128) as u64;
+    match (delta as i64) < 0 {
+        true => scaled.wrapping_neg(),
+        false => scaled,
+    }
+}
+
+/// The master logistic curve from `lutgen`: inflection at 3.0, unit steepness.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sigmoid;
+
+impl CurveShape for Sigmoid {
+    #[inline]
+    fn evaluate_cost(&self, x: Q824) -> Q1648 {
+        Q1648(evaluate_cost(x.0))
+    }
+
+    #[inline]
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        Q824(x_for_s(s.0))
+    }
+
+    #[inline]
+    fn s_max(&self) -> Q1648 {
+        LUT_S_MAX
+    }
+}
+
+/// Constant price: cost grows one-for-one with saturation (`s = x`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Linear;
+
+impl CurveShape for Linear {
+    #[inline]
+    fn evaluate_cost(&self, x: Q824) -> Q1648 {
+        Q1648((x.0.clamp(X_MIN, X_MAX) as u64) << 24)
+    }
+
+    #[inline]
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        let x = s.0.saturating_add(1 << 23) >> 24;
+        Q824(x.clamp(X_MIN as u64, X_MAX as u64) as u32)
+    }
+}
+
+/// Price `2^(rate·x)`: doubles every `1 / rate` of saturation.
+/// Cost is the integral `(2^(rate·x) - 1) / (rate·ln 2)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exponential {
+    /// Doublings per unit of saturation.
+    rate: Q824,
+}
+
+impl Exponential {
+    /// `rate` in doublings per unit of saturation; the price may double fewer than 16 times
+    /// across the domain so cumulative cost fits Q16.48.
+    pub const fn new(rate: Q824) -> Self {
+        assert!(rate.0 > 0, "exponential rate must be positive");
+        assert!(
+            (rate.0 as u64 * X_MAX as u64) >> 24 < 16 << 24,
+            "exponential rate overflows Q16.48"
+        );
+        Self { rate }
+    }
+
+    pub const fn rate(self) -> Q824 {
+        self.rate
+    }
+
+    /// `rate·ln 2` in Q2.62.
+    fn scale(self) -> u128 {
+        (self.rate.0 as u128 * LN2) >> 24
+    }
+}
+
+impl CurveShape for Exponential {
+    fn evaluate_cost(&self, x: Q824) -> Q1648 {
+        let x = x.0.clamp(X_MIN, X_MAX);
+        let price = exp2((self.rate.0 as u64 * x as u64) >> 24);
+        Q1648(div_round_u128((price - UNIT) << 48, self.scale()) as u64)
+    }
+
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        if s >= self.s_max() {
+            return LUT_X_MAX;
+        }
+        let price = UNIT + ((s.0 as u128 * self.scale() + (1 << 47)) >> 48);
+        let x = div_round_u128((log2(price) as u128) << 24, self.rate.0 as u128);
+        Q824(x.clamp(X_MIN as u128, X_MAX as u128) as u32)
+    }
+}
+
+/// Logistic curve with a tunable inflection point and steepness, built by rescaling the
+/// master table: price `σ(k·(x - m))`. Beyond the table's span the price is held at the
+/// table's end slopes, so steep curves stay continuous.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TunedSigmoid {
+    /// Inflection point `m`.
+    inflection: Q824,
+    /// Steepness `k`; 1.0 with the master inflection reproduces [`Sigmoid`].
+    steepness: Q824,
+}
+
+impl TunedSigmoid {
+    /// `inflection` within the domain, `steepness` in `(0, 8.0]`.
+    pub const fn new(inflection: Q824, steepness: Q824) -> Self {
+        assert!(inflection.0 <= X_MAX, "inflection outside the domain");
+        assert!(
+            steepness.0 > 0 && steepness.0 <= 8 * ONE,
+            "steepness outside (0, 8]"
+        );
+        Self {
+            inflection,
+            steepness,
+        }
+    }
+
+    pub const fn inflection(self) -> Q824 {
+        self.inflection
+    }
+
+    pub const fn steepness(self) -> Q824 {
+        self.steepness
+    }
+
+    /// Master-table coordinate `u = k·(x - m)`, centred on the table's inflection (Q8.24).
+    fn centred(self, x: u32) -> i64 {
+        (self.steepness.0 as i64 * (x as i64 - self.inflection.0 as i64)) >> 24
+    }
+}
+
+impl CurveShape for TunedSigmoid {
+    fn evaluate_cost(&self, x: Q824) -> Q1648 {
+        let x = x.0.clamp(X_MIN, X_MAX);
+        let origin = master_cost(self.centred(X_MIN));
+        let cost = master_cost(self.centred(x)) - origin;
+        Q1648(div_round_u128((cost as u128) << 24, self.steepness.0 as u128) as u64)
+    }
+
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        if s >= self.s_max() {
+            return LUT_X_MAX;
+        }
+        let origin = master_cost(self.centred(X_MIN));
+        let k = self.steepness.0 as i128;
+        let target = origin + ((s.0 as i128 * k + (1 << 23)) >> 24);
+        let u = master_x(target) as i128;
+        let offset = match u {
+            0.. => ((u << 24) + k / 2) / k,
+            _ => -(((-u << 24) + k / 2) / k),
+        };
+        let x = self.inflection.0 as i128 + offset;
+        Q824(x.clamp(X_MIN as i128, X_MAX as i128) as u32)
+    }
+}
+
+/// Preset shapes, indexed by the id stored in an element's curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Sigmoid(Sigmoid),
+    Linear(Linear),
+    Exponential(Exponential),
+    TunedSigmoid(TunedSigmoid),
+}
+
+/// Shape presets by id: 0 master sigmoid, 1 linear, 2 exponential (price doubles three times
+/// across the domain), 3 early, steep sigmoid (inflection at a third of the domain, steepness
+/// 2.0). On the default [0, 6] domain the exponential rate is 0.5 and the inflection 2.0.
+pub const SHAPES: [Shape; 4] = [
+    Shape::Sigmoid(Sigmoid),
+    Shape::Linear(Linear),
+    Shape::Exponential(Exponential::new(Q824(((3u64 << 48) / X_MAX as u64) as u32))),
+    Shape::TunedSigmoid(TunedSigmoid::new(Q824(X_MAX / 3), Q824(2 * ONE))),
+];
+
+impl Shape {
+    /// Preset for `id`, or `None` if no such preset exists.
+    pub const fn from_id(id: u32) -> Option<Self> {
+        if (id as usize) < SHAPES.len() {
+            Some(SHAPES[id as usize])
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::Sigmoid(Sigmoid)
+    }
+}
+
+impl CurveShape for Shape {
+    fn evaluate_cost(&self, x: Q824) -> Q1648 {
+        match self {
+            Self::Sigmoid(shape) => shape.evaluate_cost(x),
+            Self::Linear(shape) => shape.evaluate_cost(x),
+            Self::Exponential(shape) => shape.evaluate_cost(x),
+            Self::TunedSigmoid(shape) => shape.evaluate_cost(x),
+        }
+    }
+
+    fn x_for_s(&self, s: Q1648) -> Q824 {
+        match self {
+            Self::Sigmoid(shape) => shape.x_for_s(s),
+            Self::Linear(shape) => shape.x_for_s(s),
+            Self::Exponential(shape) => shape.x_for_s(s),
+            Self::TunedSigmoid(shape) => shape.x_for_s(s),
+        }
+    }
+
+    fn s_max(&self) -> Q1648 {
+        match self {
+            Self::Sigmoid(shape) => shape.s_max(),
+            Self::Linear(shape) => shape.s_max(),
+            Self::Exponential(shape) => shape.s_max(),
+            Self::TunedSigmoid(shape) => shape.s_max(),
+        }
+    }
+}
+
+// ===== Fixed-Point Helpers =====
+
+/// 1.0 in Q2.62.
+const UNIT: u128 = 1 << 62;
+/// ln 2 in Q2.62.
+const LN2: u128 = 3196577161300663915;
+/// `2^(2^-j)` in Q2.62 for j = 1..=24, one factor per fractional bit of a Q8.24 exponent.
+const EXP2_BITS: [u128; 24] = [
+    6521908912666391106,
+    5484249825272419512,
+    5029079263719320435,
+    4815862801830788490,
+    4712668792719003884,
+    4661903986662671290,
+    4636727017470743990,
+    4624189567668517720,
+    4617933561212708776,
+    4614808732577250068,
+    4613247111281068008,
+    4612466498810092975,
+    4612076242109103707,
+    4611881126141011236,
+    4611783571252412754,
+    4611734794581956353,
+    4611710406440186476,
+    4611698212417665819,
+    4611692115418496524,
+    4611689066921934630,
+    4611687542674409371,
+    4611686780550835664,
+    4611686399489096040,
+    4611686208958238036,
+];
+
+/// `2^y` in Q2.62 (scaled by the integer part), for `y` in Q8.24 below 16.0.
+fn exp2(y: u64) -> u128 {
+    let mut acc = UNIT;
+    for (j, factor) in EXP2_BITS.iter().enumerate() {
+        if y & (1 << (23 - j)) != 0 {
+            acc = (acc * factor) >> 62;
+        }
+    }
+    acc << (y >> 24)
+}
+
+/// `log2(v)` in Q8.24 for `v >= 1.0` in Q2.62, by repeated squaring.
+fn log2(v: u128) -> u64 {
+    let int = 127 - v.leading_zeros() - 62;
+    let mut m = v >> int;
+    let mut y = (int as u64) << 24;
+    for bit in (0..24).rev() {
+        m = (m * m) >> 62;
+        if m >= 2 * UNIT {
+            m >>= 1;
+            y |= 1 << bit;
+        }
+    }
+    y
+}
+
+/// Half the master domain: the master table's inflection point.
+const MASTER_CENTRE: i64 = (X_MAX / 2) as i64;
+const MASTER_SLOPE_HI: i128 = LUT_SLOPE_HI as i128;
+const MASTER_SLOPE_LO: i128 = LUT_SLOPE_LO as i128;
+
+/// Master cumulative cost at centred coordinate `u` (Q8.24), extended linearly past the
+/// table ends with the end prices. Q16.48, negative below the table.
+fn master_cost(u: i64) -> i128 {
+    let x = u + MASTER_CENTRE;
+    match x {
+        _ if x > X_MAX as i64 => {
+            S_MAX as i128 + (((x - X_MAX as i64) as i128 * MASTER_SLOPE_HI) >> 8)
+        }
+        _ if x < X_MIN as i64 => -(((X_MIN as i64 - x) as i128 * MASTER_SLOPE_LO) >> 8),
+        _ => evaluate_cost(x as u32) as i128,
+    }
+}
+
+/// Inverse of [`master_cost`]: centred coordinate (Q8.24) whose master cost is `s`.
+fn master_x(s: i128) -> i64 {
+    let x = match s {
+        _ if s > S_MAX as i128 => {
+            X_MAX as i64 + (((s - S_MAX as i128) << 8) / MASTER_SLOPE_HI) as i64
+        }
+        _ if s < 0 => X_MIN as i64 - (((-s) << 8) / MASTER_SLOPE_LO) as i64,
+        _ => x_for_s(s as u64) as i64,
+    };
+    x - MASTER_CENTRE
+}
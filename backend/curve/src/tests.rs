@@ -1,11 +1,16 @@
 //! Tests for LUT delta mapping and inversion properties.
 
-use crate::consts::LUT_S_MAX;
-use crate::consts::LUT_X_MAX;
-use crate::consts::LUT_X_MIN;
+use crate::consts;
+use crate::fixed::{Q1648, Q824};
+
+// The property tests work on raw bits so they can use wrapping (two's complement) arithmetic
+const LUT_S_MAX: u64 = consts::LUT_S_MAX.0;
+const LUT_X_MAX: u32 = consts::LUT_X_MAX.0;
+const LUT_X_MIN: u32 = consts::LUT_X_MIN.0;
 
 use super::lut::*;
 use super::math::*;
+use super::shape::*;
 
 const MARGIN: u32 = (1u32 << 24) / 4; // 0.25 in Q8.24
 
@@ -102,7 +107,7 @@ fn gen_dx_bounded_magnitude(rng: &mut Rng, x0: u32, mag_limit: u32) -> u32 {
 /// Zero movement yields zero cost delta (fixed point of the mapping).
 #[test]
 fn zero_delta_is_identity() {
-    assert_eq!(ds_for_dx(midpoint(), 0), 0);
+    assert_eq!(Sigmoid.ds_for_dx(Q824(midpoint()), 0), 0);
 }
 
 /// `ds_for_dx` matches direct cost function evaluation across 50k random in-domain steps.
@@ -116,7 +121,7 @@ fn delta_matches_cost_difference() {
         let dx = gen_dx_bounded(&mut rng, x0);
         let x1 = x0.wrapping_add(dx);
 
-        let ds = ds_for_dx(x0, dx);
+        let ds = Sigmoid.ds_for_dx(Q824(x0), dx);
         let cost_diff = evaluate_cost(x1).wrapping_sub(evaluate_cost(x0));
         assert_eq!(ds, cost_diff);
     }
@@ -143,8 +148,10 @@ fn path_independence_two_steps() {
             continue;
         }
 
-        let direct = ds_for_dx(x0, dx1.wrapping_add(dx2));
-        let step = ds_for_dx(x0, dx1).wrapping_add(ds_for_dx(x_mid, dx2));
+        let direct = Sigmoid.ds_for_dx(Q824(x0), dx1.wrapping_add(dx2));
+        let step = Sigmoid
+            .ds_for_dx(Q824(x0), dx1)
+            .wrapping_add(Sigmoid.ds_for_dx(Q824(x_mid), dx2));
         assert_eq!(direct, step);
     }
 }
@@ -165,11 +172,11 @@ fn path_independence_many_steps() {
             if dx == 0 {
                 continue;
             }
-            sum = sum.wrapping_add(ds_for_dx(x, dx));
+            sum = sum.wrapping_add(Sigmoid.ds_for_dx(Q824(x), dx));
             x = x.wrapping_add(dx);
         }
 
-        let direct = ds_for_dx(x_start, x.wrapping_sub(x_start));
+        let direct = Sigmoid.ds_for_dx(Q824(x_start), x.wrapping_sub(x_start));
         assert_eq!(direct, sum);
     }
 }
@@ -189,8 +196,8 @@ fn antisymmetry() {
         }
         let x2 = x.wrapping_add(dx);
 
-        let a = ds_for_dx(x, dx);
-        let b = ds_for_dx(x2, (0u32).wrapping_sub(dx)); // two's complement negation: -dx
+        let a = Sigmoid.ds_for_dx(Q824(x), dx);
+        let b = Sigmoid.ds_for_dx(Q824(x2), (0u32).wrapping_sub(dx)); // two's complement negation: -dx
         assert_eq!(a, (0u64).wrapping_sub(b));
     }
 }
@@ -223,7 +230,7 @@ fn invert_exact_at_lut_points() {
         ] {
             let x1 = X_LUT[j];
             let ds = S_LUT[j].wrapping_sub(s0);
-            let dx = dx_for_ds(x0, s0, ds);
+            let dx = Sigmoid.dx_for_ds(Q824(x0), Q1648(s0), ds);
             assert_eq!(x0.wrapping_add(dx), x1);
         }
     }
@@ -242,7 +249,7 @@ fn invert_round_trip_near_exact() {
         let s0 = evaluate_cost(x0);
         let ds = evaluate_cost(x1).wrapping_sub(s0);
 
-        let dx_inv = dx_for_ds(x0, s0, ds);
+        let dx_inv = Sigmoid.dx_for_ds(Q824(x0), Q1648(s0), ds);
         let x1_inv = x0.wrapping_add(dx_inv);
         let diff = x1_inv.abs_diff(x1);
 
@@ -261,7 +268,7 @@ fn invert_midpoint_between_samples() {
         let s_mid = ((s0 as u128 + s1 as u128) / 2) as u64;
         let x_mid_expected = ((x0 as u64 + x1 as u64) / 2) as u32;
 
-        let dx = dx_for_ds(x0, s0, s_mid - s0);
+        let dx = Sigmoid.dx_for_ds(Q824(x0), Q1648(s0), s_mid - s0);
         let x_mid = x0 + dx;
         let diff = x_mid.abs_diff(x_mid_expected);
 
@@ -286,7 +293,7 @@ fn invert_midpoint_between_samples() {
 #[test]
 fn capacity_scale_full_range() {
     let cmax = 1_000_000u64;
-    assert_eq!(ds_for_dc(cmax, cmax), LUT_S_MAX);
+    assert_eq!(Sigmoid.ds_for_dc(cmax, cmax), LUT_S_MAX);
 }
 
 /// Composition test: dx_for_dc breaks down as dc → ds → dx.
@@ -296,9 +303,9 @@ fn dx_for_dc_consistency() {
     let (x0, cmax, dc) = (midpoint(), 1_000_000u64, 12_345u64);
     let s0 = evaluate_cost(x0);
 
-    let ds = ds_for_dc(dc, cmax);
-    let dx_direct = dx_for_ds(x0, s0, ds);
-    let (dx, _) = dx_for_dc(x0, s0, dc, cmax);
+    let ds = Sigmoid.ds_for_dc(dc, cmax);
+    let dx_direct = Sigmoid.dx_for_ds(Q824(x0), Q1648(s0), ds);
+    let (dx, _) = dx_for_dc(Q824(x0), Q1648(s0), dc, cmax);
 
     assert_eq!(dx, dx_direct);
 }
@@ -310,11 +317,11 @@ fn dc_for_dx_consistency() {
     let dx = ((LUT_X_MAX as u64 - LUT_X_MIN as u64) / 8) as u32;
     let (x0, cmax) = (midpoint(), 1_000_000u64);
 
-    let ds = ds_for_dx(x0, dx);
+    let ds = Sigmoid.ds_for_dx(Q824(x0), dx);
     let num = (ds as u128) * (cmax as u128);
     let den = LUT_S_MAX as u128;
     let dc_expected = (num + den / 2) / den;
-    let dc = dc_for_dx(x0, dx, cmax);
+    let dc = dc_for_dx(Q824(x0), dx, cmax);
 
     assert_eq!(dc, dc_expected as u64);
 }
@@ -332,7 +339,7 @@ fn dc_for_dx_consistency() {
 #[test]
 fn clamp_at_x_max() {
     let (x0, cmax) = (LUT_X_MAX, 1_000_000u64);
-    let (dx, _) = dx_for_dc(x0, evaluate_cost(x0), 1000, cmax);
+    let (dx, _) = dx_for_dc(Q824(x0), Q1648(evaluate_cost(x0)), 1000, cmax);
     assert_eq!(dx, 0, "can't move beyond X_MAX");
 }
 
@@ -342,7 +349,7 @@ fn clamp_at_x_max() {
 fn clamp_at_x_min() {
     let x0 = LUT_X_MIN;
     let s0 = evaluate_cost(x0);
-    let dx = dx_for_ds(x0, s0, (-1_000_000_000i64) as u64);
+    let dx = Sigmoid.dx_for_ds(Q824(x0), Q1648(s0), (-1_000_000_000i64) as u64);
     let x1 = x0.wrapping_add(dx);
     assert!(
         in_range(x1, LUT_X_MIN, LUT_X_MAX),
@@ -356,7 +363,7 @@ fn clamp_at_x_min() {
 #[test]
 fn clamp_partial_movement() {
     let x0 = LUT_X_MAX - 100_000;
-    let (dx, _) = dx_for_dc(x0, evaluate_cost(x0), 100_000, 1_000_000u64);
+    let (dx, _) = dx_for_dc(Q824(x0), Q1648(evaluate_cost(x0)), 100_000, 1_000_000u64);
     let x1 = x0.wrapping_add(dx);
 
     assert!(
@@ -370,7 +377,7 @@ fn clamp_partial_movement() {
 /// Tests that the evaluate_cost clamping propagates through dc_for_dx correctly.
 #[test]
 fn clamp_dc_for_dx_at_boundary() {
-    let dc = dc_for_dx(LUT_X_MAX, 1_000_000, 1_000_000u64);
+    let dc = dc_for_dx(consts::LUT_X_MAX, 1_000_000, 1_000_000u64);
     assert_eq!(dc, 0, "overshooting dx yields zero dc");
 }
 
@@ -378,6 +385,429 @@ fn clamp_dc_for_dx_at_boundary() {
 /// Tests that evaluate_cost clamping protects against out-of-bounds wrapping.
 #[test]
 fn clamp_ds_for_dx_wraps_safely() {
-    let ds = ds_for_dx(LUT_X_MIN, (i32::MIN) as u32);
+    let ds = Sigmoid.ds_for_dx(consts::LUT_X_MIN, (i32::MIN) as u32);
     assert!(ds > 0, "extreme wraparound handled safely");
 }
+
+// ===== Curve Shape Tests =====
+//
+// Every preset must be a non-decreasing cost anchored at zero, with an inverse that
+// round-trips within a small tolerance, so the generic delta helpers stay well-behaved.
+
+const ONE: u32 = 1 << 24;
+
+/// Cost is zero at X_MIN and non-decreasing across the domain for every preset.
+#[test]
+fn shapes_monotone_from_zero() {
+    for shape in SHAPES {
+        assert_eq!(
+            shape.evaluate_cost(consts::LUT_X_MIN),
+            Q1648::ZERO,
+            "{shape:?}"
+        );
+        let mut prev = Q1648::ZERO;
+        for step in 0..=600 {
+            let x = Q824(LUT_X_MIN + (LUT_X_MAX - LUT_X_MIN) / 600 * step);
+            let s = shape.evaluate_cost(x);
+            assert!(s >= prev, "{shape:?} decreases at {x}");
+            prev = s;
+        }
+        assert_eq!(
+            shape.s_max(),
+            shape.evaluate_cost(consts::LUT_X_MAX),
+            "{shape:?}"
+        );
+    }
+}
+
+/// `x_for_s(evaluate_cost(x))` returns to within 2^-12 of x for every preset.
+#[test]
+fn shapes_invert_round_trip() {
+    let mut rng = Rng::new(11);
+    for shape in SHAPES {
+        for _ in 0..5_000 {
+            let x = Q824(gen_x_in_domain(&mut rng));
+            let back = shape.x_for_s(shape.evaluate_cost(x));
+            assert!(back.abs_diff(x).0 <= ONE >> 12, "{shape:?}: {x} -> {back}");
+        }
+        assert_eq!(shape.x_for_s(Q1648::ZERO), consts::LUT_X_MIN);
+        assert_eq!(shape.x_for_s(Q1648::MAX), consts::LUT_X_MAX);
+    }
+}
+
+/// The generic delta helpers clamp at the domain edges for every preset.
+#[test]
+fn shapes_clamp_partial_movement() {
+    for shape in SHAPES {
+        let x0 = Q824(LUT_X_MAX - 100_000);
+        let (dx, _) = shape.dx_for_dc(x0, shape.evaluate_cost(x0), 1_000_000, 1_000_000);
+        assert_eq!(x0.0.wrapping_add(dx), LUT_X_MAX, "{shape:?}");
+    }
+}
+
+/// Id 0 is the master sigmoid and unknown ids have no preset.
+#[test]
+fn shape_ids() {
+    assert_eq!(Shape::from_id(0), Some(Shape::Sigmoid(Sigmoid)));
+    assert_eq!(Shape::default(), Shape::Sigmoid(Sigmoid));
+    assert_eq!(Shape::from_id(SHAPES.len() as u32), None);
+}
+
+/// Sigmoid shape and the free functions are the same curve.
+#[test]
+fn sigmoid_shape_matches_free_functions() {
+    let mut rng = Rng::new(12);
+    for _ in 0..10_000 {
+        let x0 = Q824(gen_x_in_domain(&mut rng));
+        let dc = rng.next_u32() as u64;
+        let s0 = Q1648(evaluate_cost(x0.0));
+        assert_eq!(
+            Sigmoid.dx_for_dc(x0, s0, dc, 1 << 40),
+            dx_for_dc(x0, s0, dc, 1 << 40)
+        );
+    }
+    assert_eq!(Sigmoid.s_max(), consts::LUT_S_MAX);
+}
+
+/// Linear cost equals saturation, so a full-capacity deposit spans the domain evenly.
+#[test]
+fn linear_cost_is_saturation() {
+    assert_eq!(Linear.evaluate_cost(Q824(3 * ONE)), Q1648(3 << 48));
+    assert_eq!(Linear.x_for_s(Q1648(3 << 48)), Q824(3 * ONE));
+    assert_eq!(Linear.dc_for_dx(Q824::ZERO, LUT_X_MAX / 2, 600), 300);
+}
+
+/// Exponential price doubles every `1 / rate`: with rate 0.5, cost over [0, 2] is a third
+/// of cost over [0, 4] ((2^1 - 1) vs (2^2 - 1)), and the full span is
+/// `(2^(0.5·x_max) - 1) / (0.5 ln 2)`.
+#[test]
+fn exponential_price_doubles() {
+    let shape = Exponential::new(Q824(ONE / 2));
+    let cost = |x| shape.evaluate_cost(Q824(x)).0;
+    let (s2, s4) = (cost(2 * ONE), cost(4 * ONE));
+    assert!((s4 as i128 - 3 * s2 as i128).abs() < 1 << 24, "{s2} {s4}");
+    let x_max = LUT_X_MAX as f64 / ONE as f64;
+    let span = (2f64.powf(x_max / 2.0) - 1.0) / (0.5 * core::f64::consts::LN_2);
+    let expected = (span * (1u64 << 48) as f64) as u64;
+    assert!(shape.s_max().0.abs_diff(expected) < 1 << 28);
+}
+
+/// A tuned sigmoid with the master's inflection and unit steepness is the master curve.
+#[test]
+fn tuned_sigmoid_reproduces_master() {
+    let shape = TunedSigmoid::new(Q824(LUT_X_MAX / 2), Q824::ONE);
+    let mut rng = Rng::new(13);
+    for _ in 0..10_000 {
+        let x = Q824(gen_x_in_domain(&mut rng));
+        assert_eq!(shape.evaluate_cost(x).0, evaluate_cost(x.0));
+        let s = shape.evaluate_cost(x);
+        assert_eq!(shape.x_for_s(s), Sigmoid.x_for_s(s));
+    }
+}
+
+/// Moving the inflection moves where the price is steepest: an early curve has spent more
+/// of its span by the master inflection point.
+#[test]
+fn tuned_sigmoid_inflection_shifts_cost() {
+    let mid = Q824(LUT_X_MAX / 2);
+    let [early, master, late] =
+        [mid - Q824::ONE, mid, mid + Q824::ONE].map(|m| TunedSigmoid::new(m, Q824::ONE));
+    let share = |shape: TunedSigmoid| shape.evaluate_cost(mid).to_f64() / shape.s_max().to_f64();
+    assert!(share(early) > share(master) && share(master) > share(late));
+}
+
+/// The default bisection inverse agrees with closed-form inverses.
+#[test]
+fn bisection_inverse_matches_closed_form() {
+    struct Bisect<S>(S);
+    impl<S: CurveShape> CurveShape for Bisect<S> {
+        fn evaluate_cost(&self, x: Q824) -> Q1648 {
+            self.0.evaluate_cost(x)
+        }
+    }
+    let mut rng = Rng::new(14);
+    for _ in 0..2_000 {
+        let s = Linear.evaluate_cost(Q824(gen_x_in_domain(&mut rng))) + Q1648(12_345);
+        assert!(Bisect(Linear).x_for_s(s).abs_diff(Linear.x_for_s(s)).0 <= 1);
+    }
+}
+
+// ===== Indexed Lookup Tests =====
+
+/// The indexed searches report exactly what a binary search over the full column does: at
+/// every sample, either side of it, and at random values including past the end of `S_LUT`.
+#[test]
+fn indexed_search_matches_binary_search() {
+    for (&x, &s) in X_LUT.iter().zip(S_LUT.iter()) {
+        for x in [x.saturating_sub(1), x, x + 1] {
+            assert_eq!(search_x(x), X_LUT.binary_search(&x), "x = {x}");
+        }
+        for s in [s.saturating_sub(1), s, s + 1] {
+            assert_eq!(search_s(s), S_LUT.binary_search(&s), "s = {s}");
+        }
+    }
+    let mut rng = Rng::new(19);
+    for _ in 0..10_000 {
+        let x = gen_x_in_domain(&mut rng);
+        assert_eq!(search_x(x), X_LUT.binary_search(&x), "x = {x}");
+        let s = (rng.next_u32() as u64) << 19 | rng.next_u32() as u64;
+        assert_eq!(search_s(s), S_LUT.binary_search(&s), "s = {s}");
+    }
+    for s in [LUT_S_MAX + 1, u64::MAX] {
+        assert_eq!(search_s(s), Err(S_LUT.len()));
+    }
+}
+
+// ===== Error Analysis Tests =====
+//
+// Bounds on interpolation error and round-trip drift, and the no-leak invariant, for the
+// configured LUT. A table or shape change that loosens them fails here instead of leaking.
+
+const CAPACITIES: [u64; 3] = [1_000, 1_000_000_000, 1 << 40];
+
+fn trials(seed: u64, cmax: u64) -> Vec<(Q824, u64)> {
+    let mut rng = Rng::new(seed);
+    (0..5_000)
+        .map(|_| {
+            let dc = ((rng.next_u32() as u64) << 32 | rng.next_u32() as u64) % cmax;
+            (Q824(gen_x_in_domain(&mut rng)), dc)
+        })
+        .collect()
+}
+
+/// Interpolation stays within 1e-6 of the cumulative span for the committed 1025-sample
+/// table, and within 5e-6 for the other `lut-*` sizes (geometric spacing widens the end
+/// segments as the sample count grows, so the error is not monotone in size).
+#[test]
+fn interpolation_error_bounded() {
+    use crate::analysis::interpolation_bounds;
+
+    let bounds = interpolation_bounds(8);
+    let limit = match X_LUT.len() {
+        1025 => 1e-6,
+        _ => 5e-6,
+    };
+    assert!(bounds.max_abs / LUT_S_MAX as f64 <= limit, "{bounds:?}");
+    assert!(bounds.max_rel < 2e-2, "{bounds:?}");
+}
+
+/// Every segment's interpolation lies within the global bound, and the worst error is away
+/// from the exact sample points.
+#[test]
+fn interpolation_error_per_segment() {
+    use crate::analysis::{exact_cost, interpolation_bounds, segment_errors};
+
+    let segments = segment_errors(8);
+    assert_eq!(segments.len(), X_LUT.len() - 1);
+    let bound = interpolation_bounds(8).max_abs;
+    for segment in &segments {
+        assert!(segment.max_abs <= bound);
+    }
+    // LUT samples are rounded evaluations of the exact curve
+    for (&x, &s) in X_LUT.iter().zip(S_LUT.iter()) {
+        assert!((s as f64 - exact_cost(Q824(x))).abs() <= 1.0);
+    }
+}
+
+/// `dx_for_dc` then `dc_for_dx` returns within one saturation step's worth of capacity
+/// (per step of inverse drift) for every preset.
+#[test]
+fn round_trip_drift_within_resolution() {
+    use crate::analysis::{round_trip, step_value};
+
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            let result = round_trip(&shape, cmax, trials(21, cmax));
+            let step = step_value(&shape, cmax);
+            assert!(
+                result.max_dc_drift <= (result.max_x_drift.0 as u64 + 1) * step,
+                "{shape:?} {cmax}: {result:?}, step {step}"
+            );
+        }
+    }
+}
+
+/// Deposit then withdraw never returns more capacity than was deposited.
+#[test]
+fn deposit_then_withdraw_never_leaks() {
+    use crate::analysis::deposit_withdraw;
+
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            let leak = deposit_withdraw(&shape, cmax, trials(22, cmax));
+            assert!(leak.holds(), "{shape:?} {cmax}: {leak:?}");
+            assert_eq!(leak.trials, 5_000);
+        }
+    }
+}
+
+/// Withdrawing the deposited capacity (two's complement) walks saturation back to where it
+/// started, tracking cumulative cost the way element pressure does.
+#[test]
+fn withdrawal_mirrors_deposit() {
+    let mut rng = Rng::new(23);
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            for _ in 0..1_000 {
+                let x0 = gen_x_with_margin(&mut rng);
+                let dc = rng.next_u32() as u64 % (cmax / 100);
+                let s0 = shape.evaluate_cost(Q824(x0)).0;
+                let (dx, ds) = shape.dx_for_dc(Q824(x0), Q1648(s0), dc, cmax);
+                let (x1, s1) = (x0.wrapping_add(dx), s0.wrapping_add(ds));
+                let (back, ds_back) = shape.dx_for_dc(Q824(x1), Q1648(s1), dc.wrapping_neg(), cmax);
+                assert_eq!(ds_back, ds.wrapping_neg());
+                assert!(
+                    x1.wrapping_add(back).abs_diff(x0) <= 4,
+                    "{shape:?} {x0} {dc}"
+                );
+            }
+        }
+    }
+}
+
+/// Withdrawing past the bottom of the curve stops at X_MIN instead of wrapping to the top.
+#[test]
+fn withdrawal_clamps_at_x_min() {
+    for shape in SHAPES {
+        let x0 = LUT_X_MIN + MARGIN;
+        let s0 = shape.evaluate_cost(Q824(x0));
+        let dx = shape.dx_for_ds(Q824(x0), s0, s0.0.wrapping_add(1).wrapping_neg());
+        assert_eq!(x0.wrapping_add(dx), LUT_X_MIN, "{shape:?}");
+    }
+}
+
+/// A deposit never buys saturation that costs more than it paid, on either API. Rounding the
+/// target saturation to nearest handed the mover up to half a step of cost for free, which a
+/// later withdrawal then paid out of the other binders' capacity.
+#[test]
+fn deposit_never_buys_past_its_cost() {
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            for (x0, dc) in trials(24, cmax) {
+                let s0 = shape.evaluate_cost(x0);
+                let (dx, ds) = shape.dx_for_dc(x0, s0, dc, cmax);
+                assert!(
+                    shape.ds_for_dx(x0, dx) <= ds,
+                    "{shape:?} {cmax}: {x0:?} {dc}"
+                );
+
+                let (dx, ds) = shape
+                    .try_dx_for_dc(x0, s0, Delta::Deposit(dc), cmax)
+                    .unwrap();
+                let bought = shape.try_ds_for_dx(x0, dx).unwrap();
+                assert!(
+                    bought.magnitude() <= ds.magnitude(),
+                    "{shape:?} {cmax}: {x0:?} {dc}"
+                );
+            }
+        }
+    }
+}
+
+/// A negative delta (two's complement) scales to the negated scale of its magnitude. Scaling
+/// the raw bits read a withdrawal as a deposit of nearly `2^64`.
+#[test]
+fn negative_delta_scales_by_magnitude() {
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            for dc in [1, cmax / 3, cmax - 1] {
+                let ds = shape.ds_for_dc(dc, cmax);
+                assert_eq!(shape.ds_for_dc(dc.wrapping_neg(), cmax), ds.wrapping_neg());
+                let back = shape.dc_for_ds(ds, cmax);
+                assert_eq!(
+                    shape.dc_for_ds(ds.wrapping_neg(), cmax),
+                    back.wrapping_neg()
+                );
+            }
+        }
+    }
+}
+
+// ===== Typed Delta Tests =====
+
+use crate::{delta::Delta, error::CurveError};
+
+#[test]
+fn delta_signed_encodings() {
+    assert_eq!(Delta::from(-5i64), Delta::Withdrawal(5u64));
+    assert_eq!(Delta::from(5i64), Delta::Deposit(5u64));
+    assert_eq!(Delta::Withdrawal(1u64 << 63).to_signed(), Some(i64::MIN));
+    assert_eq!(Delta::Deposit(1u64 << 63).to_signed(), None);
+    assert_eq!(
+        Delta::<u32>::from_twos_complement(u32::MAX),
+        Delta::Withdrawal(1)
+    );
+    assert_eq!(Delta::Withdrawal(1u32).to_twos_complement(), u32::MAX);
+    assert_eq!(-Delta::Deposit(7u32), Delta::Withdrawal(7));
+    assert_eq!(
+        Delta::Withdrawal(3u64).checked_apply(2),
+        Err(CurveError::Underflow)
+    );
+    assert_eq!(
+        Delta::Deposit(1u64).checked_apply(u64::MAX),
+        Err(CurveError::Overflow)
+    );
+}
+
+/// The typed helpers agree with the two's complement ones wherever both are defined.
+#[test]
+fn typed_deltas_match_twos_complement() {
+    let mut rng = Rng::new(31);
+    for shape in SHAPES {
+        for cmax in CAPACITIES {
+            for _ in 0..2_000 {
+                let x0 = Q824(rng.gen_u32(LUT_X_MIN, LUT_X_MAX));
+                let s0 = shape.evaluate_cost(x0);
+                let dc = (rng.next_u32() as u64 % (2 * cmax)) as i64 - cmax as i64;
+                let (dx, ds) = shape.dx_for_dc(x0, s0, dc as u64, cmax);
+                let (tdx, tds) = shape.try_dx_for_dc(x0, s0, dc.into(), cmax).unwrap();
+                assert_eq!(
+                    (
+                        tdx.map(|x| x.0).to_twos_complement(),
+                        tds.map(|s| s.0).to_twos_complement()
+                    ),
+                    (dx, ds)
+                );
+
+                let x1 = Q824(rng.gen_u32(LUT_X_MIN, LUT_X_MAX));
+                let step = Delta::<u32>::from_signed(x1.0 as i32 - x0.0 as i32).map(Q824);
+                let dc = shape.try_dc_for_dx(x0, step, cmax).unwrap();
+                assert_eq!(
+                    dc.to_twos_complement(),
+                    shape.dc_for_dx(x0, x1.0.wrapping_sub(x0.0), cmax)
+                );
+                assert_eq!(dc.is_withdrawal(), x1 < x0);
+            }
+        }
+    }
+}
+
+/// Cases the two's complement helpers wrap or misread come back as typed errors.
+#[test]
+fn typed_deltas_reject_out_of_range() {
+    let x0 = Q824(LUT_X_MIN + MARGIN);
+    let s0 = Sigmoid.evaluate_cost(x0);
+    assert_eq!(
+        Sigmoid.try_dx_for_dc(x0, s0, Delta::Deposit(1), 0),
+        Err(CurveError::ZeroCapacity)
+    );
+    // Scaling this deposit exceeds u64; the raw helper truncates it
+    assert_eq!(
+        Sigmoid.try_ds_for_dc(Delta::Deposit(u64::MAX), 1),
+        Err(CurveError::Overflow)
+    );
+    // Stepping below zero wraps to the top of the domain in the raw helper
+    assert_eq!(
+        Sigmoid.try_ds_for_dx(x0, Delta::Withdrawal(x0 + Q824(1))),
+        Err(CurveError::OutOfDomain)
+    );
+    assert_eq!(
+        Sigmoid.try_dx_for_ds(Q824(LUT_X_MAX + 1), Q1648::ZERO, Delta::Deposit(Q1648(1))),
+        Err(CurveError::OutOfDomain)
+    );
+    // A deposit past i64::MAX is still a deposit
+    let (dx, _) = Sigmoid
+        .try_dx_for_dc(x0, s0, Delta::Deposit(1 << 63), 1 << 62)
+        .unwrap();
+    assert_eq!(dx, Delta::Deposit(consts::LUT_X_MAX - x0));
+}
@@ -11,6 +11,8 @@ pub use nucleus::types::{AddressBytes, Coordinates, ElementIndex, Gluon, Q1648,
 // Re-export core game functions
 pub use nucleus::action::{claim, compress, rebind};
 pub use nucleus::fees::{bind_fee, compression_fee, fee_multiplier, rebind_fee, unbind_fee};
+pub use nucleus::pressure::{heat, history_pressure, mean_saturation, verify_history};
+pub use nucleus::quote::{gluon_to_overload, saturation_after, share_for_deposit};
 pub use nucleus::round_divide;
 
 // Re-export constants
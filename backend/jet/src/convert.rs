@@ -12,6 +12,7 @@
 use crate::api::{Action, BoardEvent, PlayerEvent, SnapshotResponse};
 use crate::fb::tokamak as fb;
 use crate::tokamak;
+use nucleus::pressure;
 use std::fmt;
 
 // ============================================================================
@@ -64,11 +65,13 @@ impl From<&tokamak::AddressBytes> for fb::AddressBytes {
 impl From<fb::Curve<'_>> for tokamak::Curve {
     fn from(fb: fb::Curve<'_>) -> Self {
         tokamak::Curve {
-            capacity: fb.capacity(),
-            tvl: fb.tvl(),
-            pressure: fb.pressure(),
-            saturation: fb.saturation(),
-            shares: fb.shares(),
+            capacity: tokamak::Gluon(fb.capacity()),
+            tvl: tokamak::Gluon(fb.tvl()),
+            pressure: tokamak::Q1648(fb.pressure()),
+            saturation: tokamak::Q824(fb.saturation()),
+            shares: tokamak::Q824(fb.shares()),
+            shape: fb.shape(),
+            _pad: 0,
         }
     }
 }
@@ -77,11 +80,13 @@ impl From<fb::Curve<'_>> for tokamak::Curve {
 impl From<&tokamak::Curve> for fb::CurveArgs {
     fn from(curve: &tokamak::Curve) -> Self {
         fb::CurveArgs {
-            capacity: curve.capacity,
-            tvl: curve.tvl,
-            pressure: curve.pressure,
-            saturation: curve.saturation,
-            shares: curve.shares,
+            capacity: curve.capacity.0,
+            tvl: curve.tvl.0,
+            pressure: curve.pressure.0,
+            saturation: curve.saturation.0,
+            shares: curve.shares.0,
+            shape: curve.shape,
+            heat: pressure::heat(curve).0,
         }
     }
 }
@@ -90,10 +95,12 @@ impl From<&tokamak::Curve> for fb::CurveArgs {
 impl From<fb::Element<'_>> for tokamak::Element {
     fn from(fb: fb::Element<'_>) -> Self {
         tokamak::Element {
-            pot: fb.pot(),
+            pot: tokamak::Gluon(fb.pot()),
             index: fb.index().into(),
             curve: fb.curve().unwrap().into(),
             coordinates: fb.coordinates().into(),
+            charges: fb.charges(),
+            _pad: 0,
         }
     }
 }
@@ -102,8 +109,8 @@ impl From<fb::Element<'_>> for tokamak::Element {
 impl From<fb::Board<'_>> for tokamak::Board {
     fn from(fb: fb::Board<'_>) -> Self {
         tokamak::Board {
-            tvl: fb.tvl(),
-            quantum_pocket: fb.quantum_pocket(),
+            tvl: tokamak::Gluon(fb.tvl()),
+            quantum_pocket: tokamak::Gluon(fb.quantum_pocket()),
             charge_count: fb.charge_count(),
             quantum_index: fb.quantum_index(),
             _pad: [0; 3],
@@ -115,8 +122,8 @@ impl From<fb::Board<'_>> for tokamak::Board {
 impl From<&tokamak::Board> for fb::BoardArgs {
     fn from(board: &tokamak::Board) -> Self {
         fb::BoardArgs {
-            tvl: board.tvl,
-            quantum_pocket: board.quantum_pocket,
+            tvl: board.tvl.0,
+            quantum_pocket: board.quantum_pocket.0,
             charge_count: board.charge_count,
             quantum_index: board.quantum_index,
         }
@@ -128,9 +135,11 @@ impl From<fb::Artefact<'_>> for tokamak::Artefact {
     fn from(fb: fb::Artefact<'_>) -> Self {
         use bytemuck::Zeroable;
         let mut art = tokamak::Artefact::zeroed();
-        art.pot = fb.pot();
+        art.pot = tokamak::Gluon(fb.pot());
         art.index = fb.index().into();
-        art.shares = fb.shares();
+        art.shares = tokamak::Q824(fb.shares());
+        art.tvl = tokamak::Gluon(fb.tvl());
+        art.charges = fb.charges();
         art
     }
 }
@@ -139,9 +148,11 @@ impl From<fb::Artefact<'_>> for tokamak::Artefact {
 impl From<&tokamak::Artefact> for fb::ArtefactArgs {
     fn from(art: &tokamak::Artefact) -> Self {
         fb::ArtefactArgs {
-            pot: art.pot,
+            pot: art.pot.0,
             index: art.index.into(),
-            shares: art.shares,
+            shares: art.shares.0,
+            tvl: art.tvl.0,
+            charges: art.charges,
         }
     }
 }
@@ -155,10 +166,11 @@ impl From<fb::Wallet<'_>> for tokamak::Wallet {
     fn from(fb: fb::Wallet<'_>) -> Self {
         use bytemuck::Zeroable;
         let mut wallet = tokamak::Wallet::zeroed();
-        wallet.balance = fb.balance();
+        wallet.balance = tokamak::Gluon(fb.balance());
         wallet.authority = fb.authority().into();
         wallet.mint = fb.mint().into();
         wallet.charges = fb.charges();
+        wallet.open = fb.open();
         wallet
     }
 }
@@ -168,10 +180,10 @@ impl From<fb::Charge<'_>> for tokamak::Charge {
     fn from(fb: fb::Charge<'_>) -> Self {
         use bytemuck::Zeroable;
         let mut charge = tokamak::Charge::zeroed();
-        charge.balance = fb.balance();
+        charge.balance = tokamak::Gluon(fb.balance());
         charge.timestamp = fb.timestamp();
         charge.index = fb.index().into();
-        charge.share = fb.share();
+        charge.share = tokamak::Q824(fb.share());
         charge.authority = fb.authority().into();
         charge.mint = fb.mint().into();
         charge
@@ -18,10 +18,11 @@ pub(crate) fn serialize_element<'a>(
     let curve = fb::Curve::create(fbb, &fb::CurveArgs::from(&element.curve));
     let curve = Some(curve);
     let args = &fb::ElementArgs {
-        pot: element.pot,
+        pot: element.pot.0,
         index: element.index.into(),
         curve,
         coordinates: element.coordinates.into(),
+        charges: element.charges,
     };
     fb::Element::create(fbb, args)
 }
@@ -41,10 +42,11 @@ pub(crate) fn serialize_wallet<'a>(
     let authority = Some(&fb::AddressBytes(wallet.authority));
     let mint = Some(&fb::AddressBytes(wallet.mint));
     let args = &fb::WalletArgs {
-        balance: wallet.balance,
+        balance: wallet.balance.0,
         authority,
         mint,
         charges: wallet.charges,
+        open: wallet.open,
     };
     fb::Wallet::create(fbb, args)
 }
@@ -56,10 +58,10 @@ pub(crate) fn serialize_charge<'a>(
     let authority = Some(&fb::AddressBytes(charge.authority));
     let mint = Some(&fb::AddressBytes(charge.mint));
     let args = &fb::ChargeArgs {
-        balance: charge.balance,
+        balance: charge.balance.0,
         timestamp: charge.timestamp,
         index: charge.index.into(),
-        share: charge.share,
+        share: charge.share.0,
         authority,
         mint,
     };
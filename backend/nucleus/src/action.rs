@@ -1,34 +1,99 @@
-//! Core game actions: rebinding, claiming rewards, compressing pots, and venting value.
+//! Core game actions: rebinding, claiming rewards, compressing pots, splitting and merging
+//! charges.
+//!
+//! Board accounting: `Board::tvl` is the curve TVL of every live element plus the outstanding
+//! TVL of every artefact, and `Board::charge_count` is the bound charges of every live element
+//! plus the unclaimed charges of every artefact. Only `bind`, `unbind`, `overload` and
+//! `release` change either total; `rebind` and `compress` move value between curves, and
+//! `split` and `merge` only change how many charges hold a curve's stake.
 
-use curve::math::dx_for_dc;
+use curve::{delta::Delta, error::CurveError, shape::CurveShape};
 
 use crate::{
-    board::{Artefact, Curve, Element},
+    board::{capacity, Artefact, Board, Curve, Element},
+    consts::{GENESIS_TVL, MAX_ATOMIC_NUMBER},
+    error::TokamakError,
     player::Charge,
-    round_divide,
-    types::Q824,
+    quantum, round_divide,
+    types::{Coordinates, ElementIndex, Gluon, Q1648, Q824},
 };
 
+/// Bind an off-board charge onto `dst`; its balance enters the board totals.
+pub fn bind(charge: &mut Charge, dst: &mut Element, board: &mut Board) -> Result<(), TokamakError> {
+    rebind(charge, &mut off_board(), dst)?;
+    board.tvl += charge.balance;
+    board.charge_count += 1;
+    Ok(())
+}
+
+/// Unbind a charge from `src` and take it off the board.
+pub fn unbind(
+    charge: &mut Charge,
+    src: &mut Element,
+    board: &mut Board,
+) -> Result<(), TokamakError> {
+    rebind(charge, src, &mut off_board())?;
+    board.tvl -= charge.balance;
+    board.charge_count -= 1;
+    Ok(())
+}
+
+/// Placeholder element for the off-board side of a bind or unbind (zero index, no curve).
+fn off_board() -> Element {
+    Element {
+        pot: Gluon::ZERO,
+        index: ElementIndex(0),
+        curve: Curve {
+            capacity: Gluon::ZERO,
+            tvl: Gluon::ZERO,
+            pressure: Q1648::ZERO,
+            saturation: Q824::ZERO,
+            shares: Q824::ZERO,
+            shape: 0,
+            _pad: 0,
+        },
+        coordinates: Coordinates(0),
+        charges: 0,
+        _pad: 0,
+    }
+}
+
 /// Rebind a charge from one element to another: unbind from src, bind to dst.
 /// Updates saturation and pressure on both elements.
-pub fn rebind(charge: &mut Charge, src: &mut Element, dst: &mut Element) {
-    // Unbind: negate balance to get withdrawal delta.
-    let withdrawal = charge.balance.wrapping_neg(); // Two's complement negation
-    update_curve(withdrawal, src);
+///
+/// Fails with [`TokamakError::CurveOutOfRange`] if either curve cannot absorb the move; the
+/// elements may then be partially updated, so callers abort the whole action.
+pub fn rebind(
+    charge: &mut Charge,
+    src: &mut Element,
+    dst: &mut Element,
+) -> Result<(), TokamakError> {
+    update_curve(Delta::Withdrawal(charge.balance), src)?;
     // We have unbound from the curve, remove our shares to the pool
     src.curve.shares -= charge.share;
+    if !src.index.is_zero() {
+        src.charges -= 1;
+    }
     // Bind: positive delta to destination, capture new share.
-    charge.share = update_curve(charge.balance, dst);
+    charge.share = update_curve(Delta::Deposit(charge.balance), dst)?.magnitude();
     // We have bound to the curve, add our shares to the pool
     dst.curve.shares += charge.share;
+    if !dst.index.is_zero() {
+        dst.charges += 1;
+    }
     charge.index = dst.index;
+    Ok(())
 }
 
-/// Apply a charge delta to an element's curve. Updates saturation and pressure.
-/// Delta is signed (two's complement u64): positive = deposit, negative = withdrawal.
-fn update_curve(charge: u64, elem: &mut Element) -> Q824 {
+/// Apply a charge delta to an element's curve along its shape. Updates saturation, pressure
+/// and TVL together, or none of them if any would leave its range.
+/// Returns the saturation moved: the share bought by a deposit.
+pub(crate) fn update_curve(
+    charge: Delta<Gluon>,
+    elem: &mut Element,
+) -> Result<Delta<Q824>, CurveError> {
     if elem.index.is_zero() {
-        return 0;
+        return Ok(charge.map(|_| Q824::ZERO));
     }
     let Curve {
         capacity,
@@ -36,27 +101,146 @@ fn update_curve(charge: u64, elem: &mut Element) -> Q824 {
         pressure,
         ..
     } = elem.curve;
-    let (contribution, pressure) = dx_for_dc(saturation, pressure, charge, capacity as u64);
-    elem.curve.saturation += contribution;
-    elem.curve.pressure += pressure;
-    elem.curve.tvl += charge;
-    contribution
+    let shape = elem.curve.shape();
+    let (contribution, pressure_delta) =
+        shape.try_dx_for_dc(saturation, pressure, charge.map(|g| g.0), capacity.0)?;
+    let saturation = contribution.checked_apply(saturation)?;
+    let pressure = pressure_delta.checked_apply(pressure)?;
+    let tvl = charge.checked_apply(elem.curve.tvl)?;
+    (elem.curve.saturation, elem.curve.pressure, elem.curve.tvl) = (saturation, pressure, tvl);
+    Ok(contribution)
 }
 
 /// Claim a shareholder's proportional share from a reset element's pot.
-/// Distributes reward based on share value and updates charge state.
+/// The claimant must already be out of `artefact.charges`. Its share leaves `artefact.shares`
+/// with its reward, so later claimants split what is left in the same proportions, and the
+/// last claimant takes the remainder of the pot.
 pub fn claim(charge: &mut Charge, artefact: &mut Artefact) {
-    let reward = round_divide(artefact.pot, charge.share as u64, artefact.shares as u64);
+    let reward = match (artefact.charges, charge.share) {
+        (0, _) => artefact.pot,
+        (_, Q824::ZERO) => Gluon::ZERO,
+        (_, share) => Gluon(round_divide(
+            artefact.pot.0,
+            share.0 as u64,
+            artefact.shares.0 as u64,
+        )),
+    };
     charge.balance += reward;
     artefact.pot -= reward;
-    charge.share = 0;
+    artefact.shares -= charge.share;
+    charge.share = Q824::ZERO;
     charge.index.clear();
 }
 
+/// Reset an overloaded element: snapshot its generation into `artefact`, pay the triggering
+/// charge and re-bind it first into the next generation, sized from the board TVL.
+/// Every other charge of the generation is ejected: it stays on the board, counted in the
+/// artefact's outstanding `tvl` and `charges`, until it claims via [`release`].
+/// The triggering charge must be bound to `target`.
+pub fn overload(
+    charge: &mut Charge,
+    target: &mut Element,
+    artefact: &mut Artefact,
+    board: &mut Board,
+) -> Result<(), TokamakError> {
+    // Fe resets feed the quantum pocket; the next depth in the schedule draws from it.
+    let atomic = target.index.atomic();
+    if atomic == MAX_ATOMIC_NUMBER {
+        quantum::inject(board, &mut target.pot);
+    }
+    artefact.pot = target.pot + quantum::unlock(board, atomic);
+    artefact.shares = target.curve.shares;
+    artefact.charges = target.charges - 1;
+    artefact.tvl = match artefact.charges {
+        // Nobody left to claim: the curve's fee residue leaves the board with the generation.
+        0 => Gluon::ZERO,
+        _ => target.curve.tvl - charge.balance,
+    };
+    board.tvl -= target.curve.tvl - charge.balance - artefact.tvl;
+
+    // The trigger's reward leaves the artefact pot and joins the board with its balance.
+    let balance = charge.balance;
+    claim(charge, artefact);
+    board.tvl += charge.balance - balance;
+
+    target.index.advance_generation();
+    target.pot = Gluon::ZERO;
+    target.charges = 0;
+    target.curve = Curve {
+        capacity: capacity(board.tvl.max(GENESIS_TVL), atomic, target.coordinates),
+        tvl: Gluon::ZERO,
+        pressure: Q1648::ZERO,
+        saturation: Q824::ZERO,
+        shares: Q824::ZERO,
+        shape: target.curve.shape,
+        _pad: 0,
+    };
+    rebind(charge, &mut off_board(), target)
+}
+
+/// Claim an ejected charge's reward and take it off the board. Its balance leaves the
+/// artefact's outstanding TVL; the last claimant takes the remainder (fee residue included)
+/// and the rest of the pot, so the artefact ends with neither TVL, charges nor pot.
+pub fn release(charge: &mut Charge, artefact: &mut Artefact, board: &mut Board) {
+    let stake = match artefact.charges {
+        1 => artefact.tvl,
+        _ => charge.balance,
+    };
+    artefact.tvl -= stake;
+    artefact.charges -= 1;
+    board.tvl -= stake;
+    board.charge_count -= 1;
+    claim(charge, artefact);
+}
+
 /// Compress an element inward: rebind charge and consolidate pot to deeper element.
 /// Transfers accumulated pot from src to dst.
-pub fn compress(charge: &mut Charge, src: &mut Element, dst: &mut Element) {
-    rebind(charge, src, dst);
+pub fn compress(
+    charge: &mut Charge,
+    src: &mut Element,
+    dst: &mut Element,
+) -> Result<(), TokamakError> {
+    rebind(charge, src, dst)?;
     dst.pot += src.pot;
-    src.pot = 0;
+    src.pot = Gluon::ZERO;
+    Ok(())
+}
+
+/// Split `amount` off a charge bound to `elem` into the empty charge `part`, bound to the same
+/// element from the same slot.
+///
+/// The curve does not move: `Curve::shares`, `saturation` and `tvl` are untouched. The share is
+/// divided in proportion to the balances, rounded down for `part`, so the two shares still sum
+/// to the original and the remainder stays with `charge`. Only the element's and board's charge
+/// counts grow. Callers ensure `0 < amount < charge.balance`.
+pub fn split(
+    charge: &mut Charge,
+    part: &mut Charge,
+    amount: Gluon,
+    elem: &mut Element,
+    board: &mut Board,
+) {
+    let share = (charge.share.0 as u128 * amount.0 as u128 / charge.balance.0 as u128) as u32;
+    part.balance = amount;
+    part.share = Q824(share);
+    part.index = charge.index;
+    part.timestamp = charge.timestamp;
+    charge.balance -= amount;
+    charge.share -= part.share;
+    elem.charges += 1;
+    board.charge_count += 1;
+}
+
+/// Merge `other` into `charge`; both bound to `elem`. Balances and shares add exactly, so the
+/// curve does not move, and `other` is left empty and unbound. The merged charge keeps the
+/// later timestamp: merging into a rested charge does not shed a speed tax.
+pub fn merge(charge: &mut Charge, other: &mut Charge, elem: &mut Element, board: &mut Board) {
+    charge.balance += other.balance;
+    charge.share += other.share;
+    charge.timestamp = charge.timestamp.max(other.timestamp);
+    other.balance = Gluon::ZERO;
+    other.share = Q824::ZERO;
+    other.index.clear();
+    elem.charges -= 1;
+    board.charge_count -= 1;
 }
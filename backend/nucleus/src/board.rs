@@ -2,12 +2,17 @@
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
+use curve::shape::Shape;
 
-use crate::types::{Coordinates, ElementIndex, Gluon, Q1648, Q824};
+use crate::{
+    consts::{ELEMENT_COORDINATES, GENESIS_TVL, MAX_ATOMIC_NUMBER, SUM_ATOMIC_NUMBERS},
+    round_divide,
+    types::{Coordinates, ElementIndex, Gluon, Q1648, Q824},
+};
 
 /// Curve: bonding curve state for an element.
 /// Determines entry cost, player share value, and pressure mechanics.
-/// Field order: 8+8+4+4 = 24 bytes (Pod-aligned).
+/// Field order: 8+8+8+4+4+4+4 = 40 bytes (Pod-aligned).
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
@@ -16,16 +21,29 @@ pub struct Curve {
     pub capacity: Gluon,
     /// Total Gluon ever accumulated (TVL, net of deposits/withdrawals).
     pub tvl: Gluon,
-    /// Accumulated pressure integral as Q16.48; path-independent checksum of history.
+    /// Accumulated pressure integral: the sum of the generation's cost deltas, in any
+    /// order a checksum of its history (see `pressure`).
     pub pressure: Q1648,
-    /// Current saturation as Q8.24 (0 to 6); higher = crowded, higher costs.
+    /// Current saturation (0 to 6); higher = crowded, higher costs.
     pub saturation: Q824,
     /// Cumulative number of shares owned by bound charges (can differ from saturation)
     pub shares: Q824,
+    /// Shape preset id (`curve::shape::SHAPES`); 0 is the master sigmoid.
+    pub shape: u32,
+    pub _pad: u32,
+}
+
+impl Curve {
+    /// Pricing shape for this curve. Unknown ids fall back to the master sigmoid.
+    #[inline]
+    pub fn shape(&self) -> Shape {
+        Shape::from_id(self.shape).unwrap_or_default()
+    }
 }
 
 /// Element: single board group where players gather and accumulate pressure.
 /// Resets (overloads) when pressure exceeds a threshold.
+/// Field order: 8+8+40+8+4+4 = 72 bytes (Pod-aligned).
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
@@ -38,6 +56,49 @@ pub struct Element {
     pub curve: Curve,
     /// Bitboard: which squares this element occupies.
     pub coordinates: Coordinates,
+    /// Charges bound to the current generation.
+    pub charges: u32,
+    pub _pad: u32,
+}
+
+impl Element {
+    /// Genesis state for atomic number `atomic` (1..=26): generation 0, empty pot,
+    /// curve sized from `GENESIS_TVL`. Returns `None` for atomic numbers off the board.
+    pub fn genesis(atomic: u64) -> Option<Self> {
+        if !(1..=MAX_ATOMIC_NUMBER).contains(&atomic) {
+            return None;
+        }
+        let coordinates = ELEMENT_COORDINATES[(atomic - 1) as usize];
+        let curve = Curve {
+            capacity: capacity(GENESIS_TVL, atomic, coordinates),
+            tvl: Gluon::ZERO,
+            pressure: Q1648::ZERO,
+            saturation: Q824::ZERO,
+            shares: Q824::ZERO,
+            shape: 0,
+            _pad: 0,
+        };
+        Some(Self {
+            pot: Gluon::ZERO,
+            index: ElementIndex::new(atomic, 0),
+            curve,
+            coordinates,
+            charges: 0,
+            _pad: 0,
+        })
+    }
+}
+
+/// Curve capacity for an element: `tvl · atomic · tiles / SUM_ATOMIC_NUMBERS`, rounded.
+/// Deeper and larger elements get proportionally larger curves. The weights are not
+/// normalised (over the genesis layout they sum to 817/351, about 2.3), so capacities are a
+/// scale for each curve rather than a partition of `tvl`.
+pub fn capacity(tvl: Gluon, atomic: u64, coordinates: Coordinates) -> Gluon {
+    Gluon(round_divide(
+        tvl.0,
+        atomic * coordinates.tiles(),
+        SUM_ATOMIC_NUMBERS,
+    ))
 }
 
 /// Board: global singleton tracking game-wide state.
@@ -46,13 +107,13 @@ pub struct Element {
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
 pub struct Board {
-    /// Total Gluon locked across all active charges.
+    /// Gluon bound on the board: live element curve TVL plus artefacts' outstanding TVL.
     pub tvl: Gluon,
-    /// Gluon in quantum pocket reserve (reserved for rare unlocks/events).
+    /// Gluon in the quantum pocket: Fe yield awaiting sequential unlock (see `quantum`).
     pub quantum_pocket: Gluon,
-    /// Count of active charges on board (used in global pressure calculations).
+    /// Charges on the board: bound to a live element or ejected and not yet claimed.
     pub charge_count: u32,
-    /// Quantum unlock progression index (tracks fully-reset elements).
+    /// Depths unlocked in the current quantum cycle; the next unlock goes to Z = index + 1.
     pub quantum_index: u8,
     /// Padding for 24-byte alignment (Pod requirement).
     pub _pad: [u8; 3],
@@ -60,7 +121,7 @@ pub struct Board {
 
 /// Artefact: snapshot of a reset element. Players can claim their share but cannot re-enter.
 /// Created when an element resets (saturation exceeds threshold).
-/// Field order: 8+8 = 16 bytes.
+/// Field order: 8+8+8+4+4 = 32 bytes.
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
@@ -69,7 +130,10 @@ pub struct Artefact {
     pub pot: Gluon,
     /// Which element this was (reference only).
     pub index: ElementIndex,
-    /// Cumulative number of shares owned by bound charges at the moment of overload
+    /// Curve TVL of the ejected charges still on the board; each Claim takes its share out.
+    pub tvl: Gluon,
+    /// Shares of the charges that have not claimed yet; each Claim takes its own out.
     pub shares: Q824,
-    pub _pad: u32,
+    /// Ejected charges that have not claimed yet.
+    pub charges: u32,
 }
@@ -1,17 +1,17 @@
 //! Game parameters and constants: element limits, fee baselines, and curve saturation bounds.
 
-use crate::types::Coordinates;
+use crate::types::{Coordinates, Gluon, Q824};
 
 /// Maximum atomic number (0-indexed, so 27 distinct elements).
 pub const MAX_ATOMIC_NUMBER: u64 = 26;
 
 pub const SUM_ATOMIC_NUMBERS: u64 = MAX_ATOMIC_NUMBER * (MAX_ATOMIC_NUMBER + 1) / 2;
 
-/// Maximum curve position (Q8.24, range [0, 6]).
-pub const MAX_SATURATION: u32 = curve::consts::LUT_X_MAX;
+/// Maximum curve position (range [0, 6]).
+pub const MAX_SATURATION: Q824 = curve::consts::LUT_X_MAX;
 
 /// Minimum fee (in Gluon) to prevent dust.
-pub const MIN_FEE: u64 = 100_000;
+pub const MIN_FEE: Gluon = Gluon(100_000);
 
 /// Token decimals (Gluon precision, same as popular stable coins).
 pub const DECIMALS: u8 = 6;
@@ -22,6 +22,9 @@ pub const MAX_SPEED_MULTIPLIER: u64 = 127;
 /// Maximum elapsed time window for speed tax (slots).
 pub const MAX_DELTA_TIMESTAMP: u64 = 1024;
 
+/// Notional board TVL used to size element curves at genesis (1M Gluon).
+pub const GENESIS_TVL: Gluon = Gluon(1_000_000 * 10u64.pow(DECIMALS as u32));
+
 /// Element coordinate bitmasks (8×8 board, row-major).
 /// Each bit represents one tile: A1=bit0, B1=bit1, ..., H8=bit63.
 pub const COORD_01_H: Coordinates = Coordinates(0x0000000000000107); // A1, A2, B1, C1
@@ -50,3 +53,33 @@ pub const COORD_23_V: Coordinates = Coordinates(0x0000000006000000); // B4, C4
 pub const COORD_24_CR: Coordinates = Coordinates(0x00000000000C0000); // C3, D3
 pub const COORD_25_MN: Coordinates = Coordinates(0x0000000008000000); // D4
 pub const COORD_26_FE: Coordinates = Coordinates(0x0000001810000000); // E4, D5, E5
+
+/// Element coordinates indexed by `atomic - 1` (H at 0, Fe at 25).
+pub const ELEMENT_COORDINATES: [Coordinates; MAX_ATOMIC_NUMBER as usize] = [
+    COORD_01_H,
+    COORD_02_HE,
+    COORD_03_LI,
+    COORD_04_BE,
+    COORD_05_B,
+    COORD_06_C,
+    COORD_07_N,
+    COORD_08_O,
+    COORD_09_F,
+    COORD_10_NE,
+    COORD_11_NA,
+    COORD_12_MG,
+    COORD_13_AL,
+    COORD_14_SI,
+    COORD_15_P,
+    COORD_16_S,
+    COORD_17_CL,
+    COORD_18_AR,
+    COORD_19_K,
+    COORD_20_CA,
+    COORD_21_SC,
+    COORD_22_TI,
+    COORD_23_V,
+    COORD_24_CR,
+    COORD_25_MN,
+    COORD_26_FE,
+];
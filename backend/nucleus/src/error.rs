@@ -0,0 +1,131 @@
+//! Game errors with stable numeric codes, shared by the on-chain program and off-chain clients.
+//!
+//! Codes are grouped by concern (1xx accounts, 2xx charge state, 3xx movement, 4xx resets,
+//! 5xx custody, 6xx client guards) and never reused: on-chain they surface as `ProgramError::Custom(code)`.
+
+use core::fmt;
+
+use curve::error::CurveError;
+
+/// Rule violation that aborts an action. The discriminant is the wire code.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TokamakError {
+    /// Account header names a different (or unknown) account kind.
+    WrongAccountKind = 100,
+    /// Account header carries a layout version this build does not understand.
+    UnsupportedAccountVersion = 101,
+
+    /// Charge must be unbound (off-board) for this action.
+    ChargeAlreadyBound = 200,
+    /// Charge is still bound; unbind or claim before moving funds out.
+    ChargeStillBound = 201,
+    /// Charge is not bound to the element the action targets.
+    ChargeNotInElement = 202,
+    /// Account still holds Gluon (or, for a wallet, open charges); empty it before closing.
+    AccountNotEmpty = 203,
+
+    /// Elements do not share an edge.
+    NotAdjacent = 300,
+    /// Element does not touch the board perimeter.
+    NotOnEdge = 301,
+    /// Compression must move toward strictly higher Z.
+    CompressionNotInward = 302,
+    /// Move does not fit the element curve's fixed-point range.
+    CurveOutOfRange = 303,
+
+    /// Element saturation is below the overload threshold.
+    BelowOverloadThreshold = 400,
+    /// Artefact has no ejected charges left to claim (not overloaded yet, or fully claimed).
+    NothingToClaim = 401,
+    /// Charge index does not match the artefact (different element or generation).
+    ArtefactMismatch = 402,
+
+    /// Mint is not one of the supported stablecoins.
+    UnsupportedMint = 500,
+    /// Accounts are denominated in different mints.
+    MintMismatch = 501,
+
+    /// Move would take a larger fee than the signer's `max_fee`.
+    FeeAboveLimit = 600,
+    /// Move would leave the charge a smaller share than the signer's `min_share`.
+    ShareBelowLimit = 601,
+    /// Current slot is past the signer's `deadline_slot`.
+    DeadlinePassed = 602,
+}
+
+impl TokamakError {
+    /// Stable numeric code.
+    #[inline]
+    pub const fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// Decode a numeric code (e.g. from `ProgramError::Custom`).
+    pub const fn from_code(code: u32) -> Option<Self> {
+        use TokamakError::*;
+        Some(match code {
+            100 => WrongAccountKind,
+            101 => UnsupportedAccountVersion,
+            200 => ChargeAlreadyBound,
+            201 => ChargeStillBound,
+            202 => ChargeNotInElement,
+            203 => AccountNotEmpty,
+            300 => NotAdjacent,
+            301 => NotOnEdge,
+            302 => CompressionNotInward,
+            303 => CurveOutOfRange,
+            400 => BelowOverloadThreshold,
+            401 => NothingToClaim,
+            402 => ArtefactMismatch,
+            500 => UnsupportedMint,
+            501 => MintMismatch,
+            600 => FeeAboveLimit,
+            601 => ShareBelowLimit,
+            602 => DeadlinePassed,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for TokamakError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TokamakError::*;
+        let msg = match self {
+            WrongAccountKind => "account holds a different kind of data",
+            UnsupportedAccountVersion => "account layout version is not supported",
+            ChargeAlreadyBound => "charge is already bound to an element",
+            ChargeStillBound => "charge is still bound to an element",
+            ChargeNotInElement => "charge is not bound to this element",
+            AccountNotEmpty => "account still holds gluon or open charges",
+            NotAdjacent => "elements are not adjacent",
+            NotOnEdge => "element is not on the board edge",
+            CompressionNotInward => "compression must move toward higher Z",
+            CurveOutOfRange => "move does not fit the element's curve",
+            BelowOverloadThreshold => "element saturation is below the overload threshold",
+            NothingToClaim => "artefact has no outstanding claims",
+            ArtefactMismatch => "charge does not belong to this artefact",
+            UnsupportedMint => "mint is not a supported stablecoin",
+            MintMismatch => "accounts are denominated in different mints",
+            FeeAboveLimit => "fee exceeds the signer's limit",
+            ShareBelowLimit => "share is below the signer's limit",
+            DeadlinePassed => "deadline slot has passed",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for TokamakError {}
+
+impl From<CurveError> for TokamakError {
+    fn from(_: CurveError) -> Self {
+        Self::CurveOutOfRange
+    }
+}
+
+#[cfg(feature = "program-error")]
+impl From<TokamakError> for solana_program_error::ProgramError {
+    fn from(err: TokamakError) -> Self {
+        Self::Custom(err.code())
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     },
     player::Charge,
     round_divide,
-    types::Gluon,
+    types::{Gluon, Q824},
 };
 
 /// Rebind fee: cost to move a charge between two elements (distance-based).
@@ -15,7 +15,7 @@ use crate::{
 pub fn rebind_fee(charge: &Charge, src: &Element, dst: &Element) -> Gluon {
     let src_z = src.index.atomic();
     let dst_z = dst.index.atomic();
-    let delta_z = dst_z.wrapping_sub(src_z);
+    let delta_z = dst_z.abs_diff(src_z);
     let curve = if src.index > dst.index {
         &src.curve
     } else {
@@ -39,9 +39,9 @@ pub fn unbind_fee(charge: &Charge, src: &Element) -> Gluon {
 /// Compression fee: cost to compress an element inward (consolidate into deeper element).
 /// Accelerates element convergence toward center.
 pub fn compression_fee(src: &Element) -> Gluon {
-    let numerator = src.curve.saturation as u64 * 5;
-    let denominator = (MAX_SATURATION as u64) * 100;
-    let result = round_divide(src.pot, numerator, denominator);
+    let numerator = src.curve.saturation.0 as u64 * 5;
+    let denominator = (MAX_SATURATION.0 as u64) * 100;
+    let result = Gluon(round_divide(src.pot.0, numerator, denominator));
     result.max(MIN_FEE)
 }
 
@@ -55,9 +55,9 @@ pub fn fee_multiplier(charge: &Charge, now: u64) -> u64 {
 
 /// Calculate base fee: balance * (distance² * saturation) / (MAX_ATOMIC_NUMBER² * MAX_POSITION).
 /// Fees scale quadratically with distance and linearly with commitment/saturation.
-fn calculate_base_fee(balance: Gluon, distance: u64, saturation: u32) -> Gluon {
-    const DENOMINATOR: u64 = MAX_ATOMIC_NUMBER.pow(2) * (MAX_SATURATION as u64);
-    let numerator = distance.pow(2) * (saturation as u64);
-    let result = round_divide(balance, numerator, DENOMINATOR);
+fn calculate_base_fee(balance: Gluon, distance: u64, saturation: Q824) -> Gluon {
+    const DENOMINATOR: u64 = MAX_ATOMIC_NUMBER.pow(2) * (MAX_SATURATION.0 as u64);
+    let numerator = distance.pow(2) * (saturation.0 as u64);
+    let result = Gluon(round_divide(balance.0, numerator, DENOMINATOR));
     result.max(MIN_FEE)
 }
@@ -0,0 +1,64 @@
+//! Client limits on fee-bearing moves: the worst fee, smallest share and latest slot a player
+//! accepts. Fees and shares are priced at execution; a move that was front-run or delayed
+//! past its quote fails instead of executing at the new price.
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    error::TokamakError,
+    types::{Gluon, Q824},
+};
+
+/// Optional instruction data of Bind, Unbind, Rebind, RebindPath, Compress and the compound
+/// BindAndOverload and RebindAndOverload.
+/// A field left at its [`Guards::NONE`] value disables that check.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+pub struct Guards {
+    /// Largest fee the move may take, speed tax included.
+    pub max_fee: Gluon,
+    /// Last slot the move may execute in.
+    pub deadline_slot: u64,
+    /// Smallest share the charge must receive at its destination. Unbind has none to check.
+    pub min_share: Q824,
+    pub _pad: u32,
+}
+
+impl Guards {
+    /// No limits: what a move without guard data runs with.
+    pub const NONE: Self = Self {
+        max_fee: Gluon::MAX,
+        deadline_slot: u64::MAX,
+        min_share: Q824::ZERO,
+        _pad: 0,
+    };
+
+    /// The move must execute no later than `deadline_slot`.
+    #[inline]
+    pub fn check_deadline(&self, now: u64) -> Result<(), TokamakError> {
+        if now > self.deadline_slot {
+            return Err(TokamakError::DeadlinePassed);
+        }
+        Ok(())
+    }
+
+    /// The fee actually taken must not exceed `max_fee`.
+    #[inline]
+    pub fn check_fee(&self, fee: Gluon) -> Result<(), TokamakError> {
+        if fee > self.max_fee {
+            return Err(TokamakError::FeeAboveLimit);
+        }
+        Ok(())
+    }
+
+    /// The share actually received must reach `min_share`.
+    #[inline]
+    pub fn check_share(&self, share: Q824) -> Result<(), TokamakError> {
+        if share < self.min_share {
+            return Err(TokamakError::ShareBelowLimit);
+        }
+        Ok(())
+    }
+}
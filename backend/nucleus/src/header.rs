@@ -0,0 +1,146 @@
+//! Account header: discriminator and layout version prefixed to every stored account.
+//!
+//! On-chain data is `[Header | body]`, where the body is the plain nucleus struct.
+//! Indexers classify raw account data by the header alone.
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    board::{Artefact, Board, Element},
+    player::{Charge, Wallet},
+};
+
+/// Kind of stored account, written as the header discriminator (0 = uninitialized).
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccountKind {
+    Wallet = 1,
+    Charge = 2,
+    Board = 3,
+    Element = 4,
+    Artefact = 5,
+}
+
+impl TryFrom<u8> for AccountKind {
+    type Error = HeaderError;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Err(HeaderError::Uninitialized),
+            1 => Ok(Self::Wallet),
+            2 => Ok(Self::Charge),
+            3 => Ok(Self::Board),
+            4 => Ok(Self::Element),
+            5 => Ok(Self::Artefact),
+            _ => Err(HeaderError::UnknownKind(val)),
+        }
+    }
+}
+
+/// Why account data does not hold the expected account type.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeaderError {
+    /// Data is shorter than header plus body.
+    TooSmall,
+    /// Discriminator is zero: account was created but never written.
+    Uninitialized,
+    /// Discriminator does not name any account kind.
+    UnknownKind(u8),
+    /// Discriminator names a different account kind.
+    WrongKind {
+        expected: AccountKind,
+        found: AccountKind,
+    },
+    /// Kind matches but the layout version is not the one this build understands.
+    UnsupportedVersion { expected: u8, found: u8 },
+}
+
+/// Header prefixed to every account. 8 bytes so the body stays u64-aligned.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+pub struct Header {
+    /// `AccountKind` as u8.
+    pub discriminator: u8,
+    /// Body layout version for that kind.
+    pub version: u8,
+    /// Canonical PDA bump, so address checks skip the bump search.
+    pub bump: u8,
+    pub _pad: [u8; 5],
+}
+
+impl Header {
+    /// Size of the header in bytes (offset of the body).
+    pub const LEN: usize = size_of::<Self>();
+
+    pub const fn new(kind: AccountKind, version: u8) -> Self {
+        Self {
+            discriminator: kind as u8,
+            version,
+            bump: 0,
+            _pad: [0; 5],
+        }
+    }
+
+    /// Decode the account kind from the discriminator.
+    pub fn kind(&self) -> Result<AccountKind, HeaderError> {
+        AccountKind::try_from(self.discriminator)
+    }
+
+    /// Check that this header describes the current layout of `T`.
+    pub fn check<T: Discriminated>(&self) -> Result<(), HeaderError> {
+        let found = self.kind()?;
+        if found != T::KIND {
+            let expected = T::KIND;
+            return Err(HeaderError::WrongKind { expected, found });
+        }
+        if self.version != T::VERSION {
+            let (expected, found) = (T::VERSION, self.version);
+            return Err(HeaderError::UnsupportedVersion { expected, found });
+        }
+        Ok(())
+    }
+}
+
+/// Classify raw account data by its header (for indexers).
+pub fn classify(data: &[u8]) -> Result<AccountKind, HeaderError> {
+    let discriminator = *data.first().ok_or(HeaderError::TooSmall)?;
+    AccountKind::try_from(discriminator)
+}
+
+/// Nucleus type stored as a program account behind a `Header`.
+pub trait Discriminated: Sized {
+    const KIND: AccountKind;
+    /// Bumped whenever the body layout changes.
+    const VERSION: u8;
+    /// Header written at account creation.
+    const HEADER: Header = Header::new(Self::KIND, Self::VERSION);
+    /// Total account size: header plus body.
+    const SPACE: usize = Header::LEN + size_of::<Self>();
+}
+
+impl Discriminated for Wallet {
+    const KIND: AccountKind = AccountKind::Wallet;
+    const VERSION: u8 = 2;
+}
+
+impl Discriminated for Charge {
+    const KIND: AccountKind = AccountKind::Charge;
+    const VERSION: u8 = 1;
+}
+
+impl Discriminated for Board {
+    const KIND: AccountKind = AccountKind::Board;
+    const VERSION: u8 = 1;
+}
+
+impl Discriminated for Element {
+    const KIND: AccountKind = AccountKind::Element;
+    const VERSION: u8 = 3;
+}
+
+impl Discriminated for Artefact {
+    const KIND: AccountKind = AccountKind::Artefact;
+    const VERSION: u8 = 2;
+}
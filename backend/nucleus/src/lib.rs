@@ -2,12 +2,23 @@
 //!
 //! Nucleus defines the fundamental data structures, types, constants, and action logic that drive TOKAMAK64.
 //! It is a pure data layer with no blockchain dependencies; core game rules are expressed as deterministic functions.
+//! `no_std` unless the `std` feature is enabled.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 pub mod action;
 pub mod board;
 pub mod consts;
+pub mod error;
 pub mod fees;
+pub mod guards;
+pub mod header;
 pub mod player;
+pub mod pressure;
+pub mod quantum;
+pub mod quote;
+pub mod seeds;
+pub mod topology;
 pub mod types;
 
 #[cfg(test)]
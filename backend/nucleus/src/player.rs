@@ -16,9 +16,10 @@ pub struct Wallet {
     pub authority: AddressBytes,
     /// Stable token mint (USDT/USDC).
     pub mint: AddressBytes,
-    /// Count of charges created (for PDA derivation).
+    /// Count of charges created (for PDA derivation). Never decreases: closed ids are skipped.
     pub charges: u32,
-    pub _pad: u32,
+    /// Charges created and not yet closed; the wallet can only close at zero.
+    pub open: u32,
 }
 
 /// Allocated Gluon bound to one element. Bound (index != 0) or unbound (index == 0).
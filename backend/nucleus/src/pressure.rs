@@ -0,0 +1,68 @@
+//! Reading the pressure integral: what `Curve::pressure` says about a generation.
+//!
+//! Every curve update adds the cost delta `ds = dc · s_max / capacity` to pressure, whatever the
+//! curve's state, so pressure is the sum of a generation's cost deltas in any order. Saturation
+//! follows it along the shape until it clamps at the top of the domain; the cost saturation
+//! cannot absorb there stays in pressure as heat.
+
+use curve::{delta::Delta, error::CurveError, shape::CurveShape};
+
+use crate::{
+    board::Curve,
+    types::{Gluon, Q1648, Q824},
+};
+
+/// Trapezoids in the [`mean_saturation`] integral.
+const MEAN_STEPS: u32 = 1024;
+
+/// Pressure saturation could not absorb: the cost pushed past a full curve (plus rounding
+/// dust), zero below the top. Grows with every deposit onto a saturated element until it
+/// overloads.
+pub fn heat(curve: &Curve) -> Q1648 {
+    let absorbed = curve.shape().evaluate_cost(curve.saturation);
+    curve.pressure.saturating_sub(absorbed)
+}
+
+/// Average saturation at which the capital now on the curve entered it: each unit of
+/// absorbed cost is weighted by the saturation it bought, `x̄ = x − ∫₀ˣ s dx / s(x)`. Zero for
+/// an empty curve.
+pub fn mean_saturation(curve: &Curve) -> Q824 {
+    let shape = curve.shape();
+    let x = curve.saturation;
+    let absorbed = shape.evaluate_cost(x);
+    if absorbed == Q1648::ZERO {
+        return Q824::ZERO;
+    }
+    // Trapezoid rule over [0, x]; twice the area, in Q16.48 · Q8.24
+    let cost = |x: u32| shape.evaluate_cost(Q824(x)).0;
+    let (mut area, mut x0, mut s0) = (0u128, 0u32, cost(0));
+    for k in 1..=MEAN_STEPS {
+        let x1 = (x.0 as u64 * k as u64 / MEAN_STEPS as u64) as u32;
+        let s1 = cost(x1);
+        area += (s0 as u128 + s1 as u128) * (x1 - x0) as u128;
+        (x0, s0) = (x1, s1);
+    }
+    let below = area / (2 * absorbed.0 as u128);
+    Q824(x.0 - below.min(x.0 as u128) as u32)
+}
+
+/// Pressure a generation of `curve`'s capacity and shape reaches after `deltas`, the curve
+/// deltas its binds and unbinds applied (balances net of fees). Fails where the program would.
+pub fn history_pressure(curve: &Curve, deltas: &[Delta<Gluon>]) -> Result<Q1648, CurveError> {
+    let shape = curve.shape();
+    deltas.iter().try_fold(Q1648::ZERO, |pressure, &dc| {
+        shape
+            .try_ds_for_dc(dc.map(|g| g.0), curve.capacity.0)?
+            .checked_apply(pressure)
+    })
+}
+
+/// True if `deltas` are the full history of `curve`'s generation: replaying them gives its
+/// pressure and TVL. Pressure does not depend on order, so a reordered history verifies too,
+/// as long as no prefix withdraws more than it deposited.
+pub fn verify_history(curve: &Curve, deltas: &[Delta<Gluon>]) -> bool {
+    let tvl = deltas
+        .iter()
+        .try_fold(Gluon::ZERO, |tvl, dc| dc.checked_apply(tvl));
+    history_pressure(curve, deltas) == Ok(curve.pressure) && tvl == Ok(curve.tvl)
+}
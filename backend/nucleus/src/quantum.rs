@@ -0,0 +1,62 @@
+//! Quantum Pocket: yield set aside on Fe resets and released sequentially from the edge inward.
+//!
+//! Every Fe (Z=26) overload diverts `QUANTUM_YIELD_BPS` of its pot into `Board::quantum_pocket`.
+//! The pocket then unlocks one depth at a time, H first: when the element at the next depth
+//! (`quantum_index + 1`) overloads, it receives an equal split of what is left for the
+//! remaining depths in its Artefact pot, and `quantum_index` advances. After Mn the cycle
+//! restarts at H. Resets out of order unlock nothing.
+
+use crate::{board::Board, consts::MAX_ATOMIC_NUMBER, round_divide, types::Gluon};
+
+/// Share of an Fe pot injected into the pocket on overload, in basis points.
+pub const QUANTUM_YIELD_BPS: u64 = 1_000;
+
+/// Depths that receive unlocks per cycle: every element except Fe (H..Mn).
+pub const QUANTUM_DEPTHS: u8 = MAX_ATOMIC_NUMBER as u8 - 1;
+
+/// Yield taken from an overloading Fe pot into the pocket.
+pub fn injection(pot: Gluon) -> Gluon {
+    Gluon(round_divide(pot.0, QUANTUM_YIELD_BPS, 10_000))
+}
+
+/// Atomic number of the next element to receive an unlock.
+pub fn next_depth(quantum_index: u8) -> u64 {
+    quantum_index as u64 % QUANTUM_DEPTHS as u64 + 1
+}
+
+/// Portion of `pocket` released at the next depth: an equal split over the remaining depths.
+/// The last depth of a cycle takes everything, so the pocket drains fully each cycle.
+pub fn unlock_amount(pocket: Gluon, quantum_index: u8) -> Gluon {
+    let remaining = (QUANTUM_DEPTHS - quantum_index % QUANTUM_DEPTHS) as u64;
+    Gluon(pocket.0 / remaining)
+}
+
+/// Forecast `(atomic, unlock)` for the rest of the current cycle, assuming no further injections.
+pub fn schedule(pocket: Gluon, quantum_index: u8) -> impl Iterator<Item = (u64, Gluon)> {
+    let mut pocket = pocket;
+    (quantum_index % QUANTUM_DEPTHS..QUANTUM_DEPTHS).map(move |index| {
+        let amount = unlock_amount(pocket, index);
+        pocket -= amount;
+        (next_depth(index), amount)
+    })
+}
+
+/// Divert the Fe injection from `pot` into the pocket; returns the amount moved.
+pub fn inject(board: &mut Board, pot: &mut Gluon) -> Gluon {
+    let amount = injection(*pot);
+    *pot -= amount;
+    board.quantum_pocket += amount;
+    amount
+}
+
+/// Release the pocket's unlock if `atomic` is the next depth, advancing `quantum_index`.
+/// Returns the amount released (zero for any other element).
+pub fn unlock(board: &mut Board, atomic: u64) -> Gluon {
+    if atomic != next_depth(board.quantum_index) {
+        return Gluon::ZERO;
+    }
+    let amount = unlock_amount(board.quantum_pocket, board.quantum_index);
+    board.quantum_pocket -= amount;
+    board.quantum_index = (board.quantum_index + 1) % QUANTUM_DEPTHS;
+    amount
+}
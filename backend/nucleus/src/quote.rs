@@ -0,0 +1,61 @@
+//! Read-only quotes on an element's curve for bots and the frontend.
+//!
+//! Every quote runs the same curve update the program applies, on a copy of the element, so
+//! the numbers match on-chain rounding exactly. Amounts are what reaches the curve: a charge's
+//! balance net of fees (see [`crate::fees`]). A quote fails where the program would: when
+//! the move does not fit the curve's fixed-point range.
+
+use curve::{delta::Delta, error::CurveError};
+
+use crate::{
+    action::update_curve,
+    board::Element,
+    consts::MAX_SATURATION,
+    types::{Gluon, Q824},
+};
+
+/// Share a deposit of `amount` onto `element`'s curve would receive now.
+pub fn share_for_deposit(element: &Element, amount: Gluon) -> Result<Q824, CurveError> {
+    let mut element = *element;
+    Ok(update_curve(Delta::Deposit(amount), &mut element)?.magnitude())
+}
+
+/// Saturation of `element` after applying `deltas` in order: positive amounts bind
+/// onto the curve, negative amounts unbind from it.
+pub fn saturation_after(element: &Element, deltas: &[i64]) -> Result<Q824, CurveError> {
+    let mut element = *element;
+    for &delta in deltas {
+        update_curve(Delta::from(delta).map(Gluon), &mut element)?;
+    }
+    Ok(element.curve.saturation)
+}
+
+/// Smallest deposit that takes `element` to the overload threshold (`MAX_SATURATION`), or 0 if
+/// it is already there. `None` for an element without a curve.
+pub fn gluon_to_overload(element: &Element) -> Option<Gluon> {
+    if element.index.is_zero() || element.curve.capacity == Gluon::ZERO {
+        return None;
+    }
+    let reaches = |amount: u64| {
+        let mut element = *element;
+        update_curve(Delta::Deposit(Gluon(amount)), &mut element)
+            .is_ok_and(|_| element.curve.saturation >= MAX_SATURATION)
+    };
+    if element.curve.saturation >= MAX_SATURATION {
+        return Some(Gluon::ZERO);
+    }
+    let mut high = element.curve.capacity.0;
+    while !reaches(high) {
+        high = high.checked_mul(2)?;
+    }
+    // Invariant: `low` falls short, `high` reaches
+    let mut low = 0;
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        match reaches(mid) {
+            true => high = mid,
+            false => low = mid,
+        }
+    }
+    Some(Gluon(high))
+}
@@ -0,0 +1,98 @@
+//! PDA seeds for the game accounts, shared by the program and clients.
+//!
+//! Every game account lives at `[prefix, key, bump]`: the board is a singleton (empty key),
+//! elements are keyed by atomic number and artefacts by the full `ElementIndex`, so each
+//! generation gets its own artefact. The canonical bump is stored in the account header.
+
+use crate::{
+    board::{Artefact, Board, Element},
+    types::ElementIndex,
+};
+
+/// Seed prefix for the global Board singleton.
+pub const BOARD_SEED: &[u8] = b"board";
+/// Seed prefix for Elements, followed by the atomic number byte.
+pub const ELEMENT_SEED: &[u8] = b"element";
+/// Seed prefix for Artefacts, followed by the little-endian `ElementIndex`.
+pub const ARTEFACT_SEED: &[u8] = b"artefact";
+
+/// Seeds of one account, excluding the bump.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Seeds {
+    prefix: &'static [u8],
+    key: [u8; 8],
+    len: usize,
+}
+
+impl Seeds {
+    /// Seeds of the board singleton.
+    pub const fn board() -> Self {
+        Self::new(BOARD_SEED, [0; 8], 0)
+    }
+
+    /// Seeds of the element with atomic number `atomic` (any generation).
+    pub const fn element(atomic: u64) -> Self {
+        Self::new(ELEMENT_SEED, [atomic as u8, 0, 0, 0, 0, 0, 0, 0], 1)
+    }
+
+    /// Seeds of the artefact snapshotting generation `index`.
+    pub const fn artefact(index: ElementIndex) -> Self {
+        Self::new(ARTEFACT_SEED, index.0.to_le_bytes(), 8)
+    }
+
+    const fn new(prefix: &'static [u8], key: [u8; 8], len: usize) -> Self {
+        Self { prefix, key, len }
+    }
+
+    /// `[prefix, key]`; an empty key hashes the same as no seed at all.
+    pub fn as_array(&self) -> [&[u8]; 2] {
+        [self.prefix, &self.key[..self.len]]
+    }
+}
+
+/// Game account whose address is determined by its own contents.
+pub trait Canonical {
+    fn seeds(&self) -> Seeds;
+}
+
+impl Canonical for Board {
+    fn seeds(&self) -> Seeds {
+        Seeds::board()
+    }
+}
+
+impl Canonical for Element {
+    fn seeds(&self) -> Seeds {
+        Seeds::element(self.index.atomic())
+    }
+}
+
+impl Canonical for Artefact {
+    fn seeds(&self) -> Seeds {
+        Seeds::artefact(self.index)
+    }
+}
+
+#[cfg(feature = "pda")]
+mod derive {
+    use solana_address::Address;
+
+    use super::Seeds;
+    use crate::types::AddressBytes;
+
+    impl Seeds {
+        /// Find the canonical address and bump under `program`.
+        pub fn find(&self, program: &AddressBytes) -> (AddressBytes, u8) {
+            let program = Address::new_from_array(*program);
+            let (address, bump) = Address::find_program_address(&self.as_array(), &program);
+            (address.to_bytes(), bump)
+        }
+
+        /// Address for a known `bump`, skipping the off-curve check.
+        /// Only valid for bumps previously returned by [`Seeds::find`].
+        pub fn derive(&self, bump: u8, program: &AddressBytes) -> AddressBytes {
+            let program = Address::new_from_array(*program);
+            Address::derive_address(&self.as_array(), Some(bump), &program).to_bytes()
+        }
+    }
+}
@@ -1,13 +1,25 @@
 use bytemuck::Zeroable;
+use curve::{
+    delta::Delta,
+    shape::{CurveShape, Shape},
+};
 
 use crate::{
-    action::{claim, compress, rebind},
-    board::{Artefact, Board, Curve, Element},
+    action::{bind, claim, compress, merge, overload, rebind, release, split, unbind},
+    board::{capacity, Artefact, Board, Curve, Element},
     consts::*,
+    error::TokamakError,
     fees::{bind_fee, compression_fee, fee_multiplier, rebind_fee},
+    guards::Guards,
+    header::{classify, AccountKind, Discriminated, Header, HeaderError},
     player::{Charge, Wallet},
+    pressure::{heat, history_pressure, mean_saturation, verify_history},
+    quantum::{self, QUANTUM_DEPTHS},
+    quote::{gluon_to_overload, saturation_after, share_for_deposit},
     round_divide,
-    types::{AddressBytes, Coordinates, ElementIndex, Gluon, Q824},
+    seeds::{Canonical, Seeds, ARTEFACT_SEED, BOARD_SEED, ELEMENT_SEED},
+    topology::{self, ElementSet, EDGE, INWARD, NEIGHBOURS},
+    types::{AddressBytes, Coordinates, ElementIndex, Gluon, Q1648, Q824},
 };
 
 // Helpers
@@ -15,31 +27,41 @@ fn dummy_address() -> AddressBytes {
     [0u8; 32]
 }
 
-fn make_element(atomic: u64, gen: u64, capacity: Gluon, pot: Gluon) -> Element {
+/// Element with raw `capacity` and `pot` base units.
+fn make_element(atomic: u64, gen: u64, capacity: u64, pot: u64) -> Element {
     let mut index = ElementIndex(0);
     index.0 = (atomic << 56) | (gen & ((1u64 << 56) - 1));
     let mut curve = Curve::zeroed();
-    curve.capacity = capacity;
+    curve.capacity = Gluon(capacity);
     Element {
         index,
-        pot,
+        pot: Gluon(pot),
         coordinates: Coordinates(1u64),
         curve,
+        charges: 0,
+        _pad: 0,
     }
 }
 
-fn make_charge(balance: Gluon, index: ElementIndex, share: Q824) -> Charge {
+/// Charge with a raw `balance` (base units) and `share` (Q8.24 bits).
+fn make_charge(balance: u64, index: ElementIndex, share: u32) -> Charge {
     use bytemuck::Zeroable;
     let mut charge = Charge::zeroed();
-    charge.balance = balance;
+    charge.balance = Gluon(balance);
     charge.timestamp = 0;
     charge.index = index;
-    charge.share = share;
+    charge.share = Q824(share);
     charge.authority = dummy_address();
     charge.mint = dummy_address();
     charge
 }
 
+/// Curve fields a charge move can change.
+fn curve_state(elem: &Element) -> (Gluon, Q824, Q1648, Q824) {
+    let c = elem.curve;
+    (c.tvl, c.saturation, c.pressure, c.shares)
+}
+
 // === ElementIndex Tests ===
 
 #[test]
@@ -74,6 +96,14 @@ fn element_index_clear() {
     assert!(idx.is_zero());
 }
 
+#[test]
+fn element_index_new_matches_layout() {
+    let idx = ElementIndex::new(26, 3);
+    assert_eq!(idx.0, (26u64 << 56) | 3);
+    assert_eq!(idx.atomic(), 26);
+    assert_eq!(idx.generation(), 3);
+}
+
 // === Coordinates Tests ===
 
 #[test]
@@ -142,19 +172,22 @@ fn round_divide_ties_away_from_zero() {
 
 #[test]
 fn rebind_to_empty_element() {
-    let mut charge = make_charge(100, ElementIndex((1u64 << 56) | 5), 50);
+    let mut board = Board::zeroed();
+    let mut charge = make_charge(100, ElementIndex(0), 0);
     let mut src = make_element(1, 0, 1000, 0);
-    src.curve.shares = 50;
+    bind(&mut charge, &mut src, &mut board).unwrap();
     let mut dst = Element {
         ..make_element(2, 0, 1000, 0)
     };
     dst.index.clear(); // mark destination as empty
 
-    rebind(&mut charge, &mut src, &mut dst);
+    rebind(&mut charge, &mut src, &mut dst).unwrap();
 
     // Rebinding to empty element: share becomes 0, index becomes empty.
     assert!(charge.index.is_zero());
-    assert_eq!(charge.share, 0);
+    assert_eq!(charge.share, Q824::ZERO);
+    assert_eq!(src.charges, 0);
+    assert_eq!(dst.charges, 0);
 }
 
 #[test]
@@ -164,11 +197,29 @@ fn rebind_updates_index_and_share() {
     src.index.clear();
     let mut dst = make_element(2, 0, 1000, 0);
 
-    rebind(&mut charge, &mut src, &mut dst);
+    rebind(&mut charge, &mut src, &mut dst).unwrap();
 
     assert_eq!(charge.index, dst.index);
-    // share is set by dx_for_dc; we expect some value
-    assert!(charge.share > 0 || charge.share == 0); // just check it's set
+    // share is set by dx_for_dc; a non-empty charge always gains some share
+    assert!(charge.share > Q824::ZERO);
+    assert_eq!(dst.curve.shares, charge.share);
+}
+
+#[test]
+fn rebind_away_returns_source_saturation() {
+    let mut board = Board::zeroed();
+    let mut h = make_element(1, 0, 1_000_000, 0);
+    let mut he = make_element(2, 0, 1_000_000, 0);
+    let mut resident = make_charge(200_000, ElementIndex(0), 0);
+    let mut mover = make_charge(100_000, ElementIndex(0), 0);
+    bind(&mut resident, &mut h, &mut board).unwrap();
+    let before = h.curve.saturation;
+    bind(&mut mover, &mut h, &mut board).unwrap();
+
+    // The withdrawal walks saturation back down rather than wrapping to the top of the curve
+    rebind(&mut mover, &mut h, &mut he).unwrap();
+    assert!(h.curve.saturation.abs_diff(before) <= Q824(1));
+    assert_eq!(h.curve.tvl, resident.balance);
 }
 
 #[test]
@@ -176,29 +227,123 @@ fn claim_distributes_reward() {
     let mut charge = make_charge(0, ElementIndex((1u64 << 56) | 1), 500_000); // share = 50%
     let mut artefact = Artefact::zeroed();
     artefact.shares = MAX_SATURATION;
-    artefact.pot = 1_000_000;
+    artefact.pot = Gluon(1_000_000);
     artefact.index = ElementIndex((1u64 << 56) | 1);
+    artefact.charges = 1; // another claimant still to come
 
     claim(&mut charge, &mut artefact);
 
     // reward = round_div(1_000_000, 500_000, MAX_POSITION) = portion of pot based on share
-    assert!(charge.balance > 0);
-    assert!(artefact.pot < 1_000_000);
-    assert!(charge.share == 0); // cleared
+    assert!(charge.balance > Gluon::ZERO);
+    assert!(artefact.pot < Gluon(1_000_000));
+    assert_eq!(artefact.shares, MAX_SATURATION - Q824(500_000)); // left for the others
+    assert!(charge.share == Q824::ZERO); // cleared
     assert!(charge.index.is_zero()); // cleared
 }
 
+/// A withdrawal the source curve never received is rejected instead of wrapping its TVL.
+#[test]
+fn rebind_rejects_withdrawal_past_curve_tvl() {
+    let mut charge = make_charge(100, ElementIndex((1u64 << 56) | 1), 0);
+    let mut src = make_element(1, 0, 1000, 0);
+    let mut dst = make_element(2, 0, 1000, 0);
+
+    let err = rebind(&mut charge, &mut src, &mut dst).unwrap_err();
+    assert_eq!(err, TokamakError::CurveOutOfRange);
+    let curve = src.curve;
+    assert_eq!(
+        (curve.tvl, curve.pressure, curve.saturation),
+        (Gluon::ZERO, Q1648::ZERO, Q824::ZERO)
+    );
+}
+
 #[test]
 fn compress_moves_pot() {
-    let mut charge = make_charge(100, ElementIndex((1u64 << 56) | 1), 100);
+    let mut board = Board::zeroed();
+    let mut charge = make_charge(100, ElementIndex(0), 0);
     let mut src = make_element(1, 0, 1000, 500);
-    src.curve.shares = 100;
+    bind(&mut charge, &mut src, &mut board).unwrap();
     let mut dst = make_element(2, 0, 1000, 200);
 
-    compress(&mut charge, &mut src, &mut dst);
+    compress(&mut charge, &mut src, &mut dst).unwrap();
 
-    assert_eq!(src.pot, 0);
-    assert_eq!(dst.pot, 700); // 200 + 500
+    assert_eq!(src.pot, Gluon::ZERO);
+    assert_eq!(dst.pot, Gluon(700)); // 200 + 500
+    assert_eq!((src.charges, dst.charges), (0, 1));
+}
+
+/// Split divides the share in proportion, rounding down for the new part, and leaves the curve
+/// alone; both parts then unbind like the whole charge would.
+#[test]
+fn split_divides_share_without_moving_curve() {
+    let mut board = Board::zeroed();
+    let mut elem = make_element(1, 0, 1_000_000, 0);
+    let mut charge = make_charge(300_001, ElementIndex(0), 0);
+    bind(&mut charge, &mut elem, &mut board).unwrap();
+    let (curve, share) = (curve_state(&elem), charge.share);
+    let mut part = Charge::zeroed();
+
+    split(
+        &mut charge,
+        &mut part,
+        Gluon(100_000),
+        &mut elem,
+        &mut board,
+    );
+
+    let expected = (share.0 as u128 * 100_000 / 300_001) as u32;
+    assert_eq!(part.share, Q824(expected));
+    assert_eq!(charge.share + part.share, share);
+    assert_eq!(
+        (charge.balance, part.balance),
+        (Gluon(200_001), Gluon(100_000))
+    );
+    assert_eq!(
+        (part.index, part.timestamp),
+        (charge.index, charge.timestamp)
+    );
+    assert_eq!(curve_state(&elem), curve);
+    assert_eq!((elem.charges, board.charge_count), (2, 2));
+
+    unbind(&mut part, &mut elem, &mut board).unwrap();
+    unbind(&mut charge, &mut elem, &mut board).unwrap();
+    assert_eq!(
+        (elem.curve.tvl, elem.curve.shares),
+        (Gluon::ZERO, Q824::ZERO)
+    );
+    assert_eq!(
+        (elem.charges, board.charge_count, board.tvl),
+        (0, 0, Gluon::ZERO)
+    );
+}
+
+/// Merge adds balances and shares exactly, empties the other charge and keeps the later
+/// timestamp.
+#[test]
+fn merge_undoes_split() {
+    let mut board = Board::zeroed();
+    let mut elem = make_element(1, 0, 1_000_000, 0);
+    let mut charge = make_charge(300_001, ElementIndex(0), 0);
+    bind(&mut charge, &mut elem, &mut board).unwrap();
+    let (curve, whole) = (curve_state(&elem), charge);
+    let mut part = Charge::zeroed();
+    split(
+        &mut charge,
+        &mut part,
+        Gluon(100_000),
+        &mut elem,
+        &mut board,
+    );
+    part.timestamp = 42;
+
+    merge(&mut charge, &mut part, &mut elem, &mut board);
+
+    assert_eq!((charge.balance, charge.share), (whole.balance, whole.share));
+    assert_eq!(charge.timestamp, 42);
+    assert_eq!((part.balance, part.share), (Gluon::ZERO, Q824::ZERO));
+    assert!(part.index.is_zero());
+    assert_eq!(curve_state(&elem), curve);
+    assert_eq!((elem.charges, board.charge_count), (1, 1));
 }
 
 // === Fee Tests ===
@@ -253,40 +398,553 @@ fn rebind_fee_respects_min() {
     assert!(fee >= MIN_FEE);
 }
 
-// === Board & Wallet Tests ===
+#[test]
+fn rebind_fee_outward_uses_distance() {
+    let charge = make_charge(1_000_000_000_000, ElementIndex(0), 0);
+    let mut shallow = make_element(5, 0, 1000, 0);
+    let mut deep = make_element(10, 0, 1000, 0);
+    (shallow.curve.saturation, deep.curve.saturation) = (MAX_SATURATION, MAX_SATURATION);
+
+    // Same |ΔZ| and saturation either way; outward must not wrap the distance
+    let inward = rebind_fee(&charge, &shallow, &deep);
+    assert_eq!(rebind_fee(&charge, &deep, &shallow), inward);
+    assert!(inward > MIN_FEE);
+}
+
+// === Genesis Tests ===
 
 #[test]
-fn wallet_structure_correct() {
-    use bytemuck::Zeroable;
-    let mut wallet = Wallet::zeroed();
-    wallet.balance = 1_000_000;
-    wallet.authority = dummy_address();
-    wallet.mint = dummy_address();
-    assert_eq!(wallet.balance, 1_000_000);
+fn genesis_element_uses_layout_and_generation_zero() {
+    for atomic in 1..=MAX_ATOMIC_NUMBER {
+        let element = Element::genesis(atomic).unwrap();
+        let coordinates = ELEMENT_COORDINATES[(atomic - 1) as usize];
+        assert_eq!(element.index, ElementIndex::new(atomic, 0));
+        assert_eq!(element.coordinates.0, coordinates.0);
+        assert_eq!(element.pot, Gluon::ZERO);
+        assert_eq!(element.curve.saturation, Q824::ZERO);
+        assert_eq!(element.curve.shares, Q824::ZERO);
+        assert_eq!(
+            element.curve.capacity,
+            capacity(GENESIS_TVL, atomic, coordinates)
+        );
+        assert!(element.curve.capacity > Gluon::ZERO);
+    }
+}
+
+#[test]
+fn genesis_rejects_off_board_atomic_numbers() {
+    assert!(Element::genesis(0).is_none());
+    assert!(Element::genesis(MAX_ATOMIC_NUMBER + 1).is_none());
+}
+
+#[test]
+fn genesis_capacity_grows_with_depth() {
+    // Fe (Z=26, 3 tiles) gets a larger curve than H (Z=1, 4 tiles).
+    let h = Element::genesis(1).unwrap();
+    let fe = Element::genesis(26).unwrap();
+    assert!(fe.curve.capacity > h.curve.capacity);
+}
+
+// === Header Tests ===
+
+#[test]
+fn header_check_accepts_matching_kind_and_version() {
+    assert_eq!(Charge::HEADER.check::<Charge>(), Ok(()));
+    assert_eq!(Element::HEADER.check::<Element>(), Ok(()));
 }
 
 #[test]
-fn board_structure_correct() {
-    let board = Board {
-        tvl: 5_000_000,
-        quantum_pocket: 100_000,
-        charge_count: 42,
-        quantum_index: 3,
-        _pad: [0; 3],
+fn header_check_rejects_wrong_kind() {
+    // A 48-byte Element must never be read as a Charge (and vice versa).
+    assert_eq!(
+        Element::HEADER.check::<Charge>(),
+        Err(HeaderError::WrongKind {
+            expected: AccountKind::Charge,
+            found: AccountKind::Element,
+        })
+    );
+}
+
+#[test]
+fn header_check_rejects_uninitialized_and_unknown() {
+    assert_eq!(
+        Header::zeroed().check::<Wallet>(),
+        Err(HeaderError::Uninitialized)
+    );
+    let mut header = Wallet::HEADER;
+    header.discriminator = 0xFF;
+    assert_eq!(
+        header.check::<Wallet>(),
+        Err(HeaderError::UnknownKind(0xFF))
+    );
+}
+
+#[test]
+fn header_check_rejects_other_version() {
+    let mut header = Board::HEADER;
+    header.version += 1;
+    assert_eq!(
+        header.check::<Board>(),
+        Err(HeaderError::UnsupportedVersion {
+            expected: Board::VERSION,
+            found: Board::VERSION + 1,
+        })
+    );
+}
+
+#[test]
+fn classify_reads_discriminator() {
+    let mut data = bytemuck::bytes_of(&Artefact::HEADER).to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&Artefact::zeroed()));
+    assert_eq!(classify(&data), Ok(AccountKind::Artefact));
+    assert_eq!(classify(&[]), Err(HeaderError::TooSmall));
+    assert_eq!(Artefact::SPACE, data.len());
+}
+
+// === Error Tests ===
+
+#[test]
+fn error_codes_are_stable() {
+    // Wire codes are part of the client contract; never renumber.
+    assert_eq!(TokamakError::WrongAccountKind.code(), 100);
+    assert_eq!(TokamakError::ChargeAlreadyBound.code(), 200);
+    assert_eq!(TokamakError::AccountNotEmpty.code(), 203);
+    assert_eq!(TokamakError::NotAdjacent.code(), 300);
+    assert_eq!(TokamakError::BelowOverloadThreshold.code(), 400);
+    assert_eq!(TokamakError::ArtefactMismatch.code(), 402);
+    assert_eq!(TokamakError::FeeAboveLimit.code(), 600);
+}
+
+#[test]
+fn error_codes_round_trip() {
+    for code in 0..1000 {
+        if let Some(err) = TokamakError::from_code(code) {
+            assert_eq!(err.code(), code);
+        }
+    }
+    assert_eq!(TokamakError::from_code(0), None);
+    assert_eq!(TokamakError::from_code(42), None);
+}
+
+// === Guards Tests ===
+
+#[test]
+fn guards_none_accepts_any_move() {
+    let none = Guards::NONE;
+    assert_eq!(none.check_deadline(u64::MAX), Ok(()));
+    assert_eq!(none.check_fee(Gluon::MAX), Ok(()));
+    assert_eq!(none.check_share(Q824::ZERO), Ok(()));
+}
+
+#[test]
+fn guards_limits_are_inclusive() {
+    let guards = Guards {
+        max_fee: Gluon(500),
+        deadline_slot: 100,
+        min_share: Q824(1 << 24),
+        _pad: 0,
     };
-    assert_eq!(board.tvl, 5_000_000);
-    assert_eq!(board.charge_count, 42);
-    assert_eq!(board.quantum_index, 3);
+    assert_eq!(guards.check_deadline(100), Ok(()));
+    assert_eq!(
+        guards.check_deadline(101),
+        Err(TokamakError::DeadlinePassed)
+    );
+    assert_eq!(guards.check_fee(Gluon(500)), Ok(()));
+    assert_eq!(
+        guards.check_fee(Gluon(501)),
+        Err(TokamakError::FeeAboveLimit)
+    );
+    assert_eq!(guards.check_share(Q824(1 << 24)), Ok(()));
+    assert_eq!(
+        guards.check_share(Q824((1 << 24) - 1)),
+        Err(TokamakError::ShareBelowLimit)
+    );
+}
+
+// === Seeds Tests ===
+
+#[test]
+fn seeds_key_accounts_by_identity() {
+    assert_eq!(Seeds::board().as_array(), [BOARD_SEED, &[][..]]);
+    assert_eq!(Seeds::element(26).as_array(), [ELEMENT_SEED, &[26u8][..]]);
+    let index = ElementIndex::new(3, 7);
+    let key = index.0.to_le_bytes();
+    assert_eq!(Seeds::artefact(index).as_array(), [ARTEFACT_SEED, &key[..]]);
+}
+
+#[test]
+fn element_seeds_ignore_generation() {
+    // Elements keep their address across resets; artefacts get one per generation.
+    let mut element = make_element(5, 0, 0, 0);
+    let before = element.seeds();
+    element.index.advance_generation();
+    assert_eq!(element.seeds(), before);
+
+    let mut artefact = Artefact::zeroed();
+    artefact.index = ElementIndex::new(5, 0);
+    let gen0 = artefact.seeds();
+    artefact.index = ElementIndex::new(5, 1);
+    assert_ne!(artefact.seeds(), gen0);
+    assert_eq!(Board::zeroed().seeds(), Seeds::board());
+}
+
+// === Quantum Pocket Tests ===
+
+#[test]
+fn quantum_fe_injection_moves_yield_from_pot() {
+    let mut board = Board::zeroed();
+    let mut pot = Gluon(1_000_000);
+    let injected = quantum::inject(&mut board, &mut pot);
+    assert_eq!(injected, Gluon(100_000));
+    assert_eq!(pot + board.quantum_pocket, Gluon(1_000_000));
+}
+
+#[test]
+fn quantum_unlocks_only_next_depth_from_edge_inward() {
+    let mut board = Board::zeroed();
+    board.quantum_pocket = Gluon(2_500_000);
+    // Out of order: He before H unlocks nothing
+    assert_eq!(quantum::unlock(&mut board, 2), Gluon::ZERO);
+    assert_eq!(board.quantum_index, 0);
+    // H takes 1/25, He 1/24 of the remainder, ...
+    assert_eq!(quantum::unlock(&mut board, 1), Gluon(100_000));
+    assert_eq!(quantum::unlock(&mut board, 2), Gluon(100_000));
+    assert_eq!(board.quantum_index, 2);
+    assert_eq!(board.quantum_pocket, Gluon(2_300_000));
+}
+
+#[test]
+fn quantum_cycle_drains_pocket_and_restarts_at_h() {
+    let mut board = Board::zeroed();
+    board.quantum_pocket = Gluon(1_000_003);
+    let mut released = Gluon::ZERO;
+    for atomic in 1..=QUANTUM_DEPTHS as u64 {
+        released += quantum::unlock(&mut board, atomic);
+    }
+    assert_eq!(released, Gluon(1_000_003));
+    assert_eq!(board.quantum_pocket, Gluon::ZERO);
+    assert_eq!(board.quantum_index, 0);
+    assert_eq!(quantum::next_depth(board.quantum_index), 1);
+    // Fe never unlocks
+    assert_eq!(quantum::unlock(&mut board, MAX_ATOMIC_NUMBER), Gluon::ZERO);
+}
+
+#[test]
+fn quantum_schedule_matches_unlocks() {
+    let mut board = Board::zeroed();
+    board.quantum_pocket = Gluon(777_777);
+    board.quantum_index = 5;
+    let forecast: Vec<_> = quantum::schedule(board.quantum_pocket, board.quantum_index).collect();
+    assert_eq!(forecast.len(), (QUANTUM_DEPTHS - 5) as usize);
+    assert_eq!(forecast[0].0, 6);
+    for (atomic, amount) in forecast {
+        assert_eq!(quantum::unlock(&mut board, atomic), amount);
+    }
+    assert_eq!(board.quantum_pocket, Gluon::ZERO);
+}
+
+// === Board Accounting Tests ===
+
+/// Board totals must equal live curves plus outstanding artefacts.
+fn assert_board_totals(board: &Board, elements: &[&Element], artefacts: &[&Artefact]) {
+    let tvl = elements.iter().map(|e| e.curve.tvl).sum::<Gluon>()
+        + artefacts.iter().map(|a| a.tvl).sum::<Gluon>();
+    let count = elements.iter().map(|e| e.charges).sum::<u32>()
+        + artefacts.iter().map(|a| a.charges).sum::<u32>();
+    assert_eq!(board.tvl, tvl);
+    assert_eq!(board.charge_count, count);
+}
+
+#[test]
+fn bind_rebind_unbind_keep_board_totals() {
+    let mut board = Board::zeroed();
+    let mut h = make_element(1, 0, 1_000_000, 0);
+    let mut he = make_element(2, 0, 1_000_000, 0);
+    let mut a = make_charge(10_000, ElementIndex(0), 0);
+    let mut b = make_charge(20_000, ElementIndex(0), 0);
+
+    bind(&mut a, &mut h, &mut board).unwrap();
+    bind(&mut b, &mut h, &mut board).unwrap();
+    assert_eq!(
+        (board.tvl, board.charge_count, h.charges),
+        (Gluon(30_000), 2, 2)
+    );
+
+    // A fee taken between moves stays in the source curve as residue
+    b.balance -= Gluon(500);
+    rebind(&mut b, &mut h, &mut he).unwrap();
+    assert_board_totals(&board, &[&h, &he], &[]);
+
+    unbind(&mut a, &mut h, &mut board).unwrap();
+    assert_board_totals(&board, &[&h, &he], &[]);
+    assert_eq!((board.charge_count, h.charges, he.charges), (1, 0, 1));
+}
+
+#[test]
+fn overload_ejects_other_charges_until_they_claim() {
+    let mut board = Board::zeroed();
+    let mut li = make_element(3, 0, 1_000_000, 0);
+    let mut trigger = make_charge(40_000, ElementIndex(0), 0);
+    let mut others = [10_000, 20_000].map(|balance| make_charge(balance, ElementIndex(0), 0));
+    for charge in &mut others {
+        bind(charge, &mut li, &mut board).unwrap();
+    }
+    bind(&mut trigger, &mut li, &mut board).unwrap();
+    li.pot = Gluon(90_000);
+    // Vented value leaves a residue in the curve
+    others[1].balance -= Gluon(2_000);
+
+    let mut artefact = Artefact::zeroed();
+    artefact.index = li.index;
+    overload(&mut trigger, &mut li, &mut artefact, &mut board).unwrap();
+
+    assert_eq!(artefact.charges, 2);
+    // 28_000 of ejected balances plus the 2_000 residue
+    assert_eq!(artefact.tvl, Gluon(30_000));
+    assert_eq!(li.charges, 1);
+    assert_eq!(li.curve.tvl, trigger.balance);
+    assert_eq!(trigger.index, li.index);
+    assert_board_totals(&board, &[&li], &[&artefact]);
+
+    for charge in &mut others {
+        release(charge, &mut artefact, &mut board);
+        assert!(charge.index.is_zero());
+        assert_board_totals(&board, &[&li], &[&artefact]);
+    }
+    assert_eq!((artefact.tvl, artefact.charges), (Gluon::ZERO, 0));
+    assert_eq!((board.tvl, board.charge_count), (trigger.balance, 1));
+}
+
+/// Each claimant is paid its share of the pot as it stood at the reset, and the last one
+/// empties it: nothing is stranded once every ejected charge has claimed.
+#[test]
+fn claims_split_the_whole_pot_by_share() {
+    let mut board = Board::zeroed();
+    let mut li = make_element(3, 0, 1_000_000_000, 0);
+    let mut trigger = make_charge(300_000_000, ElementIndex(0), 0);
+    let mut others =
+        [100_000_000, 200_000_000].map(|balance| make_charge(balance, ElementIndex(0), 0));
+    for charge in &mut others {
+        bind(charge, &mut li, &mut board).unwrap();
+    }
+    bind(&mut trigger, &mut li, &mut board).unwrap();
+    li.pot = Gluon(900_000_000);
+    let (pot, shares) = (li.pot.0, li.curve.shares.0 as u64);
+    let paid = |share: Q824| round_divide(pot, share.0 as u64, shares);
+
+    let mut artefact = Artefact::zeroed();
+    artefact.index = li.index;
+    let (balance, share) = (trigger.balance, trigger.share);
+    overload(&mut trigger, &mut li, &mut artefact, &mut board).unwrap();
+    assert_eq!(trigger.balance - balance, Gluon(paid(share)));
+
+    for charge in &mut others {
+        let (balance, share) = (charge.balance, charge.share);
+        release(charge, &mut artefact, &mut board);
+        // Rounding moves at most a unit per earlier claim
+        assert!((charge.balance - balance).0.abs_diff(paid(share)) <= 2);
+    }
+    assert_eq!(
+        (artefact.pot, artefact.shares, artefact.charges),
+        (Gluon::ZERO, Q824::ZERO, 0)
+    );
+}
+
+#[test]
+fn overload_alone_drops_curve_residue() {
+    let mut board = Board::zeroed();
+    let mut be = make_element(4, 0, 1_000_000, 5_000);
+    let mut trigger = make_charge(40_000, ElementIndex(0), 0);
+    bind(&mut trigger, &mut be, &mut board).unwrap();
+    trigger.balance -= Gluon(1_000);
+
+    let mut artefact = Artefact::zeroed();
+    artefact.index = be.index;
+    overload(&mut trigger, &mut be, &mut artefact, &mut board).unwrap();
+
+    assert_eq!(
+        (artefact.tvl, artefact.charges, artefact.pot),
+        (Gluon::ZERO, 0, Gluon::ZERO)
+    );
+    assert_eq!(trigger.balance, Gluon(44_000));
+    assert_eq!(be.index.generation(), 1);
+    assert_eq!(be.curve.capacity, capacity(GENESIS_TVL, 4, be.coordinates));
+    assert_board_totals(&board, &[&be], &[&artefact]);
+}
+
+#[test]
+fn curve_shape_prices_binds_and_survives_overload() {
+    let mut board = Board::zeroed();
+    let mut sigmoid = make_element(4, 0, 1_000_000, 0);
+    let mut linear = make_element(4, 0, 1_000_000, 0);
+    linear.curve.shape = 1;
+    let mut a = make_charge(100_000, ElementIndex(0), 0);
+    let mut b = make_charge(100_000, ElementIndex(0), 0);
+    bind(&mut a, &mut sigmoid, &mut board).unwrap();
+    bind(&mut b, &mut linear, &mut board).unwrap();
+    assert_ne!(a.share, b.share);
+    // A tenth of capacity on the linear shape is a tenth of the domain, rounded down
+    assert_eq!(b.share, Q824(MAX_SATURATION.0 / 10));
+
+    let mut artefact = Artefact::zeroed();
+    artefact.index = linear.index;
+    overload(&mut b, &mut linear, &mut artefact, &mut board).unwrap();
+    assert_eq!(linear.curve.shape, 1);
+    assert!(matches!(linear.curve.shape(), Shape::Linear(_)));
+}
+
+// === Pressure Tests ===
+
+#[test]
+fn heat_is_pressure_past_a_full_curve() {
+    let mut board = Board::zeroed();
+    let mut b = make_element(5, 0, 1_000_000, 0);
+    let mut first = make_charge(300_000, ElementIndex(0), 0);
+    bind(&mut first, &mut b, &mut board).unwrap();
+    assert!(
+        heat(&b.curve) < Q1648(1 << 24),
+        "only rounding dust below the top"
+    );
+
+    // Twice the capacity saturates the curve and leaves the rest of its cost as heat
+    let mut flood = make_charge(2_000_000, ElementIndex(0), 0);
+    bind(&mut flood, &mut b, &mut board).unwrap();
+    assert_eq!(b.curve.saturation, MAX_SATURATION);
+    let full = b.curve.shape().s_max();
+    assert_eq!(heat(&b.curve), b.curve.pressure - full);
+    assert!(heat(&b.curve) > full);
+}
+
+#[test]
+fn mean_saturation_weights_saturation_by_cost() {
+    let mut board = Board::zeroed();
+    let mut b = make_element(5, 0, 1_000_000, 0);
+    assert_eq!(mean_saturation(&b.curve), Q824::ZERO);
+
+    // Constant price: capital spreads evenly, so the mean is half the saturation
+    b.curve.shape = 1;
+    let mut charge = make_charge(400_000, ElementIndex(0), 0);
+    bind(&mut charge, &mut b, &mut board).unwrap();
+    let x = b.curve.saturation;
+    assert!(mean_saturation(&b.curve).abs_diff(Q824(x.0 / 2)) <= Q824(1));
+
+    // Rising price on the sigmoid's lower half weights the later, higher saturation
+    let mut s = make_element(6, 0, 1_000_000, 0);
+    let mut charge = make_charge(200_000, ElementIndex(0), 0);
+    bind(&mut charge, &mut s, &mut board).unwrap();
+    let mean = mean_saturation(&s.curve);
+    let x = s.curve.saturation;
+    assert!(mean > Q824(x.0 / 2) && mean < x);
+}
+
+#[test]
+fn history_checksum_replays_binds_and_unbinds() {
+    let mut board = Board::zeroed();
+    let mut h = make_element(1, 0, 1_000_000, 0);
+    let mut he = make_element(2, 0, 1_000_000, 0);
+    let mut a = make_charge(300_000, ElementIndex(0), 0);
+    let mut b = make_charge(120_000, ElementIndex(0), 0);
+    bind(&mut a, &mut h, &mut board).unwrap();
+    bind(&mut b, &mut h, &mut board).unwrap();
+    a.balance -= Gluon(1_000); // a fee leaves residue on the source curve
+    rebind(&mut a, &mut h, &mut he).unwrap();
+
+    let history = [
+        Delta::Deposit(Gluon(300_000)),
+        Delta::Deposit(Gluon(120_000)),
+        Delta::Withdrawal(Gluon(299_000)),
+    ];
+    assert_eq!(history_pressure(&h.curve, &history), Ok(h.curve.pressure));
+    assert!(verify_history(&h.curve, &history));
+    let reordered = [history[1], history[0], history[2]];
+    assert!(verify_history(&h.curve, &reordered));
+    assert!(!verify_history(&h.curve, &history[..2]));
+    assert!(!verify_history(
+        &h.curve,
+        &[history[2], history[0], history[1]]
+    ));
+}
+
+// === Quote Tests ===
+
+#[test]
+fn share_quote_matches_bind() {
+    let mut board = Board::zeroed();
+    let mut li = make_element(3, 0, 1_000_000, 0);
+    let mut first = make_charge(150_000, ElementIndex(0), 0);
+    bind(&mut first, &mut li, &mut board).unwrap();
+
+    let quote = share_for_deposit(&li, Gluon(50_000));
+    let mut charge = make_charge(50_000, ElementIndex(0), 0);
+    bind(&mut charge, &mut li, &mut board).unwrap();
+    assert_eq!(charge.share, quote.unwrap());
+}
+
+#[test]
+fn saturation_quote_matches_moves() {
+    let mut board = Board::zeroed();
+    let mut h = make_element(1, 0, 1_000_000, 0);
+    let mut he = make_element(2, 0, 1_000_000, 0);
+    let start = h;
+    let mut a = make_charge(300_000, ElementIndex(0), 0);
+    let mut b = make_charge(120_000, ElementIndex(0), 0);
+
+    bind(&mut a, &mut h, &mut board).unwrap();
+    bind(&mut b, &mut h, &mut board).unwrap();
+    rebind(&mut a, &mut h, &mut he).unwrap();
+    assert_eq!(
+        saturation_after(&start, &[300_000, 120_000, -300_000]),
+        Ok(h.curve.saturation)
+    );
+    assert_eq!(saturation_after(&start, &[]), Ok(Q824::ZERO));
+}
+
+#[test]
+fn overload_quote_is_the_smallest_reaching_deposit() {
+    let mut board = Board::zeroed();
+    let mut fe = make_element(26, 0, 1_000_000, 0);
+    let mut resident = make_charge(400_000, ElementIndex(0), 0);
+    bind(&mut resident, &mut fe, &mut board).unwrap();
+
+    let needed = gluon_to_overload(&fe).unwrap();
+    assert!(saturation_after(&fe, &[needed.0 as i64]).unwrap() >= MAX_SATURATION);
+    assert!(saturation_after(&fe, &[needed.0 as i64 - 1]).unwrap() < MAX_SATURATION);
+
+    let mut trigger = make_charge(needed.0, ElementIndex(0), 0);
+    bind(&mut trigger, &mut fe, &mut board).unwrap();
+    assert_eq!(fe.curve.saturation, MAX_SATURATION);
+    assert_eq!(gluon_to_overload(&fe), Some(Gluon::ZERO));
+
+    fe.index.clear();
+    assert_eq!(gluon_to_overload(&fe), None);
+}
+
+// === Board & Wallet Tests ===
+
+#[test]
+fn wallet_layout_has_no_implicit_padding() {
+    // balance + authority + mint + charges + open
+    assert_eq!(size_of::<Wallet>(), 8 + 32 + 32 + 4 + 4);
+    assert_eq!(size_of::<Wallet>() % align_of::<Wallet>(), 0);
+}
+
+#[test]
+fn board_layout_has_no_implicit_padding() {
+    // tvl + quantum_pocket + charge_count + quantum_index + _pad
+    assert_eq!(size_of::<Board>(), 8 + 8 + 4 + 1 + 3);
+    assert_eq!(size_of::<Board>() % align_of::<Board>(), 0);
 }
 
 // === Constant Tests ===
 
 #[test]
 fn consts_reasonable_values() {
-    assert!(MIN_FEE > 0);
-    assert!(MAX_ATOMIC_NUMBER > 0);
-    assert!(MAX_SPEED_MULTIPLIER > 0);
-    assert!(MAX_DELTA_TIMESTAMP > 0);
+    const {
+        assert!(MIN_FEE.0 > 0);
+        assert!(MAX_ATOMIC_NUMBER > 0);
+        assert!(MAX_SPEED_MULTIPLIER > 0);
+        assert!(MAX_DELTA_TIMESTAMP > 0);
+    }
 }
 
 // === Coordinates Tests ===
@@ -295,7 +953,7 @@ fn consts_reasonable_values() {
 /// Row-major layout: A1=0, B1=1, ..., H1=7, A2=8, ..., H8=63.
 fn coord_to_bit(coord: &str) -> u64 {
     let col = (coord.as_bytes()[0] - b'A') as u64;
-    let row = (coord[1..].parse::<u64>().unwrap() - 1) as u64;
+    let row = coord[1..].parse::<u64>().unwrap() - 1;
     1 << (row * 8 + col)
 }
 
@@ -575,3 +1233,62 @@ fn all_elements_used() {
     ];
     assert_eq!(all_elements.len(), 26);
 }
+
+// === Topology Tests ===
+
+#[test]
+fn topology_agrees_with_coordinates() {
+    for a in 1..=MAX_ATOMIC_NUMBER {
+        let ca = ELEMENT_COORDINATES[(a - 1) as usize];
+        assert_eq!(EDGE.contains(a), ca.on_edge(), "edge mismatch at {a}");
+        for b in 1..=MAX_ATOMIC_NUMBER {
+            let cb = ELEMENT_COORDINATES[(b - 1) as usize];
+            let expected = a != b && ca.adjacent(cb);
+            assert_eq!(topology::adjacent(a, b), expected, "{a} <-> {b}");
+            assert_eq!(topology::adjacent(a, b), topology::adjacent(b, a));
+            assert_eq!(topology::compressible(a, b), expected && b > a);
+        }
+    }
+}
+
+#[test]
+fn topology_edge_is_first_twelve() {
+    assert_eq!(EDGE, ElementSet((1 << 12) - 1));
+    assert_eq!(EDGE.len(), 12);
+    assert!(EDGE.iter().eq(1..=12));
+}
+
+#[test]
+fn topology_every_element_but_fe_compresses_inward() {
+    for atomic in 1..MAX_ATOMIC_NUMBER {
+        assert!(!INWARD[(atomic - 1) as usize].is_empty(), "{atomic}");
+    }
+    assert!(INWARD[(MAX_ATOMIC_NUMBER - 1) as usize].is_empty());
+}
+
+#[test]
+fn topology_paths_are_shortest_and_adjacent() {
+    for src in 1..=MAX_ATOMIC_NUMBER {
+        for dst in 1..=MAX_ATOMIC_NUMBER {
+            let hops: Vec<u64> = topology::path(src, dst).collect();
+            assert_eq!(hops.len(), topology::distance(src, dst) as usize);
+            assert_eq!(hops.last().copied().unwrap_or(src), dst);
+            let mut at = src;
+            for hop in hops {
+                assert!(NEIGHBOURS[(at - 1) as usize].contains(hop));
+                at = hop;
+            }
+        }
+    }
+    assert_eq!(topology::distance(1, 1), 0);
+    assert_eq!(topology::distance(1, 2), 1);
+    assert!(topology::distance(1, MAX_ATOMIC_NUMBER) > 1);
+}
+
+#[test]
+fn topology_diameter_is_longest_path() {
+    let longest = (1..=MAX_ATOMIC_NUMBER)
+        .flat_map(|src| (1..=MAX_ATOMIC_NUMBER).map(move |dst| topology::distance(src, dst)))
+        .max();
+    assert_eq!(longest, Some(topology::DIAMETER));
+}
@@ -0,0 +1,225 @@
+//! Board topology: the element adjacency graph, precomputed from `ELEMENT_COORDINATES`.
+//!
+//! Everything here is evaluated at compile time: neighbour sets, the edge (entry/exit)
+//! elements, Z-increasing compression edges and all-pairs shortest paths. The build fails if
+//! the coordinate masks do not tile the 8×8 board exactly once or leave an element isolated.
+//! Elements are addressed by atomic number (1..=26); out-of-range atomics panic.
+
+use crate::{
+    consts::{ELEMENT_COORDINATES, MAX_ATOMIC_NUMBER},
+    types::Coordinates,
+};
+
+const N: usize = MAX_ATOMIC_NUMBER as usize;
+
+/// Set of elements as a bitmask: bit `atomic - 1` is set for each member.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ElementSet(pub u32);
+
+impl ElementSet {
+    pub const EMPTY: Self = Self(0);
+
+    /// True if element `atomic` is a member.
+    #[inline]
+    pub const fn contains(self, atomic: u64) -> bool {
+        atomic >= 1 && atomic <= MAX_ATOMIC_NUMBER && self.0 & (1 << (atomic - 1)) != 0
+    }
+
+    /// Number of members.
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Member atomic numbers in increasing order.
+    pub fn iter(self) -> impl Iterator<Item = u64> {
+        (1..=MAX_ATOMIC_NUMBER).filter(move |&atomic| self.contains(atomic))
+    }
+
+    const fn with(self, atomic: usize) -> Self {
+        Self(self.0 | 1 << (atomic - 1))
+    }
+}
+
+/// Elements sharing an orthogonal edge with each element, indexed by `atomic - 1`.
+pub const NEIGHBOURS: [ElementSet; N] = neighbours_table();
+
+/// Elements touching the board perimeter: the only ones that accept Bind and Unbind.
+pub const EDGE: ElementSet = edge_set();
+
+/// Valid Compress destinations of each element: neighbours with strictly higher Z.
+pub const INWARD: [ElementSet; N] = inward_table();
+
+/// Minimum number of rebinds between two elements, indexed by `atomic - 1`.
+pub const DISTANCE: [[u8; N]; N] = shortest_paths().0;
+
+/// Longest shortest path on the board: the most rebinds any single move needs.
+pub const DIAMETER: u8 = diameter();
+
+/// First hop (atomic number) on a shortest path, indexed by `atomic - 1`; 0 on the diagonal.
+const NEXT_HOP: [[u8; N]; N] = shortest_paths().1;
+
+/// True if elements `a` and `b` share an edge (a single Rebind apart).
+#[inline]
+pub const fn adjacent(a: u64, b: u64) -> bool {
+    NEIGHBOURS[a as usize - 1].contains(b)
+}
+
+/// True if Compress may move from `src` to `dst`: adjacent and strictly deeper.
+#[inline]
+pub const fn compressible(src: u64, dst: u64) -> bool {
+    INWARD[src as usize - 1].contains(dst)
+}
+
+/// Minimum number of rebinds from `src` to `dst`.
+#[inline]
+pub const fn distance(src: u64, dst: u64) -> u8 {
+    DISTANCE[src as usize - 1][dst as usize - 1]
+}
+
+/// A shortest rebind path from `src` to `dst`, excluding `src` and including `dst`.
+/// Ties prefer the lower atomic number at each hop.
+pub fn path(src: u64, dst: u64) -> impl Iterator<Item = u64> {
+    let mut at = src;
+    core::iter::from_fn(move || {
+        if at == dst {
+            return None;
+        }
+        at = NEXT_HOP[at as usize - 1][dst as usize - 1] as u64;
+        Some(at)
+    })
+}
+
+const fn coordinates(atomic: usize) -> Coordinates {
+    ELEMENT_COORDINATES[atomic - 1]
+}
+
+const fn neighbours_table() -> [ElementSet; N] {
+    let mut table = [ElementSet::EMPTY; N];
+    let mut a = 1;
+    while a <= N {
+        let mut b = 1;
+        while b <= N {
+            if a != b && coordinates(a).adjacent(coordinates(b)) {
+                table[a - 1] = table[a - 1].with(b);
+            }
+            b += 1;
+        }
+        assert!(table[a - 1].0 != 0, "element has no neighbours");
+        a += 1;
+    }
+    table
+}
+
+const fn edge_set() -> ElementSet {
+    let mut set = ElementSet::EMPTY;
+    let mut a = 1;
+    while a <= N {
+        if coordinates(a).on_edge() {
+            set = set.with(a);
+        }
+        a += 1;
+    }
+    set
+}
+
+const fn inward_table() -> [ElementSet; N] {
+    let mut table = [ElementSet::EMPTY; N];
+    let mut a = 1;
+    while a <= N {
+        // Members above `a`: clear bits 0..a
+        table[a - 1] = ElementSet(NEIGHBOURS[a - 1].0 & !((1 << a) - 1));
+        a += 1;
+    }
+    table
+}
+
+/// Floyd–Warshall over the adjacency graph: (distances, next hops).
+const fn shortest_paths() -> ([[u8; N]; N], [[u8; N]; N]) {
+    const FAR: u8 = u8::MAX;
+    let mut dist = [[FAR; N]; N];
+    let mut next = [[0u8; N]; N];
+    let mut a = 0;
+    while a < N {
+        dist[a][a] = 0;
+        let mut b = 0;
+        while b < N {
+            if NEIGHBOURS[a].contains(b as u64 + 1) {
+                dist[a][b] = 1;
+                next[a][b] = b as u8 + 1;
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    let mut k = 0;
+    while k < N {
+        let mut a = 0;
+        while a < N {
+            let mut b = 0;
+            while b < N {
+                if k != a && k != b && dist[a][k] != FAR && dist[k][b] != FAR {
+                    let through = dist[a][k] + dist[k][b];
+                    let tie = through == dist[a][b] && next[a][k] < next[a][b];
+                    if through < dist[a][b] || tie {
+                        dist[a][b] = through;
+                        next[a][b] = next[a][k];
+                    }
+                }
+                b += 1;
+            }
+            a += 1;
+        }
+        k += 1;
+    }
+    let mut a = 0;
+    while a < N {
+        let mut b = 0;
+        while b < N {
+            assert!(dist[a][b] != FAR, "board is not connected");
+            b += 1;
+        }
+        a += 1;
+    }
+    (dist, next)
+}
+
+const fn diameter() -> u8 {
+    let mut max = 0;
+    let mut a = 0;
+    while a < N {
+        let mut b = 0;
+        while b < N {
+            if DISTANCE[a][b] > max {
+                max = DISTANCE[a][b];
+            }
+            b += 1;
+        }
+        a += 1;
+    }
+    max
+}
+
+// The coordinate masks must tile the 8×8 board: every square covered exactly once.
+const _: () = {
+    let mut union = 0u64;
+    let mut tiles = 0;
+    let mut a = 1;
+    while a <= N {
+        let mask = coordinates(a).0;
+        assert!(mask != 0, "element has no tiles");
+        assert!(union & mask == 0, "element masks overlap");
+        union |= mask;
+        tiles += coordinates(a).tiles();
+        a += 1;
+    }
+    assert!(
+        union == u64::MAX && tiles == 64,
+        "element masks do not cover the board"
+    );
+};
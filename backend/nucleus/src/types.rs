@@ -1,18 +1,147 @@
-//! Fundamental types: fixed-point arithmetic, element identifiers, and board coordinates.
+//! Fundamental types: currency, fixed-point arithmetic, element identifiers, and board coordinates.
+
+use core::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
 
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
+/// Fixed-point saturation and share (`Q824`, range [0, 6]) and pressure integral (`Q1648`) for
+/// path-independent history tracking.
+pub use curve::fixed::{Q1648, Q824};
+
+use crate::consts::DECIMALS;
 
 /// Sole in-game currency. Accumulates in wallets (liquid), charges (allocated), and element pots (shared).
-pub type Gluon = u64;
+/// Counted in base units of `10^-DECIMALS` Gluon.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "bytemuck", derive(Pod, Zeroable))]
+pub struct Gluon(pub u64);
+
+impl Gluon {
+    pub const ZERO: Self = Self(0);
+    pub const MAX: Self = Self(u64::MAX);
+    /// Base units in one whole Gluon.
+    pub const UNIT: u64 = 10u64.pow(DECIMALS as u32);
+
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// `self · rhs`, clamped at `MAX`: fee multipliers.
+    #[inline]
+    pub const fn saturating_mul(self, rhs: u64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
+
+    /// Nearest base unit to `v` whole Gluon; negative and NaN inputs give zero.
+    pub fn from_f64(v: f64) -> Self {
+        // `as` saturates and maps NaN to zero
+        Self((v * Self::UNIT as f64 + 0.5) as u64)
+    }
+
+    /// Whole Gluon.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::UNIT as f64
+    }
+}
+
+impl Add for Gluon {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Gluon {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign for Gluon {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Gluon {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Sum for Gluon {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Gluon> for Gluon {
+    fn sum<I: Iterator<Item = &'a Gluon>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl curve::delta::Checked for Gluon {
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Gluon::checked_add(self, rhs)
+    }
 
-/// Fixed-point (8 integer, 24 fractional bits): element saturation and player share in pots.
-/// Range [0, 6]; conversion: `q824 = actual_value * 2^24`.
-pub type Q824 = u32;
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Gluon::checked_sub(self, rhs)
+    }
+}
 
-/// Fixed-point (16 integer, 48 fractional bits): pressure integral for path-independent history tracking.
-/// Conversion: `q1648 = actual_value * 2^48`.
-pub type Q1648 = u64;
+/// Exact decimal amount in whole Gluon (`1.500000`); the formatter's precision truncates it.
+impl fmt::Display for Gluon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (whole, frac) = (self.0 / Self::UNIT, self.0 % Self::UNIT);
+        let digits = DECIMALS as usize;
+        match f.precision() {
+            Some(0) => write!(f, "{whole}"),
+            Some(p) if p < digits => {
+                let frac = frac / 10u64.pow((digits - p) as u32);
+                write!(f, "{whole}.{frac:0p$}")
+            }
+            _ => write!(f, "{whole}.{frac:0digits$}"),
+        }
+    }
+}
 
 /// 32-byte Solana public key: identifies authorities (signers) and mint accounts.
 pub type AddressBytes = [u8; 32];
@@ -41,6 +170,12 @@ impl ElementIndex {
     const GEN_BITS: u32 = u64::BITS - u8::BITS;
     const GEN_MASK: u64 = u64::MAX >> u8::BITS;
 
+    /// Compose an index from atomic number and generation (generation truncated to 56 bits).
+    #[inline]
+    pub const fn new(atomic: u64, generation: u64) -> Self {
+        Self((atomic << Self::GEN_BITS) | (generation & Self::GEN_MASK))
+    }
+
     /// Extract atomic number (element position, 0..255).
     #[inline]
     pub fn atomic(self) -> u64 {
@@ -109,7 +244,7 @@ impl Coordinates {
 
     /// True if shares orthogonal edge with another element (for movement validation).
     #[inline(always)]
-    pub fn adjacent(self, other: Coordinates) -> bool {
+    pub const fn adjacent(self, other: Coordinates) -> bool {
         let neighbors = ((self.0 & Self::NFILE_H) << 1) // east neighbors
             | ((self.0 & Self::NFILE_A) >> 1) // west neighbors
             | (self.0 << 8) // north neighbors
@@ -119,7 +254,13 @@ impl Coordinates {
 
     /// True if touches board perimeter (entry/exit gateways).
     #[inline(always)]
-    pub fn on_edge(self) -> bool {
+    pub const fn on_edge(self) -> bool {
         (self.0 & Self::PERIMETER) != 0
     }
+
+    /// Number of board squares occupied.
+    #[inline(always)]
+    pub const fn tiles(self) -> u64 {
+        self.0.count_ones() as u64
+    }
 }
@@ -1,13 +1,18 @@
 //! Account structures and validation for TOKAMAK64 program instructions.
 
-use core::slice;
+use core::{iter, slice};
 use nucleus::{
     board::{Artefact, Board, Element},
+    error::TokamakError,
+    header::{Discriminated, Header, HeaderError},
     player::{Charge, Wallet},
+    seeds::Canonical,
     types::AddressBytes,
 };
 use pinocchio::{account::AccountView, error::ProgramError};
 
+use crate::addresses::{self, Vault};
+
 /// Trait alias for account iterators used in processor signatures.
 pub trait AccountIter<'a>: Iterator<Item = &'a AccountView> {}
 impl<'a, I: Iterator<Item = &'a AccountView>> AccountIter<'a> for I {}
@@ -48,6 +53,13 @@ pub struct RebindAccounts<'a> {
     pub(crate) dst: &'a mut Element,
 }
 
+/// RebindPath: Move charge along a path of elements. Validates: charge authority.
+/// The path's Element accounts follow `src` and are read hop by hop by the processor.
+pub struct RebindPathAccounts<'a> {
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) src: &'a mut Element,
+}
+
 /// Vent: Donate charge value to element pot. Validates: charge in element, sufficient balance.
 pub struct VentAccounts<'a> {
     pub(crate) charge: &'a mut Charge,
@@ -66,29 +78,65 @@ pub struct OverloadAccounts<'a> {
     pub(crate) board: &'a mut Board,
 }
 
-/// Claim: Collect reward share from overload event. Validates: generation matches.
+/// RebindAndOverload: Rebind into an element and reset it. Validates: as Rebind, then Overload.
+pub struct RebindAndOverloadAccounts<'a> {
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) src: &'a mut Element,
+    pub(crate) dst: &'a mut Element,
+    pub(crate) artefact: &'a mut Artefact,
+    pub(crate) board: &'a mut Board,
+}
+
+/// BindAndOverload: Bind into an edge element and reset it. Validates: as Bind, then Overload.
+pub struct BindAndOverloadAccounts<'a> {
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) dst: &'a mut Element,
+    pub(crate) artefact: &'a mut Artefact,
+    pub(crate) board: &'a mut Board,
+}
+
+/// Claim: Collect reward share from overload event and leave the board. Validates: generation matches.
 pub struct ClaimAccounts<'a> {
     pub(crate) charge: &'a mut Charge,
     pub(crate) artefact: &'a mut Artefact,
+    pub(crate) board: &'a mut Board,
 }
 
 // ============================================================================
 // WALLET & BALANCE MANAGEMENT
 // ============================================================================
 
-/// Charge: Allocate Gluon from wallet to charge. Validates: sufficient balance.
+/// Charge: Allocate Gluon from wallet to charge. Validates: same owner and mint, sufficient balance.
 pub struct ChargeAccounts<'a> {
     pub(crate) charge: &'a mut Charge,
     pub(crate) wallet: &'a mut Wallet,
 }
 
-/// Discharge: Merge charge back to wallet. Validates: charge unbound.
+/// Discharge: Merge charge back to wallet. Validates: same owner and mint, charge unbound.
 pub struct DischargeAccounts<'a> {
     pub(crate) charge: &'a mut Charge,
     pub(crate) wallet: &'a mut Wallet,
 }
 
-/// Infusion: Convert stable tokens to Gluon (1:1). Validates: token transfer.
+/// Split: Move part of a bound charge into a new charge PDA. Validates: same owner and mint.
+pub struct SplitAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) wallet: &'a mut Wallet,
+    pub(crate) part: &'a AccountView,
+    pub(crate) target: &'a mut Element,
+    pub(crate) board: &'a mut Board,
+}
+
+/// Merge: Fold one bound charge into another. Validates: distinct charges, same owner and mint.
+pub struct MergeAccounts<'a> {
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) other: &'a mut Charge,
+    pub(crate) target: &'a mut Element,
+    pub(crate) board: &'a mut Board,
+}
+
+/// Infusion: Convert stable tokens to Gluon (1:1). Validates: wallet mint, vault ATA.
 pub struct InfusionAccounts<'a> {
     pub(crate) authority: &'a AccountView,
     pub(crate) wallet: &'a mut Wallet,
@@ -97,13 +145,15 @@ pub struct InfusionAccounts<'a> {
     pub(crate) vault: &'a AccountView,
 }
 
-/// Extraction: Convert Gluon to stable tokens (1:1). Validates: sufficient balance.
+/// Extraction: Convert Gluon to stable tokens (1:1). Validates: wallet mint, vault ATA and authority.
 pub struct ExtractionAccounts<'a> {
     pub(crate) wallet: &'a mut Wallet,
     pub(crate) vault: &'a AccountView,
     pub(crate) mint: &'a AccountView,
     pub(crate) dst: &'a AccountView,
     pub(crate) authority: &'a AccountView,
+    /// Vault authority PDA bump, for signing the transfer out.
+    pub(crate) bump: u8,
 }
 
 // ============================================================================
@@ -117,13 +167,47 @@ pub struct InitChargeAccounts<'a> {
     pub(crate) charge: &'a AccountView,
 }
 
-/// InitWallet: Create new wallet PDA. Validates: signer authority.
+/// InitWallet: Create new wallet PDA. Validates: signer authority, supported mint.
 pub struct InitWalletAccounts<'a> {
     pub(crate) signer: &'a AccountView,
     pub(crate) wallet: &'a AccountView,
     pub(crate) mint: &'a AccountView,
 }
 
+/// InitBoard: Create the board singleton PDA. Validates: signer (rent payer).
+pub struct InitBoardAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) board: &'a AccountView,
+}
+
+/// InitElement: Create an element PDA. Validates: signer (rent payer).
+pub struct InitElementAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) element: &'a AccountView,
+}
+
+/// InitArtefact: Create the artefact PDA for an element's generation. Validates: signer (rent payer).
+pub struct InitArtefactAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) element: &'a Element,
+    pub(crate) artefact: &'a AccountView,
+}
+
+/// CloseCharge: Close an empty, unbound charge PDA. Validates: wallet authority, same owner and mint.
+pub struct CloseChargeAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) charge: &'a mut Charge,
+    pub(crate) charge_account: &'a AccountView,
+    pub(crate) wallet: &'a mut Wallet,
+}
+
+/// CloseWallet: Close an empty wallet PDA with no open charges. Validates: wallet authority.
+pub struct CloseWalletAccounts<'a> {
+    pub(crate) signer: &'a AccountView,
+    pub(crate) wallet: &'a mut Wallet,
+    pub(crate) wallet_account: &'a AccountView,
+}
+
 // ============================================================================
 // HELPERS & IMPLS
 // ============================================================================
@@ -139,8 +223,8 @@ impl<'a> FromAccounts<'a> for BindAccounts<'a> {
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            dst: parse(it)?,
-            board: parse(it)?,
+            dst: canonical(it)?,
+            board: canonical(it)?,
         })
     }
 }
@@ -152,48 +236,84 @@ impl<'a> FromAccounts<'a> for UnbindAccounts<'a> {
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            src: parse(it)?,
-            board: parse(it)?,
+            src: canonical(it)?,
+            board: canonical(it)?,
         })
     }
 }
 
 impl<'a> FromAccounts<'a> for CompressionAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let charge: &'a mut Charge = parse(it)?;
+        authorize(signer, &charge.authority)?;
+        let (src, dst) = canonical_pair(it)?;
+        Ok(Self { charge, src, dst })
+    }
+}
+
+impl<'a> FromAccounts<'a> for OverloadAccounts<'a> {
     fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
         let signer = next(it)?;
         let charge: &'a mut Charge = parse(it)?;
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            src: parse(it)?,
-            dst: parse(it)?,
+            target: canonical(it)?,
+            artefact: canonical(it)?,
+            board: canonical(it)?,
         })
     }
 }
 
-impl<'a> FromAccounts<'a> for OverloadAccounts<'a> {
+impl<'a> FromAccounts<'a> for RebindAndOverloadAccounts<'a> {
     fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
         let signer = next(it)?;
         let charge: &'a mut Charge = parse(it)?;
         authorize(signer, &charge.authority)?;
+        let (src, dst) = canonical_pair(it)?;
         Ok(Self {
             charge,
-            target: parse(it)?,
-            artefact: parse(it)?,
-            board: parse(it)?,
+            src,
+            dst,
+            artefact: canonical(it)?,
+            board: canonical(it)?,
+        })
+    }
+}
+
+impl<'a> FromAccounts<'a> for BindAndOverloadAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let charge: &'a mut Charge = parse(it)?;
+        authorize(signer, &charge.authority)?;
+        Ok(Self {
+            charge,
+            dst: canonical(it)?,
+            artefact: canonical(it)?,
+            board: canonical(it)?,
         })
     }
 }
 
 impl<'a> FromAccounts<'a> for RebindAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let charge: &'a mut Charge = parse(it)?;
+        authorize(signer, &charge.authority)?;
+        let (src, dst) = canonical_pair(it)?;
+        Ok(Self { charge, src, dst })
+    }
+}
+
+impl<'a> FromAccounts<'a> for RebindPathAccounts<'a> {
     fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
         let signer = next(it)?;
         let charge: &'a mut Charge = parse(it)?;
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            src: parse(it)?,
-            dst: parse(it)?,
+            src: canonical(it)?,
         })
     }
 }
@@ -205,7 +325,7 @@ impl<'a> FromAccounts<'a> for VentAccounts<'a> {
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            target: parse(it)?,
+            target: canonical(it)?,
         })
     }
 }
@@ -217,7 +337,8 @@ impl<'a> FromAccounts<'a> for ClaimAccounts<'a> {
         authorize(signer, &charge.authority)?;
         Ok(Self {
             charge,
-            artefact: parse(it)?,
+            artefact: canonical(it)?,
+            board: canonical(it)?,
         })
     }
 }
@@ -228,6 +349,7 @@ impl<'a> FromAccounts<'a> for ChargeAccounts<'a> {
         let charge: &'a mut Charge = parse(it)?;
         let wallet: &'a mut Wallet = parse(it)?;
         authorize(signer, &wallet.authority)?;
+        pair(charge, wallet)?;
         Ok(Self { charge, wallet })
     }
 }
@@ -238,21 +360,69 @@ impl<'a> FromAccounts<'a> for DischargeAccounts<'a> {
         let charge: &'a mut Charge = parse(it)?;
         let wallet: &'a mut Wallet = parse(it)?;
         authorize(signer, &wallet.authority)?;
+        pair(charge, wallet)?;
         Ok(Self { charge, wallet })
     }
 }
 
+impl<'a> FromAccounts<'a> for SplitAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let charge: &'a mut Charge = parse(it)?;
+        let wallet: &'a mut Wallet = parse(it)?;
+        authorize(signer, &wallet.authority)?;
+        pair(charge, wallet)?;
+        Ok(Self {
+            signer,
+            charge,
+            wallet,
+            part: next(it)?,
+            target: canonical(it)?,
+            board: canonical(it)?,
+        })
+    }
+}
+
+impl<'a> FromAccounts<'a> for MergeAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let (first, second) = (next(it)?, next(it)?);
+        // The same charge twice would alias its data
+        if first.address() == second.address() {
+            return Err(ProgramError::AccountBorrowFailed);
+        }
+        let charge: &'a mut Charge = parse(&mut iter::once(first))?;
+        let other: &'a mut Charge = parse(&mut iter::once(second))?;
+        authorize(signer, &charge.authority)?;
+        authorize(signer, &other.authority)?;
+        if charge.mint != other.mint {
+            return Err(TokamakError::MintMismatch.into());
+        }
+        Ok(Self {
+            charge,
+            other,
+            target: canonical(it)?,
+            board: canonical(it)?,
+        })
+    }
+}
+
 impl<'a> FromAccounts<'a> for InfusionAccounts<'a> {
     fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
         let authority = next(it)?;
         let wallet = parse::<Wallet, _>(it)?;
         authorize(authority, &wallet.authority)?;
+        let src = next(it)?;
+        let mint = next(it)?;
+        let vault = next(it)?;
+        denominated(wallet, mint)?;
+        custody(mint, vault)?;
         Ok(Self {
             authority,
             wallet,
-            src: next(it)?,
-            mint: next(it)?,
-            vault: next(it)?,
+            src,
+            mint,
+            vault,
         })
     }
 }
@@ -262,12 +432,19 @@ impl<'a> FromAccounts<'a> for ExtractionAccounts<'a> {
         let signer = next(it)?;
         let wallet = parse::<Wallet, _>(it)?;
         authorize(signer, &wallet.authority)?;
+        let vault = next(it)?;
+        let mint = next(it)?;
+        let dst = next(it)?;
+        let authority = next(it)?;
+        denominated(wallet, mint)?;
+        let Vault { bump, .. } = custody(mint, vault)?.signed_by(authority)?;
         Ok(Self {
             wallet,
-            vault: next(it)?,
-            mint: next(it)?,
-            dst: next(it)?,
-            authority: next(it)?,
+            vault,
+            mint,
+            dst,
+            authority,
+            bump,
         })
     }
 }
@@ -287,31 +464,218 @@ impl<'a> FromAccounts<'a> for InitChargeAccounts<'a> {
 
 impl<'a> FromAccounts<'a> for InitWalletAccounts<'a> {
     fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
-        let signer = next(it)?;
-        if !signer.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature)?;
+        let signer = payer(it)?;
+        let wallet = next(it)?;
+        let mint = next(it)?;
+        if !addresses::supported(mint.address().as_array()) {
+            return Err(TokamakError::UnsupportedMint.into());
         }
+        Ok(Self {
+            signer,
+            wallet,
+            mint,
+        })
+    }
+}
 
+impl<'a> FromAccounts<'a> for InitBoardAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = payer(it)?;
+        Ok(Self {
+            signer,
+            board: next(it)?,
+        })
+    }
+}
+
+impl<'a> FromAccounts<'a> for InitElementAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = payer(it)?;
+        Ok(Self {
+            signer,
+            element: next(it)?,
+        })
+    }
+}
+
+impl<'a> FromAccounts<'a> for InitArtefactAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = payer(it)?;
         Ok(Self {
             signer,
-            wallet: next(it)?,
-            mint: next(it)?,
+            element: canonical(it)?,
+            artefact: next(it)?,
         })
     }
 }
 
+impl<'a> FromAccounts<'a> for CloseChargeAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let charge_account = next(it)?;
+        let charge: &'a mut Charge = parse(&mut iter::once(charge_account))?;
+        let wallet: &'a mut Wallet = parse(it)?;
+        authorize(signer, &wallet.authority)?;
+        pair(charge, wallet)?;
+        Ok(Self {
+            signer,
+            charge,
+            charge_account,
+            wallet,
+        })
+    }
+}
+
+impl<'a> FromAccounts<'a> for CloseWalletAccounts<'a> {
+    fn extract<I: Iterator<Item = &'a AccountView>>(it: &mut I) -> Result<Self, ProgramError> {
+        let signer = next(it)?;
+        let wallet_account = next(it)?;
+        let wallet: &'a mut Wallet = parse(&mut iter::once(wallet_account))?;
+        authorize(signer, &wallet.authority)?;
+        Ok(Self {
+            signer,
+            wallet,
+            wallet_account,
+        })
+    }
+}
+
+/// Parse the next account as `T`: must be owned by this program and carry `T`'s header.
 pub(crate) fn parse<'a, T, I>(it: &mut I) -> Result<&'a mut T, ProgramError>
 where
-    T: bytemuck::Pod,
+    T: bytemuck::Pod + Discriminated,
+    I: Iterator<Item = &'a AccountView>,
+{
+    let (_, body) = load(next(it)?)?;
+    Ok(body)
+}
+
+/// Like [`parse`], but the account must also sit at the PDA derived from its own contents.
+pub(crate) fn canonical<'a, T, I>(it: &mut I) -> Result<&'a mut T, ProgramError>
+where
+    T: bytemuck::Pod + Discriminated + Canonical,
     I: Iterator<Item = &'a AccountView>,
 {
     let info = next(it)?;
+    let (header, body) = load::<T>(info)?;
+    let address = body.seeds().derive(header.bump, crate::ID.as_array());
+    if info.address().as_array() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(body)
+}
+
+/// Like [`canonical`], for the next two accounts: the same account twice would alias its data.
+fn canonical_pair<'a, T, I>(it: &mut I) -> Result<(&'a mut T, &'a mut T), ProgramError>
+where
+    T: bytemuck::Pod + Discriminated + Canonical,
+    I: Iterator<Item = &'a AccountView>,
+{
+    let (first, second) = (next(it)?, next(it)?);
+    if first.address() == second.address() {
+        return Err(ProgramError::AccountBorrowFailed);
+    }
+    Ok((
+        canonical(&mut iter::once(first))?,
+        canonical(&mut iter::once(second))?,
+    ))
+}
+
+/// Write `T`'s header (with the PDA `bump`) into the next freshly created account
+/// and return its zeroed body.
+pub(crate) fn initialize<'a, T, I>(it: &mut I, bump: u8) -> Result<&'a mut T, ProgramError>
+where
+    T: bytemuck::Pod + Discriminated,
+    I: Iterator<Item = &'a AccountView>,
+{
+    let (header, body) = split::<T>(next(it)?)?;
+    if header.discriminator != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    *header = T::HEADER;
+    header.bump = bump;
+    Ok(body)
+}
+
+/// Account must be owned by this program and carry `T`'s header.
+#[allow(clippy::mut_from_ref)]
+fn load<T>(info: &AccountView) -> Result<(&mut Header, &mut T), ProgramError>
+where
+    T: bytemuck::Pod + Discriminated,
+{
+    if !info.owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let (header, body) = split::<T>(info)?;
+    header.check::<T>().map_err(header_error)?;
+    Ok((header, body))
+}
+
+/// Split account data into header and `T` body, checking only the length.
+// Account data lives in runtime-owned memory, not inside the view borrow.
+#[allow(clippy::mut_from_ref)]
+fn split<T: bytemuck::Pod>(info: &AccountView) -> Result<(&mut Header, &mut T), ProgramError> {
     // Ensure account data is large enough before attempting cast
-    if info.data_len() < size_of::<T>() {
-        return Err(ProgramError::InvalidAccountData);
+    let space = Header::LEN + size_of::<T>();
+    if info.data_len() < space {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    let s = unsafe { slice::from_raw_parts_mut(info.data_ptr(), space) };
+    let (header, body) = s.split_at_mut(Header::LEN);
+    let header =
+        bytemuck::try_from_bytes_mut(header).map_err(|_| ProgramError::InvalidAccountData)?;
+    let body = bytemuck::try_from_bytes_mut(body).map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok((header, body))
+}
+
+/// Map header mismatches onto program errors.
+fn header_error(err: HeaderError) -> ProgramError {
+    match err {
+        HeaderError::TooSmall => ProgramError::AccountDataTooSmall,
+        HeaderError::Uninitialized => ProgramError::UninitializedAccount,
+        HeaderError::UnknownKind(_) | HeaderError::WrongKind { .. } => {
+            TokamakError::WrongAccountKind.into()
+        }
+        HeaderError::UnsupportedVersion { .. } => TokamakError::UnsupportedAccountVersion.into(),
+    }
+}
+
+/// Charge and wallet must belong to the same player and mint.
+fn pair(charge: &Charge, wallet: &Wallet) -> Result<(), ProgramError> {
+    if charge.authority != wallet.authority {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+    if charge.mint != wallet.mint {
+        return Err(TokamakError::MintMismatch.into());
+    }
+    Ok(())
+}
+
+/// Wallet must be denominated in `mint`.
+fn denominated(wallet: &Wallet, mint: &AccountView) -> Result<(), ProgramError> {
+    if wallet.mint != *mint.address().as_array() {
+        return Err(TokamakError::MintMismatch.into());
+    }
+    Ok(())
+}
+
+/// Vault for `mint`, which must be a supported stablecoin with `vault` as its ATA.
+fn custody(mint: &AccountView, vault: &AccountView) -> Result<Vault, ProgramError> {
+    let custody = Vault::derive(mint.address().as_array()).ok_or(TokamakError::UnsupportedMint)?;
+    if vault.address().as_array() != &custody.ata {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(custody)
+}
+
+impl Vault {
+    /// `authority` must be this vault's authority PDA (withdrawals sign as it).
+    fn signed_by(self, authority: &AccountView) -> Result<Self, ProgramError> {
+        if authority.address().as_array() != &self.authority {
+            return Err(ProgramError::IncorrectAuthority);
+        }
+        Ok(self)
     }
-    let s = unsafe { slice::from_raw_parts_mut(info.data_ptr(), size_of::<T>()) };
-    bytemuck::try_from_bytes_mut(s).map_err(|_| ProgramError::InvalidAccountData)
 }
 
 fn next<'a, I>(it: &mut I) -> Result<&'a AccountView, ProgramError>
@@ -321,6 +685,18 @@ where
     it.next().ok_or(ProgramError::NotEnoughAccountKeys)
 }
 
+/// Next account must have signed; used for rent payers that carry no stored authority.
+fn payer<'a, I>(it: &mut I) -> Result<&'a AccountView, ProgramError>
+where
+    I: Iterator<Item = &'a AccountView>,
+{
+    let signer = next(it)?;
+    if !signer.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(signer)
+}
+
 pub(crate) fn authorize(
     signer: &AccountView,
     authority: &AddressBytes,
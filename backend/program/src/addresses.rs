@@ -1,27 +1,15 @@
-use nucleus::types::AddressBytes;
-// TODO: setup real PDA/ATAs
+//! Supported stablecoin mints and the program vaults that custody them.
 
-pub type VaultSeeds = [&'static [u8]; 2];
+use nucleus::types::AddressBytes;
+use pinocchio::Address;
 
-const VAULT_SEED: &[u8] = b"vault";
-pub const USDT_VAULT_SEEDS: VaultSeeds = [VAULT_SEED, &USDT_MINT];
-pub const USDC_VAULT_SEEDS: VaultSeeds = [VAULT_SEED, &USDC_MINT];
+/// Seed prefix for vault authority PDAs, followed by the mint address.
+pub const VAULT_SEED: &[u8] = b"vault";
 
-pub const USDT_VAULT_ATA: AddressBytes = [
-    0xe7, 0x61, 0xab, 0x6c, 0x62, 0x18, 0x33, 0x37, 0xc4, 0xbc, 0x90, 0x42, 0xab, 0x7c, 0x09, 0x98,
-    0x26, 0x26, 0x73, 0x4d, 0xcb, 0x25, 0xa6, 0x78, 0x88, 0xea, 0x5f, 0x86, 0x17, 0xe7, 0xee, 0x83,
-];
-pub const USDC_VAULT_ATA: AddressBytes = [
-    0xe7, 0x61, 0xab, 0x6c, 0x62, 0x18, 0x33, 0x37, 0xc4, 0xbc, 0x90, 0x42, 0xab, 0x7c, 0x09, 0x98,
-    0x26, 0x26, 0x73, 0x4d, 0xcb, 0x25, 0xa6, 0x78, 0x88, 0xea, 0x5f, 0x86, 0x17, 0xe7, 0xee, 0x83,
-];
-pub const USDT_VAULT_AUTHORITY: AddressBytes = [
-    0xb3, 0x1d, 0xee, 0x28, 0xa7, 0xa4, 0x04, 0x94, 0x67, 0xd4, 0x3a, 0x3f, 0xbf, 0xe5, 0x81, 0x09,
-    0x7f, 0x70, 0x75, 0xed, 0x30, 0x56, 0x8a, 0x4d, 0x00, 0x5c, 0xdd, 0x87, 0x85, 0xdd, 0x3b, 0xba,
-];
-pub const USDC_VAULT_AUTHORITY: AddressBytes = [
-    0xb3, 0x1d, 0xee, 0x28, 0xa7, 0xa4, 0x04, 0x94, 0x67, 0xd4, 0x3a, 0x3f, 0xbf, 0xe5, 0x81, 0x09,
-    0x7f, 0x70, 0x75, 0xed, 0x30, 0x56, 0x8a, 0x4d, 0x00, 0x5c, 0xdd, 0x87, 0x85, 0xdd, 0x3b, 0xba,
+/// SPL Associated Token Account program (ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL).
+pub const ASSOCIATED_TOKEN_PROGRAM: AddressBytes = [
+    0x8c, 0x97, 0x25, 0x8f, 0x4e, 0x24, 0x89, 0xf1, 0xbb, 0x3d, 0x10, 0x29, 0x14, 0x8e, 0x0d, 0x83,
+    0x0b, 0x5a, 0x13, 0x99, 0xda, 0xff, 0x10, 0x84, 0x04, 0x8e, 0x7b, 0xd8, 0xdb, 0xe9, 0xf8, 0x59,
 ];
 
 pub const USDT_MINT: [u8; 32] = [
@@ -41,12 +29,34 @@ pub const USDC_MINT: AddressBytes = [
     0x2b, 0x5f, 0xfe, 0xcd, 0x01, 0xa2, 0xdb, 0xf1, 0xb7, 0x79, 0x06, 0x08, 0xdf, 0x00, 0x2e, 0xa7,
 ];
 
-pub fn resolve_vault(vault: &AddressBytes) -> Option<(AddressBytes, VaultSeeds)> {
-    if *vault == USDC_VAULT_ATA {
-        Some((USDC_VAULT_AUTHORITY, USDC_VAULT_SEEDS))
-    } else if *vault == USDT_VAULT_ATA {
-        Some((USDT_VAULT_AUTHORITY, USDT_VAULT_SEEDS))
-    } else {
-        None
+/// Whether `mint` is one of the stablecoins the program custodies.
+pub fn supported(mint: &AddressBytes) -> bool {
+    *mint == USDC_MINT || *mint == USDT_MINT
+}
+
+/// Program vault for one supported mint: the authority PDA and its associated token account.
+pub struct Vault {
+    pub authority: AddressBytes,
+    /// Bump of the authority PDA, needed to sign withdrawals.
+    pub bump: u8,
+    pub ata: AddressBytes,
+}
+
+impl Vault {
+    /// Derive the vault for `mint`; `None` if the mint is not a supported stablecoin.
+    pub fn derive(mint: &AddressBytes) -> Option<Self> {
+        if !supported(mint) {
+            return None;
+        }
+        let (authority, bump) = Address::find_program_address(&[VAULT_SEED, mint], &crate::ID);
+        let token_program = pinocchio_token::ID;
+        let ata_program = Address::new_from_array(ASSOCIATED_TOKEN_PROGRAM);
+        let seeds: [&[u8]; 3] = [authority.as_ref(), token_program.as_ref(), mint];
+        let (ata, _) = Address::find_program_address(&seeds, &ata_program);
+        Some(Self {
+            authority: authority.to_bytes(),
+            bump,
+            ata: ata.to_bytes(),
+        })
     }
 }
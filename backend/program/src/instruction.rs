@@ -5,7 +5,7 @@ use core::mem;
 use bytemuck::Pod;
 use pinocchio::error::ProgramError;
 
-const IX_COUNT: u64 = 13;
+const IX_COUNT: u64 = 23;
 
 pub(crate) struct IxData<'a> {
     inner: &'a [u8],
@@ -40,7 +40,29 @@ pub enum TokamakInstruction {
     /// Convert stable tokens to Gluon and add to wallet (1:1 conversion).
     Infuse,
     /// Donate part of a bound charge's value to its current Element's shared pot.
-    Vent = IX_COUNT - 1,
+    Vent,
+    /// Initialize the global Board singleton (PDA).
+    InitBoard,
+    /// Initialize an Element (PDA) at generation 0 with its genesis curve.
+    InitElement,
+    /// Initialize the Artefact (PDA) for an Element's current generation.
+    InitArtefact,
+    /// Rebind a charge into an adjacent Element and overload it in the same call. Takes the
+    /// guards of Rebind, which bound the move.
+    RebindAndOverload,
+    /// Bind a charge into an edge Element and overload it in the same call. Takes the guards
+    /// of Bind, which bound the move.
+    BindAndOverload,
+    /// Move a bound charge along a path of adjacent Elements, one rebind per hop.
+    RebindPath,
+    /// Move part of a bound charge into a new charge PDA bound to the same Element.
+    Split,
+    /// Fold a bound charge into another charge bound to the same Element.
+    Merge,
+    /// Close an empty, unbound charge account and return its rent to the signer.
+    CloseCharge,
+    /// Close an empty wallet account with no open charges and return its rent to the signer.
+    CloseWallet = IX_COUNT - 1,
 }
 
 impl TokamakInstruction {
@@ -49,7 +71,7 @@ impl TokamakInstruction {
         if let 0..IX_COUNT = discriminator {
             // # SAFETY
             // The bit pattern is valid for the enum due to range inclusion
-            return Ok(unsafe { mem::transmute(discriminator) });
+            return Ok(unsafe { mem::transmute::<u64, Self>(discriminator) });
         }
         Err(ProgramError::InvalidInstructionData)
     }
@@ -61,14 +83,23 @@ impl<'a> IxData<'a> {
         Self { inner, cursor }
     }
 
+    /// Read the next `T`. Fields follow each other unpadded, so `T` may sit at any offset.
     pub(crate) fn read<T: Pod>(&mut self) -> Result<T, ProgramError> {
         let end = self.cursor + size_of::<T>();
-        let val = *self
+        let val = self
             .inner
             .get(self.cursor..end)
-            .and_then(|s| bytemuck::try_from_bytes(s).ok())
+            .and_then(|s| bytemuck::try_pod_read_unaligned(s).ok())
             .ok_or(ProgramError::InvalidInstructionData)?;
         self.cursor = end;
         Ok(val)
     }
+
+    /// Read a `T` if any data remains, `None` at the end of the data.
+    pub(crate) fn read_optional<T: Pod>(&mut self) -> Result<Option<T>, ProgramError> {
+        if self.cursor == self.inner.len() {
+            return Ok(None);
+        }
+        self.read().map(Some)
+    }
 }
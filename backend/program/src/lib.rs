@@ -7,10 +7,12 @@ use pinocchio::{account::AccountView, address::declare_id, program_entrypoint, P
 use crate::instruction::IxData;
 
 mod accounts;
-mod addresses;
+pub mod addresses;
 pub mod instruction;
 mod processors;
 
+pub use nucleus::error::TokamakError;
+
 program_entrypoint!(process_instruction);
 declare_id!("DuJrE9ZB4TqcMByw9g4CiDQdNQosPQCQw2ECWGfLiyi");
 
@@ -30,14 +32,24 @@ fn process_instruction(
         InitWallet => init::wallet(it, data),
         Charge => charge::charge(it, data),
         Claim => claim::claim(it),
-        Compress => compress::compress(it),
+        Compress => compress::compress(it, data),
         Extract => extract::extract(it, data),
         Discharge => discharge::discharge(it, data),
-        Rebind => rebind::rebind(it),
-        Unbind => unbind::unbind(it),
-        Bind => bind::bind(it),
+        Rebind => rebind::rebind(it, data),
+        Unbind => unbind::unbind(it, data),
+        Bind => bind::bind(it, data),
         Overload => overload::overload(it),
         Infuse => infuse::infuse(it, data),
         Vent => vent::vent(it, data),
+        InitBoard => init::board(it),
+        InitElement => init::element(it, data),
+        InitArtefact => init::artefact(it),
+        RebindAndOverload => compound::rebind_and_overload(it, data),
+        BindAndOverload => compound::bind_and_overload(it, data),
+        RebindPath => rebind::rebind_path(it, data),
+        Split => split::split(it, data),
+        Merge => split::merge(it),
+        CloseCharge => close::charge(it),
+        CloseWallet => close::wallet(it),
     }
 }
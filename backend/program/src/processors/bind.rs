@@ -1,35 +1,56 @@
 //! Bind charge onto board into edge Element; charge becomes bound.
 
-use bytemuck::Zeroable;
-use nucleus::{action, board::Element, fees::bind_fee};
-use pinocchio::error::ProgramError;
-use pinocchio::ProgramResult;
-
-use super::common::charge_fee;
-use crate::accounts::{AccountIter, BindAccounts, FromAccounts};
+use nucleus::{
+    action,
+    board::{Board, Element},
+    error::TokamakError,
+    fees::bind_fee,
+    player::Charge,
+    types::Gluon,
+};
+use pinocchio::{error::ProgramError, ProgramResult};
+
+use super::common::{charge_fee, guards};
+use crate::{
+    accounts::{AccountIter, BindAccounts, FromAccounts},
+    instruction::IxData,
+};
 
 /// Bind a charge onto the board into an edge Element;
 /// charge becomes bound for pressure/overload mechanics.
-pub(crate) fn bind<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+pub(crate) fn bind<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
     let BindAccounts { charge, dst, board } = BindAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
+
+    let fee = apply(charge, dst, board)?;
+    guards.check_fee(fee)?;
+    guards.check_share(charge.share)?;
+    Ok(())
+}
 
+/// Validate and apply a bind on already extracted accounts; returns the fee taken.
+pub(super) fn apply(
+    charge: &mut Charge,
+    dst: &mut Element,
+    board: &mut Board,
+) -> Result<Gluon, ProgramError> {
     dst.coordinates
         .on_edge()
         .then_some(())
-        .ok_or(ProgramError::InvalidArgument)?;
+        .ok_or(TokamakError::NotOnEdge)?;
     if !charge.index.is_zero() {
         // charge needs to be out of the board and no outstanding claims
-        return Err(ProgramError::Custom(43));
+        return Err(TokamakError::ChargeAlreadyBound.into());
     }
 
-    board.tvl += charge.balance;
-    board.charge_count += 1;
-
     let fee = charge_fee(charge, bind_fee(charge, dst))?;
 
-    let mut src = Element::zeroed();
-    action::rebind(charge, &mut src, dst);
+    // The board counts what reaches the curve, i.e. the balance net of the fee
+    action::bind(charge, dst, board)?;
     dst.pot += fee;
 
-    Ok(())
+    Ok(fee)
 }
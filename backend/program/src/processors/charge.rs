@@ -1,5 +1,6 @@
 //! Add funds to a charge account from a wallet.
 
+use nucleus::{error::TokamakError, types::Gluon};
 use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 
@@ -8,19 +9,24 @@ use crate::{
     instruction::IxData,
 };
 
-/// Create a new charge by allocating Gluon from wallet to charge account.
+/// Create a new charge by allocating Gluon from wallet to charge account; unbound charges only.
 pub(crate) fn charge<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
 where
     I: AccountIter<'a>,
 {
     let ChargeAccounts { charge, wallet } = ChargeAccounts::extract(it)?;
 
-    let amount = data.read()?;
+    let amount: Gluon = data.read()?;
 
-    if amount == 0 {
+    if amount == Gluon::ZERO {
         return Err(ProgramError::InvalidArgument);
     }
 
+    // Charge must be unbound: a bound balance is exactly its stake on the curve
+    if charge.index.atomic() != 0 {
+        return Err(TokamakError::ChargeStillBound.into());
+    }
+
     // Transfer from wallet to charge balance
     wallet.balance = wallet
         .balance
@@ -1,26 +1,29 @@
 //! Claim accumulated rewards from an artefact pot to a charge.
 
-use nucleus::action;
-use pinocchio::error::ProgramError;
+use nucleus::{action, error::TokamakError};
 use pinocchio::ProgramResult;
 
 use crate::accounts::{AccountIter, ClaimAccounts, FromAccounts};
 
 /// Collect accumulated rewards from an Element's overload event based on accumulated share.
+/// The ejected charge leaves the board: its stake moves out of the artefact and board totals.
 pub(crate) fn claim<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
-    let ClaimAccounts { charge, artefact } = ClaimAccounts::extract(it)?;
+    let ClaimAccounts {
+        charge,
+        artefact,
+        board,
+    } = ClaimAccounts::extract(it)?;
 
-    if charge.share == 0 {
-        // TODO proper handling of nothing to claim
-        return Err(ProgramError::Custom(42));
+    if charge.index != artefact.index {
+        return Err(TokamakError::ArtefactMismatch.into());
     }
 
-    if charge.index != artefact.index {
-        // TODO proper handling of claim vialotion
-        return Err(ProgramError::Custom(42));
+    // Artefact must be overloaded and still have ejected charges outstanding
+    if artefact.charges == 0 {
+        return Err(TokamakError::NothingToClaim.into());
     }
 
-    action::claim(charge, artefact);
+    action::release(charge, artefact, board);
 
     Ok(())
 }
@@ -0,0 +1,63 @@
+//! Close empty charge and wallet accounts, returning their rent to the signer.
+
+use nucleus::{error::TokamakError, types::Gluon};
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::accounts::{AccountIter, CloseChargeAccounts, CloseWalletAccounts, FromAccounts};
+
+/// Close an empty, unbound charge account. Its id is skipped, not reissued: `wallet.charges`
+/// only counts up while the wallet lives.
+pub(crate) fn charge<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+    let CloseChargeAccounts {
+        signer,
+        charge,
+        charge_account,
+        wallet,
+    } = CloseChargeAccounts::extract(it)?;
+
+    // Charge must be unbound: an ejected charge still has a claim to make
+    if !charge.index.is_zero() {
+        return Err(TokamakError::ChargeStillBound.into());
+    }
+    if charge.balance != Gluon::ZERO {
+        return Err(TokamakError::AccountNotEmpty.into());
+    }
+
+    wallet.open = wallet
+        .open
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    close(charge_account, signer)
+}
+
+/// Close an empty wallet with no open charges. A wallet created again at the same PDA counts
+/// charge ids from 0; every charge of the closed one was closed first, so those ids are free.
+pub(crate) fn wallet<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+    let CloseWalletAccounts {
+        signer,
+        wallet,
+        wallet_account,
+    } = CloseWalletAccounts::extract(it)?;
+
+    if wallet.balance != Gluon::ZERO || wallet.open != 0 {
+        return Err(TokamakError::AccountNotEmpty.into());
+    }
+
+    close(wallet_account, signer)
+}
+
+/// Zero the account's data, move its lamports to `recipient` and hand the account back to the
+/// system program. The body parsed from the account must not be used afterwards.
+fn close(account: &AccountView, recipient: &AccountView) -> ProgramResult {
+    // SAFETY: the only view of the data is the body parsed by extract, which callers drop here
+    unsafe { account.borrow_unchecked_mut() }.fill(0);
+
+    let lamports = recipient
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    recipient.set_lamports(lamports);
+    account.set_lamports(0);
+    account.close()
+}
@@ -1,9 +1,11 @@
 //! Common utilities for instruction processors.
 
-use nucleus::{fees::fee_multiplier, player::Charge, types::Gluon};
+use nucleus::{fees::fee_multiplier, guards::Guards, player::Charge, types::Gluon};
 use pinocchio::error::ProgramError;
 use pinocchio::sysvars::{clock::Clock, Sysvar};
 
+use crate::instruction::IxData;
+
 /// Apply speed tax multiplier to a base fee and update charge timestamp.
 ///
 /// 1. Gets current slot from Clock sysvar
@@ -14,11 +16,20 @@ use pinocchio::sysvars::{clock::Clock, Sysvar};
 /// Returns the final fee after speed tax.
 #[inline]
 pub fn apply_speed_tax(charge: &mut Charge, base_fee: Gluon) -> Result<Gluon, ProgramError> {
+    let multiplier = speed_multiplier(charge)?;
+    Ok(base_fee.saturating_mul(multiplier))
+}
+
+/// Speed tax multiplier for an action taken now; updates charge timestamp to the current slot.
+///
+/// Actions that pay several fees (a multi-hop rebind) read the multiplier once and apply it
+/// to each fee, so their own steps do not tax each other.
+#[inline]
+pub fn speed_multiplier(charge: &mut Charge) -> Result<u64, ProgramError> {
     let clock = Clock::get()?;
     let multiplier = fee_multiplier(charge, clock.slot);
-    let fee = base_fee.saturating_mul(multiplier);
     charge.timestamp = clock.slot;
-    Ok(fee)
+    Ok(multiplier)
 }
 
 /// Deduct fee from charge balance, failing if insufficient funds.
@@ -38,3 +49,12 @@ pub fn charge_fee(charge: &mut Charge, base_fee: Gluon) -> Result<Gluon, Program
     deduct_fee(charge, fee)?;
     Ok(fee)
 }
+
+/// Read the optional guards that end a move's instruction data and enforce their deadline.
+/// Without guard data the move runs unguarded ([`Guards::NONE`]).
+#[inline]
+pub fn guards(data: &mut IxData) -> Result<Guards, ProgramError> {
+    let guards = data.read_optional()?.unwrap_or(Guards::NONE);
+    guards.check_deadline(Clock::get()?.slot)?;
+    Ok(guards)
+}
@@ -0,0 +1,65 @@
+//! Move and overload in one call: the bind or rebind that saturates an element, then its reset.
+
+use nucleus::error::TokamakError;
+use pinocchio::ProgramResult;
+
+use super::{bind, common::guards, overload, rebind};
+use crate::{
+    accounts::{AccountIter, BindAndOverloadAccounts, FromAccounts, RebindAndOverloadAccounts},
+    instruction::IxData,
+};
+
+/// Rebind a charge into `dst` and overload `dst` with it. Fails as a whole, with the
+/// overload's error, if the move leaves `dst` below the threshold or another charge reset
+/// it first.
+///
+/// Guards apply to the move: its fee, and the share it buys in `dst`, which is the share the
+/// overload pays the charge's reward on.
+pub(crate) fn rebind_and_overload<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
+    let RebindAndOverloadAccounts {
+        charge,
+        src,
+        dst,
+        artefact,
+        board,
+    } = RebindAndOverloadAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
+
+    // Race lost before the move: dst already reset past the artefact's generation
+    if artefact.index != dst.index {
+        return Err(TokamakError::ArtefactMismatch.into());
+    }
+
+    let fee = rebind::apply(charge, src, dst)?;
+    guards.check_fee(fee)?;
+    guards.check_share(charge.share)?;
+    overload::apply(charge, dst, artefact, board)
+}
+
+/// Bind a charge into edge element `dst` and overload `dst` with it. Fails as a whole, and
+/// takes guards, like [`rebind_and_overload`].
+pub(crate) fn bind_and_overload<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
+    let BindAndOverloadAccounts {
+        charge,
+        dst,
+        artefact,
+        board,
+    } = BindAndOverloadAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
+
+    // Race lost before the move: dst already reset past the artefact's generation
+    if artefact.index != dst.index {
+        return Err(TokamakError::ArtefactMismatch.into());
+    }
+
+    let fee = bind::apply(charge, dst, board)?;
+    guards.check_fee(fee)?;
+    guards.check_share(charge.share)?;
+    overload::apply(charge, dst, artefact, board)
+}
@@ -2,34 +2,48 @@
 
 use nucleus::{
     action,
+    error::TokamakError,
     fees::{compression_fee, rebind_fee},
+    topology,
 };
-use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 
-use super::common::charge_fee;
-use crate::accounts::{AccountIter, CompressionAccounts, FromAccounts};
+use super::common::{charge_fee, guards};
+use crate::{
+    accounts::{AccountIter, CompressionAccounts, FromAccounts},
+    instruction::IxData,
+};
 
 /// Move Element's pot inward to deeper destination and rebind charge; adds fees to destination pot.
 /// Rebind fee (standard movement cost) + compression fee (0-5% of pot, scaled by saturation) both paid by Charge, added to destination.
-pub(crate) fn compress<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+pub(crate) fn compress<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
     let CompressionAccounts { charge, src, dst } = CompressionAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
 
     // Charge must be bound to source element
     if charge.index != src.index {
-        return Err(ProgramError::Custom(1)); // Charge not in source element
+        return Err(TokamakError::ChargeNotInElement.into());
     }
 
+    // Compression only moves towards increasing Z
     if src.index > dst.index {
-        // TODO proper handling of compression error (only towards increasing Z)
-        return Err(ProgramError::Custom(42));
+        return Err(TokamakError::CompressionNotInward.into());
+    }
+    // ...and only into a neighbour
+    if !topology::compressible(src.index.atomic(), dst.index.atomic()) {
+        return Err(TokamakError::NotAdjacent.into());
     }
 
     // Rebind fee + compression fee, with speed tax applied to combined total
     let base_fee = rebind_fee(charge, src, dst) + compression_fee(src);
     let fee = charge_fee(charge, base_fee)?;
+    guards.check_fee(fee)?;
 
-    action::compress(charge, src, dst);
+    action::compress(charge, src, dst)?;
+    guards.check_share(charge.share)?;
     // Both fees accumulate in destination pot (investment in deeper element)
     dst.pot += fee;
 
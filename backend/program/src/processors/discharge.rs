@@ -1,5 +1,6 @@
 //! Release a charge from element binding and reset its state.
 
+use nucleus::{error::TokamakError, types::Gluon};
 use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 
@@ -15,16 +16,15 @@ where
 {
     let DischargeAccounts { charge, wallet } = DischargeAccounts::extract(it)?;
 
-    let amount = data.read()?;
+    let amount: Gluon = data.read()?;
 
-    if amount == 0 {
+    if amount == Gluon::ZERO {
         return Err(ProgramError::InvalidArgument);
     }
 
     // Charge must be unbound (off-board)
     if charge.index.atomic() != 0 {
-        // TODO: proper error handling - can only withdraw when not bound to an element
-        return Err(ProgramError::Custom(50));
+        return Err(TokamakError::ChargeStillBound.into());
     }
 
     // Check sufficient balance
@@ -1,6 +1,8 @@
 //! Convert Gluon from wallet back to stable tokens and withdraw.
 
-use nucleus::consts::DECIMALS;
+use core::slice;
+use nucleus::{consts::DECIMALS, types::Gluon};
+
 use pinocchio::cpi::Seed;
 use pinocchio::ProgramResult;
 use pinocchio::{cpi::Signer, error::ProgramError};
@@ -8,7 +10,7 @@ use pinocchio_token::instructions::TransferChecked;
 
 use crate::{
     accounts::{AccountIter, ExtractionAccounts, FromAccounts},
-    addresses,
+    addresses::VAULT_SEED,
     instruction::IxData,
 };
 
@@ -24,33 +26,32 @@ where
         mint,
         dst,
         authority,
+        bump,
     } = ExtractionAccounts::extract(it)?;
 
-    let amount = data.read()?;
+    let amount: Gluon = data.read()?;
 
-    if amount == 0 {
+    if amount == Gluon::ZERO {
         return Err(ProgramError::InvalidArgument);
     }
 
     // Check sufficient GLUON balance in wallet
-    if (wallet.balance as u64) < amount {
+    if wallet.balance < amount {
         return Err(ProgramError::InsufficientFunds);
     }
 
-    let Some((auth, seeds)) = addresses::resolve_vault(vault.address().as_array()) else {
-        return Err(ProgramError::InvalidArgument);
-    };
-    if auth == *authority.address().as_array() {
-        return Err(ProgramError::IncorrectAuthority);
-    }
     // Convert 1:1 from GLUON to stable token
     wallet.balance = wallet
         .balance
         .checked_sub(amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    let seeds = &[Seed::from(seeds[0]), Seed::from(seeds[1])];
-    let signer = Signer::from(seeds);
+    let seeds = [
+        Seed::from(VAULT_SEED),
+        Seed::from(mint.address().as_ref()),
+        Seed::from(slice::from_ref(&bump)),
+    ];
+    let signer = Signer::from(&seeds);
 
     // Execute token transfer: vault -> dst via CPI
     TransferChecked {
@@ -58,7 +59,7 @@ where
         mint,
         to: dst,
         authority,
-        amount,
+        amount: amount.0,
         decimals: DECIMALS,
     }
     .invoke_signed(&[signer])?;
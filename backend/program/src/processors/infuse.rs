@@ -1,6 +1,6 @@
 //! Add funds to a wallet from a token account.
 
-use nucleus::consts::DECIMALS;
+use nucleus::{consts::DECIMALS, types::Gluon};
 use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 use pinocchio_token::instructions::TransferChecked;
@@ -23,9 +23,9 @@ where
         authority,
         wallet,
     } = InfusionAccounts::extract(it)?;
-    let amount = data.read()?;
+    let amount: Gluon = data.read()?;
 
-    if amount == 0 {
+    if amount == Gluon::ZERO {
         return Err(ProgramError::InvalidArgument);
     }
 
@@ -35,7 +35,7 @@ where
         mint,
         to: vault,
         authority,
-        amount,
+        amount: amount.0,
         decimals: DECIMALS,
     };
     transfer.invoke()?;
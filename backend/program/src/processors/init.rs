@@ -1,15 +1,25 @@
-//! Initialize charge and wallet accounts.
+//! Initialize charge, wallet, board, element and artefact accounts.
 
 use core::{iter, slice};
 
-use nucleus::player::{Charge, Wallet};
+use nucleus::{
+    board::{Artefact, Board, Element},
+    header::Discriminated,
+    player::{Charge, Wallet},
+    seeds::Seeds,
+};
+use pinocchio::account::AccountView;
 use pinocchio::cpi::{Seed, Signer};
+use pinocchio::error::ProgramError;
 use pinocchio::sysvars::rent::Rent;
 use pinocchio::sysvars::Sysvar;
 use pinocchio::ProgramResult;
 use pinocchio_system::instructions::CreateAccount;
 
-use crate::accounts::{parse, AccountIter, FromAccounts, InitChargeAccounts, InitWalletAccounts};
+use crate::accounts::{
+    initialize, AccountIter, FromAccounts, InitArtefactAccounts, InitBoardAccounts,
+    InitChargeAccounts, InitElementAccounts, InitWalletAccounts,
+};
 use crate::instruction::IxData;
 
 /// Initialize a new wallet account for a player.
@@ -25,25 +35,14 @@ where
     } = InitWalletAccounts::extract(it)?;
 
     let bump = data.read()?;
-    let rent = Rent::get()?;
-    let space = size_of::<Wallet>();
-    let lamports = rent.try_minimum_balance(space)?;
     let seeds = [
         Seed::from(signer.address().as_ref()),
         Seed::from(mint.address().as_ref()),
         Seed::from(slice::from_ref(&bump)),
     ];
-    let invoker = Signer::from(&seeds);
-    CreateAccount {
-        from: signer,
-        to: wallet,
-        lamports,
-        space: space as u64,
-        owner: &crate::ID,
-    }
-    .invoke_signed(&[invoker])?;
+    create::<Wallet>(signer, wallet, &seeds)?;
 
-    let wallet: &mut Wallet = parse(&mut iter::once(wallet))?;
+    let wallet: &mut Wallet = initialize(&mut iter::once(wallet), bump)?;
     wallet.authority = signer.address().to_bytes();
     wallet.mint = mint.address().to_bytes();
 
@@ -63,9 +62,19 @@ where
     } = InitChargeAccounts::extract(it)?;
 
     let bump = data.read()?;
-    let rent = Rent::get()?;
-    let space = size_of::<Charge>();
-    let lamports = rent.try_minimum_balance(space)?;
+    create_charge(signer, wallet, charge, bump)?;
+
+    Ok(())
+}
+
+/// Create the wallet's next charge PDA (id = `wallet.charges`) with `signer` as authority and
+/// return its empty body. Shared by InitCharge and Split.
+pub(super) fn create_charge<'a>(
+    signer: &AccountView,
+    wallet: &mut Wallet,
+    account: &'a AccountView,
+    bump: u8,
+) -> Result<&'a mut Charge, ProgramError> {
     let id = wallet.charges.to_le_bytes();
     let seeds = [
         Seed::from(signer.address().as_ref()),
@@ -73,21 +82,109 @@ where
         Seed::from(&id),
         Seed::from(slice::from_ref(&bump)),
     ];
-    let invoker = Signer::from(&seeds);
-    CreateAccount {
-        from: signer,
-        to: charge,
-        lamports,
-        space: space as u64,
-        owner: &crate::ID,
-    }
-    .invoke_signed(&[invoker])?;
+    create::<Charge>(signer, account, &seeds)?;
 
-    let charge: &mut Charge = parse(&mut iter::once(charge))?;
+    let charge: &mut Charge = initialize(&mut iter::once(account), bump)?;
     charge.authority = signer.address().to_bytes();
     charge.mint = wallet.mint;
 
     wallet.charges += 1;
+    wallet.open += 1;
+
+    Ok(charge)
+}
+
+/// Initialize the global board singleton with zero TVL and no charges.
+/// Permissionless: the PDA can only ever be created once.
+pub(crate) fn board<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+    let InitBoardAccounts { signer, board } = InitBoardAccounts::extract(it)?;
+
+    let bump = create_canonical::<Board>(signer, board, Seeds::board())?;
+
+    // Freshly created accounts are zero-filled, which is the genesis board.
+    initialize::<Board, _>(&mut iter::once(board), bump)?;
 
     Ok(())
 }
+
+/// Initialize an element at generation 0 with its coordinates and genesis curve capacity.
+/// Permissionless: one canonical PDA per atomic number, contents fully determined by it.
+pub(crate) fn element<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
+    let InitElementAccounts { signer, element } = InitElementAccounts::extract(it)?;
+
+    let atomic: u8 = data.read()?;
+    let genesis = Element::genesis(atomic as u64).ok_or(ProgramError::InvalidArgument)?;
+
+    let bump = create_canonical::<Element>(signer, element, Seeds::element(atomic as u64))?;
+
+    let element: &mut Element = initialize(&mut iter::once(element), bump)?;
+    *element = genesis;
+
+    Ok(())
+}
+
+/// Initialize the artefact for an element's current generation (empty pot, no shares).
+/// Must exist before the element can be overloaded; one canonical PDA per `ElementIndex`.
+pub(crate) fn artefact<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+    let InitArtefactAccounts {
+        signer,
+        element,
+        artefact,
+    } = InitArtefactAccounts::extract(it)?;
+
+    let index = element.index;
+    if index.is_zero() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let bump = create_canonical::<Artefact>(signer, artefact, Seeds::artefact(index))?;
+
+    let artefact: &mut Artefact = initialize(&mut iter::once(artefact), bump)?;
+    artefact.index = index;
+
+    Ok(())
+}
+
+/// Create a rent-exempt, program-owned account sized for `T` and its header, signed by the PDA seeds.
+fn create<T: Discriminated>(
+    payer: &AccountView,
+    account: &AccountView,
+    seeds: &[Seed],
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let space = T::SPACE;
+    let lamports = rent.try_minimum_balance(space)?;
+    let invoker = Signer::from(seeds);
+    CreateAccount {
+        from: payer,
+        to: account,
+        lamports,
+        space: space as u64,
+        owner: &crate::ID,
+    }
+    .invoke_signed(&[invoker])
+}
+
+/// Create `account` at the canonical PDA for `seeds` and return its bump.
+/// Game accounts must use the canonical bump so no duplicates can be created.
+fn create_canonical<T: Discriminated>(
+    payer: &AccountView,
+    account: &AccountView,
+    seeds: Seeds,
+) -> Result<u8, ProgramError> {
+    let (address, bump) = seeds.find(crate::ID.as_array());
+    if account.address().as_array() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let [prefix, key] = seeds.as_array();
+    let seeds = [
+        Seed::from(prefix),
+        Seed::from(key),
+        Seed::from(slice::from_ref(&bump)),
+    ];
+    create::<T>(payer, account, &seeds)?;
+    Ok(bump)
+}
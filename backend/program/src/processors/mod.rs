@@ -5,6 +5,8 @@ mod common;
 pub(crate) mod bind;
 pub(crate) mod charge;
 pub(crate) mod claim;
+pub(crate) mod close;
+pub(crate) mod compound;
 pub(crate) mod compress;
 pub(crate) mod discharge;
 pub(crate) mod extract;
@@ -12,5 +14,6 @@ pub(crate) mod infuse;
 pub(crate) mod init;
 pub(crate) mod overload;
 pub(crate) mod rebind;
+pub(crate) mod split;
 pub(crate) mod unbind;
 pub(crate) mod vent;
@@ -1,13 +1,12 @@
 //! Rapidly discharge a charge (overload action).
 
-use bytemuck::Zeroable;
 use nucleus::{
-    action::{self, claim},
-    board::{Curve, Element},
-    consts::{MAX_SATURATION, SUM_ATOMIC_NUMBERS},
-    round_divide,
+    action,
+    board::{Artefact, Board, Element},
+    consts::MAX_SATURATION,
+    error::TokamakError,
+    player::Charge,
 };
-use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 
 use crate::accounts::{AccountIter, FromAccounts, OverloadAccounts};
@@ -21,31 +20,34 @@ pub(crate) fn overload<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
         artefact,
         board,
     } = OverloadAccounts::extract(it)?;
+    apply(charge, target, artefact, board)
+}
 
+/// Validate and apply an overload on already extracted accounts.
+pub(super) fn apply(
+    charge: &mut Charge,
+    target: &mut Element,
+    artefact: &mut Artefact,
+    board: &mut Board,
+) -> ProgramResult {
     // 1. Validate that the move causes capacity overflow
     if target.curve.saturation < MAX_SATURATION {
-        return Err(ProgramError::Custom(1)); // Not an overload condition
+        return Err(TokamakError::BelowOverloadThreshold.into());
+    }
+
+    // Triggering charge must be bound to the current generation of the target
+    if charge.index != target.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
+
+    // Artefact must be the one initialized for the target's current generation
+    if artefact.index != target.index {
+        return Err(TokamakError::ArtefactMismatch.into());
     }
 
-    // 2. Create artefact: snapshot the state of the overloaded element
-    artefact.pot = target.pot;
-    artefact.index = target.index;
-    artefact.shares = target.curve.shares;
-    claim(charge, artefact);
-
-    // 3. Advance target element to next generation
-    // Reset curve to genesis state and clear pot (moved to artefact)
-    target.curve = Curve::zeroed();
-    target.pot = 0;
-
-    // Increment generation in the element index
-    target.index.advance_generation();
-    let mut src = Element::zeroed();
-    action::rebind(charge, &mut src, target);
-
-    board.tvl -= target.pot + (target.curve.tvl - charge.balance);
-    let mul = target.index.atomic() * target.index.tiles();
-    target.curve.capacity = round_divide(board.tvl, mul, SUM_ATOMIC_NUMBERS);
+    // 2. Snapshot the generation into the artefact, eject the other charges and
+    // re-bind the trigger to the next generation
+    action::overload(charge, target, artefact, board)?;
 
     Ok(())
 }
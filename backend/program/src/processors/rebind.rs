@@ -1,29 +1,98 @@
-//! Rebind a charge to a different element.
+//! Rebind a charge to a different element, one hop or along a path.
 
-use nucleus::{action, fees::rebind_fee};
-use pinocchio::error::ProgramError;
-use pinocchio::ProgramResult;
+use nucleus::{
+    action, board::Element, error::TokamakError, fees::rebind_fee, player::Charge,
+    topology::DIAMETER, types::Gluon,
+};
+use pinocchio::{error::ProgramError, ProgramResult};
 
-use super::common::charge_fee;
-use crate::accounts::{AccountIter, FromAccounts, RebindAccounts};
+use super::common::{deduct_fee, guards, speed_multiplier};
+use crate::{
+    accounts::{canonical, AccountIter, FromAccounts, RebindAccounts, RebindPathAccounts},
+    instruction::IxData,
+};
 
 /// Move a bound charge from source Element to an adjacent Element; incurs movement cost.
-pub(crate) fn rebind<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+pub(crate) fn rebind<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
     let RebindAccounts { charge, src, dst } = RebindAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
 
+    let fee = apply(charge, src, dst)?;
+    guards.check_fee(fee)?;
+    guards.check_share(charge.share)?;
+    Ok(())
+}
+
+/// Move a bound charge along a path of adjacent Elements in one call.
+///
+/// Each hop is validated, priced and routed like a single rebind, and moves the charge through
+/// the intermediate element's curve. The speed tax is read once, against the charge's last
+/// action before the path, and multiplies every hop's fee: a path costs what its hops would
+/// after the same wait, without taxing each other. Guards bound the whole path: the fee of all
+/// hops together, and the share in the last element.
+pub(crate) fn rebind_path<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
+    let RebindPathAccounts { charge, src } = RebindPathAccounts::extract(it)?;
+
+    let hops: u8 = data.read()?;
+    if hops == 0 || hops > DIAMETER {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let guards = guards(&mut data)?;
+
+    let multiplier = speed_multiplier(charge)?;
+    let mut fee = Gluon::ZERO;
+    let mut at = src;
+    for _ in 0..hops {
+        let dst: &mut Element = canonical(it)?;
+        // A repeated account is not a move, and would alias `at`
+        if dst.index == at.index {
+            return Err(TokamakError::NotAdjacent.into());
+        }
+        fee += hop(charge, at, dst, multiplier)?;
+        at = dst;
+    }
+    guards.check_fee(fee)?;
+    guards.check_share(charge.share)?;
+    Ok(())
+}
+
+/// Validate and apply a rebind on already extracted accounts; returns the fee taken.
+pub(super) fn apply(
+    charge: &mut Charge,
+    src: &mut Element,
+    dst: &mut Element,
+) -> Result<Gluon, ProgramError> {
+    let multiplier = speed_multiplier(charge)?;
+    hop(charge, src, dst, multiplier)
+}
+
+/// One adjacency step, paying the rebind fee times the speed tax `multiplier`; returns the fee.
+fn hop(
+    charge: &mut Charge,
+    src: &mut Element,
+    dst: &mut Element,
+    multiplier: u64,
+) -> Result<Gluon, ProgramError> {
     src.coordinates
         .adjacent(dst.coordinates)
         .then_some(())
-        .ok_or(ProgramError::InvalidArgument)?;
+        .ok_or(TokamakError::NotAdjacent)?;
 
     // Charge must be bound to source element
     if charge.index != src.index {
-        return Err(ProgramError::Custom(1)); // Charge not in source element
+        return Err(TokamakError::ChargeNotInElement.into());
     }
 
-    let fee = charge_fee(charge, rebind_fee(charge, src, dst))?;
+    let fee = rebind_fee(charge, src, dst).saturating_mul(multiplier);
+    deduct_fee(charge, fee)?;
 
-    action::rebind(charge, src, dst);
+    action::rebind(charge, src, dst)?;
     // Fee routing: moving outward (src.index > dst.index) pays to src;
     // moving inward (src.index < dst.index) pays to dst.
     if src.index > dst.index {
@@ -32,5 +101,5 @@ pub(crate) fn rebind<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
         dst.pot += fee; // Moving inward: fee funds deeper element
     }
 
-    Ok(())
+    Ok(fee)
 }
@@ -0,0 +1,65 @@
+//! Split a bound charge in two, or merge two charges on the same element back into one.
+
+use nucleus::{action, error::TokamakError, types::Gluon};
+use pinocchio::error::ProgramError;
+use pinocchio::ProgramResult;
+
+use super::init::create_charge;
+use crate::{
+    accounts::{AccountIter, FromAccounts, MergeAccounts, SplitAccounts},
+    instruction::IxData,
+};
+
+/// Move `amount` of a bound charge into the wallet's next charge PDA, bound to the same
+/// Element with a proportional share. No fee: the curve does not move.
+pub(crate) fn split<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
+    let SplitAccounts {
+        signer,
+        charge,
+        wallet,
+        part,
+        target,
+        board,
+    } = SplitAccounts::extract(it)?;
+
+    let amount: Gluon = data.read()?;
+    let bump = data.read()?;
+
+    // Both parts must keep a balance
+    if amount == Gluon::ZERO || amount >= charge.balance {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Charge must be bound to target element
+    if charge.index != target.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
+
+    let part = create_charge(signer, wallet, part, bump)?;
+    action::split(charge, part, amount, target, board);
+
+    Ok(())
+}
+
+/// Fold a bound charge into another bound to the same Element; the emptied charge is left
+/// unbound. No fee: the curve does not move.
+pub(crate) fn merge<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+    let MergeAccounts {
+        charge,
+        other,
+        target,
+        board,
+    } = MergeAccounts::extract(it)?;
+
+    // Both charges must be bound to target element
+    if charge.index != target.index || other.index != target.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
+
+    action::merge(charge, other, target, board);
+
+    Ok(())
+}
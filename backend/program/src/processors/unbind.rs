@@ -1,29 +1,36 @@
 //! Unbind charge from board and move it outside; edge Elements only.
 
-use bytemuck::Zeroable;
-use nucleus::{action, board::Element, fees::unbind_fee};
-use pinocchio::error::ProgramError;
+use nucleus::{action, error::TokamakError, fees::unbind_fee};
 use pinocchio::ProgramResult;
 
-use super::common::charge_fee;
-use crate::accounts::{AccountIter, FromAccounts, UnbindAccounts};
+use super::common::{charge_fee, guards};
+use crate::{
+    accounts::{AccountIter, FromAccounts, UnbindAccounts},
+    instruction::IxData,
+};
 
 /// Unbind a charge from its current Element and move it outside the board; only from edge Elements.
-pub(crate) fn unbind<'a, I: AccountIter<'a>>(it: &mut I) -> ProgramResult {
+pub(crate) fn unbind<'a, I>(it: &mut I, mut data: IxData) -> ProgramResult
+where
+    I: AccountIter<'a>,
+{
     let UnbindAccounts { charge, src, board } = UnbindAccounts::extract(it)?;
+    let guards = guards(&mut data)?;
 
     src.coordinates
         .on_edge()
         .then_some(())
-        .ok_or(ProgramError::InvalidArgument)?;
+        .ok_or(TokamakError::NotOnEdge)?;
 
-    let fee = charge_fee(charge, unbind_fee(charge, src))?;
+    // Charge must be bound to source element
+    if charge.index != src.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
 
-    board.tvl -= charge.balance;
-    board.charge_count -= 1;
+    let fee = charge_fee(charge, unbind_fee(charge, src))?;
+    guards.check_fee(fee)?;
 
-    let mut dst = Element::zeroed();
-    action::rebind(charge, src, &mut dst);
+    action::unbind(charge, src, board)?;
     src.pot += fee;
 
     Ok(())
@@ -1,3 +1,4 @@
+use nucleus::{error::TokamakError, types::Gluon};
 use pinocchio::error::ProgramError;
 use pinocchio::ProgramResult;
 
@@ -14,13 +15,12 @@ where
     let VentAccounts { charge, target } = VentAccounts::extract(it)?;
 
     if charge.index != target.index {
-        // TODO proper error on leaking to a wrong target
-        return Err(ProgramError::Custom(32));
+        return Err(TokamakError::ChargeNotInElement.into());
     }
 
-    let amount = data.read()?;
+    let amount: Gluon = data.read()?;
 
-    if amount == 0 {
+    if amount == Gluon::ZERO {
         return Err(ProgramError::InvalidArgument);
     }
 
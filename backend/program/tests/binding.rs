@@ -1,10 +1,12 @@
-//! Tests for Bind, Unbind, and Rebind instructions.
+//! Tests for Bind, Unbind, Rebind and RebindPath instructions.
 
 mod common;
 use common::*;
 
 use nucleus::board::{Board, Element};
 use nucleus::player::Charge;
+use nucleus::topology::DIAMETER;
+use nucleus::types::Gluon;
 
 // ============================================================================
 // BIND INSTRUCTION TESTS
@@ -30,7 +32,7 @@ fn bind_success_edge_element() {
     let c: Charge = result.get(1);
     let b: Board = result.get(3);
     assert!(!c.index.is_zero());
-    assert!(b.tvl > 0);
+    assert!(b.tvl > Gluon::ZERO);
     assert_eq!(b.charge_count, 1);
 }
 
@@ -48,11 +50,11 @@ fn bind_fails_not_on_edge() {
             metas!(signer, charge, elem, board)
         ),
         &[signer.into(), charge.into(), elem.into(), board.into()],
-        &[Check::err(ProgramError::InvalidArgument)]
+        &[Check::err(TokamakError::NotOnEdge.into())]
     );
 }
 
-/// Bind fails when charge is already bound (Custom(43))
+/// Bind fails when charge is already bound (ChargeAlreadyBound)
 #[test]
 fn bind_fails_already_bound() {
     let signer = signer();
@@ -67,7 +69,7 @@ fn bind_fails_already_bound() {
             metas!(signer, charge, elem, board)
         ),
         &[signer.into(), charge.into(), elem.into(), board.into()],
-        &[Check::err(ProgramError::Custom(43))]
+        &[Check::err(TokamakError::ChargeAlreadyBound.into())]
     );
 }
 
@@ -91,8 +93,8 @@ fn bind_deducts_fee() {
 
     let c: Charge = result.get(1);
     let e: Element = result.get(2);
-    assert!(c.balance < initial);
-    assert!(e.pot > 0);
+    assert!(c.balance < Gluon(initial));
+    assert!(e.pot > Gluon::ZERO);
 }
 
 // ============================================================================
@@ -138,7 +140,25 @@ fn unbind_fails_not_on_edge() {
             metas!(signer, charge, elem, board)
         ),
         &[signer.into(), charge.into(), elem.into(), board.into()],
-        &[Check::err(ProgramError::InvalidArgument)]
+        &[Check::err(TokamakError::NotOnEdge.into())]
+    );
+}
+
+/// Unbind fails when the charge is bound to another element (ChargeNotInElement)
+#[test]
+fn unbind_fails_charge_not_in_element() {
+    let signer = signer();
+    let charge = charge_high_with_index(&signer.pubkey, elem_index(2));
+    let elem = element_edge_shared(1, SHARE_ONE);
+    let board = board_with_count(1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Unbind,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
     );
 }
 
@@ -163,8 +183,8 @@ fn unbind_deducts_fee() {
 
     let c: Charge = result.get(1);
     let e: Element = result.get(2);
-    assert!(c.balance < initial);
-    assert!(e.pot > 0);
+    assert!(c.balance < Gluon(initial));
+    assert!(e.pot > Gluon::ZERO);
 }
 
 // ============================================================================
@@ -205,11 +225,25 @@ fn rebind_fails_not_adjacent() {
     test_run!(
         ix!(TokamakInstruction::Rebind, metas!(signer, charge, src, dst)),
         &[signer.into(), charge.into(), src.into(), dst.into()],
-        &[Check::err(ProgramError::InvalidArgument)]
+        &[Check::err(TokamakError::NotAdjacent.into())]
     );
 }
 
-/// Rebind fails when charge is not in source element (Custom(1))
+/// Rebind fails when the same element is passed as source and destination
+#[test]
+fn rebind_fails_same_element() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let src = element_with_shares(1, EDGE_COORD, SHARE_ONE, BAL_MIN, SHARE_ONE);
+
+    test_run!(
+        ix!(TokamakInstruction::Rebind, metas!(signer, charge, src, src)),
+        &[signer.into(), charge.into(), src.into()],
+        &[Check::err(ProgramError::AccountBorrowFailed)]
+    );
+}
+
+/// Rebind fails when charge is not in source element (ChargeNotInElement)
 #[test]
 fn rebind_fails_charge_not_in_source() {
     let signer = signer();
@@ -222,7 +256,7 @@ fn rebind_fails_charge_not_in_source() {
     test_run!(
         ix!(TokamakInstruction::Rebind, metas!(signer, charge, src, dst)),
         &[signer.into(), charge.into(), src.into(), dst.into()],
-        &[Check::err(ProgramError::Custom(1))]
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
     );
 }
 
@@ -242,10 +276,13 @@ fn rebind_fee_routing_outward() {
         &[Check::success()]
     );
 
+    let c: Charge = result.get(1);
     let src_elem: Element = result.get(2);
     let dst_elem: Element = result.get(3);
-    assert!(src_elem.pot > 0);
-    assert_eq!(dst_elem.pot, 0);
+    assert!(src_elem.pot > Gluon::ZERO);
+    assert_eq!(dst_elem.pot, Gluon::ZERO);
+    // The fee is priced on |ΔZ| and lands whole in the source pot
+    assert_eq!(Gluon(BAL_HIGH) - c.balance, src_elem.pot);
 }
 
 /// Fee routes inward to destination when destination has higher saturation
@@ -266,6 +303,132 @@ fn rebind_fee_routing_inward() {
 
     let src_elem: Element = result.get(2);
     let dst_elem: Element = result.get(3);
-    assert_eq!(src_elem.pot, 0);
-    assert!(dst_elem.pot > 0);
+    assert_eq!(src_elem.pot, Gluon::ZERO);
+    assert!(dst_elem.pot > Gluon::ZERO);
+}
+
+// ============================================================================
+// REBIND PATH INSTRUCTION TESTS
+// ============================================================================
+
+/// Rebind along two hops: the charge lands on the last element and leaves the middle one
+#[test]
+fn rebind_path_success_two_hops() {
+    let signer = signer();
+    let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let mid = element_at(2, 0x02);
+    let dst = element_at(3, 0x04);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::RebindPath,
+            2u8,
+            metas!(signer, charge, src, mid, dst)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            mid.into(),
+            dst.into()
+        ],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let mid_elem: Element = result.get(3);
+    let dst_elem: Element = result.get(4);
+    assert_eq!(c.index, dst_elem.index);
+    assert_eq!((mid_elem.charges, dst_elem.charges), (0, 1));
+    assert_eq!(dst_elem.curve.shares.0, c.share.0);
+    // Both hops move inward: each fee funds the element it enters
+    assert!(mid_elem.pot > Gluon::ZERO);
+    assert!(dst_elem.pot > Gluon::ZERO);
+    assert_eq!(c.balance, Gluon(BAL_HIGH) - mid_elem.pot - dst_elem.pot);
+}
+
+/// RebindPath fails as a whole when a later hop is not adjacent
+#[test]
+fn rebind_path_fails_not_adjacent_hop() {
+    let signer = signer();
+    let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let mid = element_at(2, 0x02);
+    let dst = element_at(3, INTERIOR_COORD);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::RebindPath,
+            2u8,
+            metas!(signer, charge, src, mid, dst)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            mid.into(),
+            dst.into()
+        ],
+        &[Check::err(TokamakError::NotAdjacent.into())]
+    );
+}
+
+/// RebindPath rejects the same element twice in a row
+#[test]
+fn rebind_path_fails_repeated_element() {
+    let signer = signer();
+    let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let mid = element_at(2, 0x02);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::RebindPath,
+            2u8,
+            metas!(signer, charge, src, mid, mid)
+        ),
+        &[signer.into(), charge.into(), src.into(), mid.into()],
+        &[Check::err(TokamakError::NotAdjacent.into())]
+    );
+}
+
+/// RebindPath rejects an empty path and one longer than the board diameter
+#[test]
+fn rebind_path_fails_hop_count() {
+    for hops in [0, DIAMETER + 1] {
+        let signer = signer();
+        let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+        let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+        let dst = element_at(2, 0x02);
+
+        test_run!(
+            ix!(
+                TokamakInstruction::RebindPath,
+                hops,
+                metas!(signer, charge, src, dst)
+            ),
+            &[signer.into(), charge.into(), src.into(), dst.into()],
+            &[Check::err(ProgramError::InvalidArgument)]
+        );
+    }
+}
+
+/// RebindPath fails when the charge is not in the first element
+#[test]
+fn rebind_path_fails_charge_not_in_source() {
+    let signer = signer();
+    let charge = charge_high_with_index(&signer.pubkey, elem_index(5));
+    let src = element_edge(1);
+    let dst = element_at(2, 0x02);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::RebindPath,
+            1u8,
+            metas!(signer, charge, src, dst)
+        ),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
+    );
 }
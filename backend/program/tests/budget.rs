@@ -0,0 +1,607 @@
+//! Compute-unit and program-size budgets.
+//!
+//! Each test runs one instruction down its success path and fails if it consumes more compute
+//! units than its budget, so a regression in the curve hot path (LUT search, u128 scaling) or in
+//! account validation shows up here before it shows up on chain. Budgets are ceilings with
+//! headroom, not exact counts: tighten them when the program gets cheaper.
+
+mod common;
+use common::*;
+
+use mollusk_svm::result::InstructionResult;
+use nucleus::{board::Element, player::Wallet, topology::DIAMETER, types::ElementIndex};
+use tokamak_program::addresses::USDC_MINT;
+
+/// The deployed program binary, and the same program built with `lut-binary-search`.
+const PROGRAM_ELF: &str = "tokamak_program.so";
+const BINARY_SEARCH_ELF: &str = "tokamak_program_binary_search.so";
+
+/// Upper bound on the deployed program binary.
+const PROGRAM_SIZE_BUDGET: usize = 256 * 1024;
+
+// Per-instruction compute-unit budgets
+const CHARGE_BUDGET: u64 = 3_000;
+const DISCHARGE_BUDGET: u64 = 3_000;
+const VENT_BUDGET: u64 = 3_000;
+const BIND_BUDGET: u64 = 10_000;
+const UNBIND_BUDGET: u64 = 10_000;
+const REBIND_BUDGET: u64 = 15_000;
+const REBIND_PATH_BUDGET: u64 = 90_000;
+const COMPRESS_BUDGET: u64 = 15_000;
+const CLAIM_BUDGET: u64 = 8_000;
+const OVERLOAD_BUDGET: u64 = 15_000;
+const BIND_AND_OVERLOAD_BUDGET: u64 = 25_000;
+const REBIND_AND_OVERLOAD_BUDGET: u64 = 30_000;
+const SPLIT_BUDGET: u64 = 10_000;
+const MERGE_BUDGET: u64 = 5_000;
+const CLOSE_CHARGE_BUDGET: u64 = 3_000;
+const CLOSE_WALLET_BUDGET: u64 = 3_000;
+const INFUSE_BUDGET: u64 = 25_000;
+const EXTRACT_BUDGET: u64 = 25_000;
+const INIT_BOARD_BUDGET: u64 = 15_000;
+const INIT_ELEMENT_BUDGET: u64 = 15_000;
+const INIT_ARTEFACT_BUDGET: u64 = 15_000;
+
+/// Fail if `result` consumed more than `budget` compute units
+fn assert_budget(result: &InstructionResult, name: &str, budget: u64) {
+    let used = result.compute_units_consumed;
+    assert!(
+        used <= budget,
+        "{name} consumed {used} CU, budget is {budget}"
+    );
+}
+
+// ============================================================================
+// PROGRAM SIZE
+// ============================================================================
+
+/// The program binary stays within its size budget
+#[test]
+fn program_size_within_budget() {
+    let size = std::fs::metadata(deploy_path(PROGRAM_ELF))
+        .expect("Run `cargo build-sbf` first.")
+        .len() as usize;
+    assert!(
+        size <= PROGRAM_SIZE_BUDGET,
+        "program is {size} bytes, budget is {PROGRAM_SIZE_BUDGET}"
+    );
+}
+
+// ============================================================================
+// TRANSFER INSTRUCTIONS
+// ============================================================================
+
+#[test]
+fn charge_within_budget() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, 0, ZERO_INDEX);
+    let wallet = wallet_min(&signer.pubkey);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Charge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Charge", CHARGE_BUDGET);
+}
+
+#[test]
+fn discharge_within_budget() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, ZERO_INDEX);
+    let wallet = wallet(&signer.pubkey, 0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Discharge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Discharge", DISCHARGE_BUDGET);
+}
+
+#[test]
+fn vent_within_budget() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(3));
+    let elem = element_edge(3);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_QUARTER,
+            metas!(signer, charge, elem)
+        ),
+        &[signer.into(), charge.into(), elem.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Vent", VENT_BUDGET);
+}
+
+// ============================================================================
+// CURVE INSTRUCTIONS
+// ============================================================================
+
+/// An instruction with the accounts it runs against
+type Case = (Instruction, Vec<(Pubkey, Account)>);
+
+/// Builds a fresh [`Case`]
+type CaseFn = fn() -> Case;
+
+fn bind_case() -> Case {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let board = board_empty();
+
+    (
+        ix!(
+            TokamakInstruction::Bind,
+            metas!(signer, charge, elem, board)
+        ),
+        vec![signer.into(), charge.into(), elem.into(), board.into()],
+    )
+}
+
+fn unbind_case() -> Case {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let elem = element_with_shares_at(1, EDGE_COORD, SHARE_ONE, BAL_MIN, SHARE_ONE);
+    let board = board_with_count(1);
+
+    (
+        ix!(
+            TokamakInstruction::Unbind,
+            metas!(signer, charge, elem, board)
+        ),
+        vec![signer.into(), charge.into(), elem.into(), board.into()],
+    )
+}
+
+fn rebind_case() -> Case {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let src = element_with_shares_at(1, src_coord, SHARE_ONE, BAL_MIN, SHARE_ONE);
+    let dst = element_at(2, dst_coord);
+
+    (
+        ix!(TokamakInstruction::Rebind, metas!(signer, charge, src, dst)),
+        vec![signer.into(), charge.into(), src.into(), dst.into()],
+    )
+}
+
+/// A path as long as the board diameter, along one row of single-tile elements
+fn rebind_path_case() -> Case {
+    let signer = signer();
+    let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let hops: Vec<_> = (1..=DIAMETER as u64)
+        .map(|hop| element_at(hop + 1, 1 << hop))
+        .collect();
+
+    let mut metas = metas!(signer, charge, src);
+    metas.extend(hops.iter().map(|e| AccountMeta::new(e.pubkey, false)));
+    let mut accounts = vec![signer.into(), charge.into(), src.into()];
+    accounts.extend(hops.into_iter().map(Into::into));
+
+    (
+        ix!(TokamakInstruction::RebindPath, DIAMETER, metas),
+        accounts,
+    )
+}
+
+fn compress_case() -> Case {
+    let signer = signer();
+    let (src_coord, _) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares_at(2, src_coord, SHARE_ONE, AMT_HALF, SHARE_ONE);
+    let dst = element_edge(3);
+
+    (
+        ix!(
+            TokamakInstruction::Compress,
+            metas!(signer, charge, src, dst)
+        ),
+        vec![signer.into(), charge.into(), src.into(), dst.into()],
+    )
+}
+
+/// Every instruction that prices through the curve, by name
+const CURVE_CASES: [(&str, CaseFn); 5] = [
+    ("Bind", bind_case),
+    ("Unbind", unbind_case),
+    ("Rebind", rebind_case),
+    ("RebindPath", rebind_path_case),
+    ("Compress", compress_case),
+];
+
+#[test]
+fn bind_within_budget() {
+    let (ix, accounts) = bind_case();
+    let result = test_run!(ix, &accounts, &[Check::success()]);
+    assert_budget(&result, "Bind", BIND_BUDGET);
+}
+
+#[test]
+fn unbind_within_budget() {
+    let (ix, accounts) = unbind_case();
+    let result = test_run!(ix, &accounts, &[Check::success()]);
+    assert_budget(&result, "Unbind", UNBIND_BUDGET);
+}
+
+#[test]
+fn rebind_within_budget() {
+    let (ix, accounts) = rebind_case();
+    let result = test_run!(ix, &accounts, &[Check::success()]);
+    assert_budget(&result, "Rebind", REBIND_BUDGET);
+}
+
+#[test]
+fn rebind_path_within_budget() {
+    let (ix, accounts) = rebind_path_case();
+    let result = test_run!(ix, &accounts, &[Check::success()]);
+    assert_budget(&result, "RebindPath", REBIND_PATH_BUDGET);
+}
+
+#[test]
+fn compress_within_budget() {
+    let (ix, accounts) = compress_case();
+    let result = test_run!(ix, &accounts, &[Check::success()]);
+    assert_budget(&result, "Compress", COMPRESS_BUDGET);
+}
+
+#[test]
+fn claim_within_budget() {
+    let signer = signer();
+    let art_index = elem_index(3);
+    let charge = charge_with_share(&signer.pubkey, 0, art_index, SHARE_ONE);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, 0, 2);
+    let board = board(0, 2);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Claim", CLAIM_BUDGET);
+}
+
+#[test]
+fn overload_within_budget() {
+    let signer = signer();
+    let elem_index = elem_index(3);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    let elem = element_overloaded(3, AMT_HALF, 3 * BAL_MIN, 3);
+    let art = artefact(elem_index);
+    let board = board(BAL_HIGH, 5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Overload", OVERLOAD_BUDGET);
+}
+
+#[test]
+fn bind_and_overload_within_budget() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_with_capacity(1, EDGE_COORD, BAL_MIN);
+    let art = artefact(elem_index(1));
+    let board = board_empty();
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::BindAndOverload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "BindAndOverload", BIND_AND_OVERLOAD_BUDGET);
+}
+
+#[test]
+fn rebind_and_overload_within_budget() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let dst = element_with_capacity(2, dst_coord, BAL_MIN);
+    let art = artefact(elem_index(2));
+    let board = board(BAL_HIGH, 1);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::RebindAndOverload,
+            metas!(signer, charge, src, dst, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            dst.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "RebindAndOverload", REBIND_AND_OVERLOAD_BUDGET);
+}
+
+#[test]
+fn split_within_budget() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let wallet = wallet_min(&signer.pubkey);
+    let (pda, bump) = derive_charge_pda(&signer.pubkey, &[0u8; 32], 0);
+    let part = pda_account(pda);
+    let elem = element_overloaded(1, 0, BAL_HIGH, 1);
+    let board = board_with_count(1);
+
+    let result = test_run!(
+        split_ix(
+            BAL_HIGH / 2,
+            bump,
+            with_system_program(metas!(signer, charge, wallet, part, elem, board))
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            wallet.into(),
+            part.into(),
+            elem.into(),
+            board.into(),
+            system_program().into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Split", SPLIT_BUDGET);
+}
+
+#[test]
+fn merge_within_budget() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let other = charge_shared(&signer.pubkey, elem_index(1));
+    let elem = element_overloaded(1, 0, 2 * BAL_HIGH, 2);
+    let board = board_with_count(2);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Merge,
+            metas!(signer, charge, other, elem, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            other.into(),
+            elem.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Merge", MERGE_BUDGET);
+}
+
+#[test]
+fn close_charge_within_budget() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, 0, ZERO_INDEX);
+    let mut wallet = wallet(&signer.pubkey, 0);
+    wallet.account.data = stored(&Wallet {
+        open: 1,
+        ..read(&wallet.account)
+    });
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::CloseCharge,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "CloseCharge", CLOSE_CHARGE_BUDGET);
+}
+
+#[test]
+fn close_wallet_within_budget() {
+    let signer = signer();
+    let wallet = wallet(&signer.pubkey, 0);
+
+    let result = test_run!(
+        ix!(TokamakInstruction::CloseWallet, metas!(signer, wallet)),
+        &[signer.into(), wallet.into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "CloseWallet", CLOSE_WALLET_BUDGET);
+}
+
+// ============================================================================
+// CUSTODY INSTRUCTIONS
+// ============================================================================
+
+#[test]
+fn infuse_within_budget() {
+    let signer = signer();
+    let mint = mint(USDC_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDC_MINT, 0);
+    let src = token_account(&signer.pubkey, &mint.pubkey, BAL_HIGH);
+    let vault = vault(&mint.pubkey, 0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Infuse,
+            AMT_HALF,
+            infuse_metas(&signer, &wallet, &src, &mint, &vault)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            src.into(),
+            mint.into(),
+            vault.into(),
+            token_program().into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Infuse", INFUSE_BUDGET);
+}
+
+#[test]
+fn extract_within_budget() {
+    let signer = signer();
+    let mint = mint(USDC_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDC_MINT, BAL_HIGH);
+    let vault = vault(&mint.pubkey, BAL_HIGH);
+    let dst = token_account(&signer.pubkey, &mint.pubkey, 0);
+    let authority = vault_authority(&mint.pubkey);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Extract,
+            AMT_HALF,
+            extract_metas(&signer, &wallet, &vault, &mint, &dst, &authority)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            vault.into(),
+            mint.into(),
+            dst.into(),
+            authority.into(),
+            token_program().into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "Extract", EXTRACT_BUDGET);
+}
+
+// ============================================================================
+// GENESIS INSTRUCTIONS
+// ============================================================================
+
+#[test]
+fn init_board_within_budget() {
+    let signer = signer();
+    let board = pda_account(derive_board_pda().0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::InitBoard,
+            with_system_program(metas!(signer, board))
+        ),
+        &[signer.into(), board.into(), system_program().into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "InitBoard", INIT_BOARD_BUDGET);
+}
+
+#[test]
+fn init_element_within_budget() {
+    let signer = signer();
+    let element = pda_account(derive_element_pda(26).0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::InitElement,
+            26u8,
+            with_system_program(metas!(signer, element))
+        ),
+        &[signer.into(), element.into(), system_program().into()],
+        &[Check::success()]
+    );
+    assert_budget(&result, "InitElement", INIT_ELEMENT_BUDGET);
+}
+
+#[test]
+fn init_artefact_within_budget() {
+    let signer = signer();
+    let element = canonical_account(&Element::genesis(26).unwrap());
+    let artefact = pda_account(derive_artefact_pda(ElementIndex::new(26, 0)).0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::InitArtefact,
+            with_system_program(metas!(signer, element, artefact))
+        ),
+        &[
+            signer.into(),
+            element.into(),
+            artefact.into(),
+            system_program().into()
+        ],
+        &[Check::success()]
+    );
+    assert_budget(&result, "InitArtefact", INIT_ARTEFACT_BUDGET);
+}
+
+// ============================================================================
+// LUT SEARCH
+// ============================================================================
+
+/// Prints what the LUT index costs and saves against a binary search over each whole column:
+/// compute units per curve instruction, and the size of both binaries. Fails if the index is
+/// not cheaper on every instruction. Needs the baseline binary next to the default one:
+///
+/// ```bash
+/// cargo build-sbf --features lut-binary-search
+/// mv target/deploy/tokamak_program.so target/deploy/tokamak_program_binary_search.so
+/// cargo build-sbf
+/// cargo test -p tokamak-program --test budget -- --ignored --nocapture lut_index
+/// ```
+#[test]
+#[ignore = "needs the lut-binary-search baseline binary"]
+fn lut_index_against_binary_search() {
+    let indexed = mollusk();
+    let baseline = mollusk_with(&deploy_path(BINARY_SEARCH_ELF));
+
+    for (name, case) in CURVE_CASES {
+        let (ix, accounts) = case();
+        let used = |m: &Mollusk| {
+            m.process_and_validate_instruction(&ix, &accounts, &[Check::success()])
+                .compute_units_consumed
+        };
+        let (new, old) = (used(&indexed), used(&baseline));
+        println!("{name}: {new} CU indexed, {old} CU binary search");
+        assert!(
+            new < old,
+            "{name}: index costs {new} CU, binary search {old}"
+        );
+    }
+
+    let size = |file| std::fs::metadata(deploy_path(file)).unwrap().len();
+    println!(
+        "binary: {} bytes indexed, {} bytes binary search",
+        size(PROGRAM_ELF),
+        size(BINARY_SEARCH_ELF)
+    );
+}
@@ -3,8 +3,9 @@
 mod common;
 use common::*;
 
-use nucleus::board::Artefact;
+use nucleus::board::{Artefact, Board};
 use nucleus::player::Charge;
+use nucleus::types::{Gluon, Q824};
 
 // ============================================================================
 // CLAIM INSTRUCTION TESTS
@@ -16,50 +17,140 @@ fn claim_success_proportional_share() {
     let signer = signer();
     let art_index = elem_index(3);
     let charge = charge_with_share(&signer.pubkey, 0, art_index, SHARE_ONE);
-    let art = artefact_full(1_000_000, art_index, SHARE_TWO);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, 0, 2);
+    let board = board(0, 2);
 
     let result = test_run!(
-        ix!(TokamakInstruction::Claim, metas!(signer, charge, art)),
-        &[signer.into(), charge.into(), art.into()],
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
         &[Check::success()]
     );
 
     let c: Charge = result.get(1);
     let a: Artefact = result.get(2);
-    assert_eq!(c.balance, AMT_HALF);
-    assert_eq!(a.pot, AMT_HALF);
-    assert_eq!(c.share, 0);
+    assert_eq!(c.balance, Gluon(AMT_HALF));
+    assert_eq!(a.pot, Gluon(AMT_HALF));
+    // The share leaves the artefact with its reward: the next claimant splits what is left
+    assert_eq!(a.shares, Q824(SHARE_TWO - SHARE_ONE));
+    assert_eq!(c.share, Q824::ZERO);
     assert!(c.index.is_zero());
 }
 
-/// Claim fails when charge has zero share (Custom(42))
+/// Claim moves the ejected charge off the board: artefact and board totals drop by its stake
 #[test]
-fn claim_fails_zero_share() {
+fn claim_releases_charge_from_board() {
     let signer = signer();
     let art_index = elem_index(3);
-    let charge = charge_with_share(&signer.pubkey, 0, art_index, 0);
-    let art = artefact_full(1_000_000, art_index, SHARE_TWO);
+    let charge = charge_with_share(&signer.pubkey, AMT_QUARTER, art_index, SHARE_ONE);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, AMT_HALF, 2);
+    let board = board(BAL_HIGH, 5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let a: Artefact = result.get(2);
+    let b: Board = result.get(3);
+    assert_eq!((a.tvl, a.charges), (Gluon(AMT_HALF - AMT_QUARTER), 1));
+    assert_eq!((b.tvl, b.charge_count), (Gluon(BAL_HIGH - AMT_QUARTER), 4));
+}
+
+/// Last claimant takes the artefact's remaining TVL (curve fee residue) off the board, and
+/// the rest of the pot whatever its share
+#[test]
+fn claim_last_charge_clears_artefact_tvl() {
+    let signer = signer();
+    let art_index = elem_index(3);
+    let charge = charge_with_share(&signer.pubkey, AMT_QUARTER, art_index, SHARE_ONE);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, AMT_HALF, 1);
+    let board = board(BAL_HIGH, 5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let a: Artefact = result.get(2);
+    let b: Board = result.get(3);
+    assert_eq!((a.tvl, a.charges, a.pot), (Gluon::ZERO, 0, Gluon::ZERO));
+    assert_eq!(c.balance, Gluon(AMT_QUARTER + 1_000_000));
+    assert_eq!((b.tvl, b.charge_count), (Gluon(BAL_HIGH - AMT_HALF), 4));
+}
+
+/// Ejected charge without share still leaves the board (zero reward)
+#[test]
+fn claim_zero_share_releases_charge() {
+    let signer = signer();
+    let art_index = elem_index(3);
+    let charge = charge_with_share(&signer.pubkey, AMT_QUARTER, art_index, 0);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, AMT_HALF, 2);
+    let board = board(BAL_HIGH, 5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let a: Artefact = result.get(2);
+    assert_eq!(c.balance, Gluon(AMT_QUARTER));
+    assert!(c.index.is_zero());
+    assert_eq!(a.pot, Gluon(1_000_000));
+}
+
+/// Claim fails before the artefact's generation has overloaded (NothingToClaim)
+#[test]
+fn claim_fails_before_overload() {
+    let signer = signer();
+    let art_index = elem_index(3);
+    let charge = charge_with_share(&signer.pubkey, 0, art_index, SHARE_ONE);
+    let art = artefact(art_index);
+    let board = board_with_count(5);
 
     test_run!(
-        ix!(TokamakInstruction::Claim, metas!(signer, charge, art)),
-        &[signer.into(), charge.into(), art.into()],
-        &[Check::err(ProgramError::Custom(42))]
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::err(TokamakError::NothingToClaim.into())]
     );
 }
 
-/// Claim fails when charge index doesn't match artefact index (Custom(42))
+/// Claim fails when charge index doesn't match artefact index (ArtefactMismatch)
 #[test]
 fn claim_fails_index_mismatch() {
     let signer = signer();
     let charge_index = elem_index(3);
     let art_index = elem_index(5);
     let charge = charge_with_share(&signer.pubkey, 0, charge_index, SHARE_ONE);
-    let art = artefact_full(1_000_000, art_index, SHARE_TWO);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_TWO, 0, 2);
+    let board = board_with_count(5);
 
     test_run!(
-        ix!(TokamakInstruction::Claim, metas!(signer, charge, art)),
-        &[signer.into(), charge.into(), art.into()],
-        &[Check::err(ProgramError::Custom(42))]
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer, charge, art, board)
+        ),
+        &[signer.into(), charge.into(), art.into(), board.into()],
+        &[Check::err(TokamakError::ArtefactMismatch.into())]
     );
 }
 
@@ -69,16 +160,20 @@ fn claim_multiple_claims_distribute_correctly() {
     let art_index = elem_index(3);
     let signer1 = signer();
     let charge1 = charge_with_share(&signer1.pubkey, 0, art_index, SHARE_ONE);
-    let art = artefact_full(1_000_000, art_index, SHARE_FOUR);
+    let art = artefact_ejected(1_000_000, art_index, SHARE_FOUR, 0, 4);
+    let board = board(0, 4);
 
     let result = test_run!(
-        ix!(TokamakInstruction::Claim, metas!(signer1, charge1, art)),
-        &[signer1.into(), charge1.into(), art.into()],
+        ix!(
+            TokamakInstruction::Claim,
+            metas!(signer1, charge1, art, board)
+        ),
+        &[signer1.into(), charge1.into(), art.into(), board.into()],
         &[Check::success()]
     );
 
     let c1: Charge = result.get(1);
     let a1: Artefact = result.get(2);
-    assert_eq!(c1.balance, AMT_QUARTER);
-    assert_eq!(a1.pot, 750_000);
+    assert_eq!(c1.balance, Gluon(AMT_QUARTER));
+    assert_eq!(a1.pot, Gluon(750_000));
 }
@@ -0,0 +1,169 @@
+//! Tests for CloseCharge and CloseWallet instructions: empty accounts return their rent to the
+//! signer.
+
+mod common;
+use common::*;
+
+use nucleus::{player::Wallet, types::Gluon};
+
+/// Wallet with `balance` and `open` charges out of `open` issued ids
+fn wallet_open(authority: &Pubkey, balance: u64, open: u32) -> AccountWithPubkey {
+    let w = Wallet {
+        balance: Gluon(balance),
+        authority: authority.to_bytes(),
+        mint: [0u8; 32],
+        charges: open,
+        open,
+    };
+    (Pubkey::new_unique(), program_account(stored(&w))).into()
+}
+
+/// Run CloseCharge of `charge` paired with a wallet holding one open charge
+fn run_close_charge(signer: AccountWithPubkey, charge: AccountWithPubkey, check: Check) {
+    let wallet = wallet_open(&signer.pubkey, 0, 1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::CloseCharge,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[check]
+    );
+}
+
+/// Run CloseWallet of `wallet`
+fn run_close_wallet(signer: AccountWithPubkey, wallet: AccountWithPubkey, check: Check) {
+    test_run!(
+        ix!(TokamakInstruction::CloseWallet, metas!(signer, wallet)),
+        &[signer.into(), wallet.into()],
+        &[check]
+    );
+}
+
+// ============================================================================
+// CLOSE CHARGE INSTRUCTION TESTS
+// ============================================================================
+
+/// CloseCharge moves the rent to the signer, closes the account and frees a wallet slot
+#[test]
+fn close_charge_success_returns_rent() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, 0, ZERO_INDEX);
+    let wallet = wallet_open(&signer.pubkey, BAL_MIN, 2);
+    let (signer_key, charge_key) = (signer.pubkey, charge.pubkey);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::CloseCharge,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[
+            Check::success(),
+            Check::account(&charge_key).closed().build(),
+            Check::account(&signer_key)
+                .lamports(SIGNER_LAMPORTS + LAMPORTS)
+                .build(),
+        ]
+    );
+
+    // The id stays spent: the next charge still derives from `charges`
+    let w: Wallet = result.get(2);
+    assert_eq!((w.charges, w.open), (2, 1));
+    assert_eq!(w.balance, Gluon(BAL_MIN));
+}
+
+/// CloseCharge fails while the charge is bound (or ejected and unclaimed)
+#[test]
+fn close_charge_fails_charge_is_bound() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, 0, elem_index(1));
+
+    run_close_charge(
+        signer,
+        charge,
+        Check::err(TokamakError::ChargeStillBound.into()),
+    );
+}
+
+/// CloseCharge fails while the charge holds Gluon
+#[test]
+fn close_charge_fails_balance_left() {
+    let signer = signer();
+    let charge = charge_min(&signer.pubkey);
+
+    run_close_charge(
+        signer,
+        charge,
+        Check::err(TokamakError::AccountNotEmpty.into()),
+    );
+}
+
+/// CloseCharge fails when the charge belongs to another player than the wallet
+#[test]
+fn close_charge_fails_foreign_charge() {
+    let signer = signer();
+    let charge = charge(&Pubkey::new_unique(), 0, ZERO_INDEX);
+
+    run_close_charge(signer, charge, Check::err(ProgramError::IncorrectAuthority));
+}
+
+// ============================================================================
+// CLOSE WALLET INSTRUCTION TESTS
+// ============================================================================
+
+/// CloseWallet moves the rent to the signer and closes the account
+#[test]
+fn close_wallet_success_returns_rent() {
+    let signer = signer();
+    let wallet = wallet_open(&signer.pubkey, 0, 0);
+    let (signer_key, wallet_key) = (signer.pubkey, wallet.pubkey);
+
+    test_run!(
+        ix!(TokamakInstruction::CloseWallet, metas!(signer, wallet)),
+        &[signer.into(), wallet.into()],
+        &[
+            Check::success(),
+            Check::account(&wallet_key).closed().build(),
+            Check::account(&signer_key)
+                .lamports(SIGNER_LAMPORTS + LAMPORTS)
+                .build(),
+        ]
+    );
+}
+
+/// CloseWallet fails while any charge of the wallet is open
+#[test]
+fn close_wallet_fails_open_charges() {
+    let signer = signer();
+    let wallet = wallet_open(&signer.pubkey, 0, 1);
+
+    run_close_wallet(
+        signer,
+        wallet,
+        Check::err(TokamakError::AccountNotEmpty.into()),
+    );
+}
+
+/// CloseWallet fails while the wallet holds Gluon
+#[test]
+fn close_wallet_fails_balance_left() {
+    let signer = signer();
+    let wallet = wallet_min(&signer.pubkey);
+
+    run_close_wallet(
+        signer,
+        wallet,
+        Check::err(TokamakError::AccountNotEmpty.into()),
+    );
+}
+
+/// CloseWallet fails for a signer other than the wallet authority
+#[test]
+fn close_wallet_fails_wrong_authority() {
+    let signer = signer();
+    let wallet = wallet_open(&Pubkey::new_unique(), 0, 0);
+
+    run_close_wallet(signer, wallet, Check::err(ProgramError::IncorrectAuthority));
+}
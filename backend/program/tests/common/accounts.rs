@@ -3,7 +3,12 @@
 use super::constants::*;
 use super::prelude::*;
 use super::types::AccountWithPubkey;
-use nucleus::{player::Charge, player::Wallet, types::ElementIndex};
+use super::utils::stored;
+use nucleus::{
+    player::Charge,
+    player::Wallet,
+    types::{ElementIndex, Gluon, Q824},
+};
 
 /// Base account config for program-owned accounts
 pub fn program_account(data: Vec<u8>) -> Account {
@@ -31,15 +36,20 @@ pub fn signer() -> AccountWithPubkey {
 
 /// Creates wallet account
 pub fn wallet(authority: &Pubkey, balance: u64) -> AccountWithPubkey {
+    wallet_for_mint(authority, [0u8; 32], balance)
+}
+
+/// Creates wallet account denominated in `mint`
+pub fn wallet_for_mint(authority: &Pubkey, mint: [u8; 32], balance: u64) -> AccountWithPubkey {
     let key = Pubkey::new_unique();
     let w = Wallet {
-        balance,
+        balance: Gluon(balance),
         authority: authority.to_bytes(),
-        mint: [0u8; 32],
+        mint,
         charges: 0,
-        _pad: 0,
+        open: 0,
     };
-    let data = bytes_of(&w).to_vec();
+    let data = stored(&w);
     (key, program_account(data)).into()
 }
 
@@ -57,21 +67,35 @@ pub fn charge_with_share(
 ) -> AccountWithPubkey {
     let key = Pubkey::new_unique();
     let c = Charge {
-        balance,
+        balance: Gluon(balance),
         timestamp: 0,
         index,
-        share,
+        share: Q824(share),
         authority: authority.to_bytes(),
         mint: [0u8; 32],
         _pad: 0,
     };
-    let data = bytes_of(&c).to_vec();
+    let data = stored(&c);
     AccountWithPubkey {
         pubkey: key,
         account: program_account(data),
     }
 }
 
+/// Creates unbound charge account denominated in `mint`
+pub fn charge_for_mint(authority: &Pubkey, mint: [u8; 32], balance: u64) -> AccountWithPubkey {
+    let c = Charge {
+        balance: Gluon(balance),
+        timestamp: 0,
+        index: ZERO_INDEX,
+        share: Q824::ZERO,
+        authority: authority.to_bytes(),
+        mint,
+        _pad: 0,
+    };
+    (Pubkey::new_unique(), program_account(stored(&c))).into()
+}
+
 /// Creates charge account (zero share)
 pub fn charge(authority: &Pubkey, balance: u64, index: ElementIndex) -> AccountWithPubkey {
     charge_with_share(authority, balance, index, 0)
@@ -1,28 +1,38 @@
 //! Artefact account factories.
 
-use super::accounts::program_account;
 use super::constants::*;
+use super::pdas::canonical_account;
 use super::prelude::*;
 use super::types::AccountWithPubkey;
-use nucleus::{board::Artefact, types::ElementIndex};
+use nucleus::{
+    board::Artefact,
+    types::{ElementIndex, Gluon, Q824},
+};
 
 /// Creates artefact account with index and shares
 pub fn artefact_full(pot: u64, index: ElementIndex, shares: u32) -> AccountWithPubkey {
-    let key = Pubkey::new_unique();
+    artefact_ejected(pot, index, shares, 0, 0)
+}
+
+/// Creates overloaded artefact with `charges` ejected charges holding `tvl` awaiting claim
+pub fn artefact_ejected(
+    pot: u64,
+    index: ElementIndex,
+    shares: u32,
+    tvl: u64,
+    charges: u32,
+) -> AccountWithPubkey {
     let a = Artefact {
-        pot,
+        pot: Gluon(pot),
         index,
-        shares,
-        _pad: 0,
+        tvl: Gluon(tvl),
+        shares: Q824(shares),
+        charges,
     };
-    let data = bytes_of(&a).to_vec();
-    AccountWithPubkey {
-        pubkey: key,
-        account: program_account(data),
-    }
+    canonical_account(&a)
 }
 
-/// Creates artefact account (index and shares default to zero)
-pub fn artefact(pot: u64) -> AccountWithPubkey {
-    artefact_full(pot, ZERO_INDEX, 0)
+/// Creates the empty artefact for an element generation (as after InitArtefact)
+pub fn artefact(index: ElementIndex) -> AccountWithPubkey {
+    artefact_full(0, index, 0)
 }
@@ -1,26 +1,31 @@
 //! Board account factories.
 
-use super::accounts::program_account;
 use super::constants::*;
+use super::pdas::canonical_account;
 use super::prelude::*;
 use super::types::AccountWithPubkey;
-use nucleus::board::Board;
+use nucleus::{board::Board, types::Gluon};
 
 /// Creates board account
 pub fn board(tvl: u64, charge_count: u32) -> AccountWithPubkey {
-    let key = Pubkey::new_unique();
+    board_with_pocket(tvl, charge_count, 0, 0)
+}
+
+/// Creates board account with a funded quantum pocket at a given unlock position
+pub fn board_with_pocket(
+    tvl: u64,
+    charge_count: u32,
+    quantum_pocket: u64,
+    quantum_index: u8,
+) -> AccountWithPubkey {
     let b = Board {
-        tvl,
-        quantum_pocket: 0,
+        tvl: Gluon(tvl),
+        quantum_pocket: Gluon(quantum_pocket),
         charge_count,
-        quantum_index: 0,
+        quantum_index,
         _pad: [0u8; 3],
     };
-    let data = bytes_of(&b).to_vec();
-    AccountWithPubkey {
-        pubkey: key,
-        account: program_account(data),
-    }
+    canonical_account(&b)
 }
 
 /// Creates board with 0 tvl and 0 charge_count (common default)
@@ -1,16 +1,19 @@
 //! Element account factories.
 
-use super::accounts::program_account;
 use super::constants::*;
+use super::pdas::canonical_account;
 use super::prelude::*;
 use super::types::AccountWithPubkey;
 use super::utils::elem_index;
+use bytemuck::Zeroable;
 use nucleus::{
-    board::{Curve, Element},
-    types::Coordinates,
+    action,
+    board::{Board, Curve, Element},
+    player::Charge,
+    types::{Coordinates, Gluon, Q1648, Q824},
 };
 
-/// Creates element account with shares
+/// Creates element account with shares; shares imply the test's own charge is bound
 pub fn element_with_shares(
     atomic: u64,
     coords: u64,
@@ -18,24 +21,23 @@ pub fn element_with_shares(
     pot: u64,
     shares: u32,
 ) -> AccountWithPubkey {
-    let key = Pubkey::new_unique();
     let e = Element {
-        pot,
+        pot: Gluon(pot),
         index: elem_index(atomic),
         curve: Curve {
-            capacity: 1_000_000_000_000,
-            tvl: 0,
-            pressure: 0,
-            saturation,
-            shares,
+            capacity: Gluon(1_000_000_000_000),
+            tvl: Gluon::ZERO,
+            pressure: Q1648::ZERO,
+            saturation: Q824(saturation),
+            shares: Q824(shares),
+            shape: 0,
+            _pad: 0,
         },
         coordinates: Coordinates(coords),
+        charges: u32::from(shares > 0),
+        _pad: 0,
     };
-    let data = bytes_of(&e).to_vec();
-    AccountWithPubkey {
-        pubkey: key,
-        account: program_account(data),
-    }
+    canonical_account(&e)
 }
 
 /// Creates element account
@@ -73,3 +75,68 @@ pub fn element_with_shares_at(
 ) -> AccountWithPubkey {
     element_with_shares(atomic, coords, saturation, pot, shares)
 }
+
+/// Creates element at max saturation with `charges` bound holding `tvl` in its curve
+pub fn element_overloaded(atomic: u64, pot: u64, tvl: u64, charges: u32) -> AccountWithPubkey {
+    let e = Element {
+        pot: Gluon(pot),
+        index: elem_index(atomic),
+        curve: Curve {
+            capacity: Gluon(1_000_000_000_000),
+            tvl: Gluon(tvl),
+            pressure: Q1648::ZERO,
+            saturation: MAX_SATURATION,
+            shares: Q824(SHARE_FOUR),
+            shape: 0,
+            _pad: 0,
+        },
+        coordinates: Coordinates(EDGE_COORD),
+        charges,
+        _pad: 0,
+    };
+    canonical_account(&e)
+}
+
+/// Creates empty element at custom coordinates whose whole curve holds only `capacity`,
+/// so a single deposit of that size saturates it
+pub fn element_with_capacity(atomic: u64, coords: u64, capacity: u64) -> AccountWithPubkey {
+    let e = Element {
+        pot: Gluon::ZERO,
+        index: elem_index(atomic),
+        curve: Curve {
+            capacity: Gluon(capacity),
+            tvl: Gluon::ZERO,
+            pressure: Q1648::ZERO,
+            saturation: Q824::ZERO,
+            shares: Q824::ZERO,
+            shape: 0,
+            _pad: 0,
+        },
+        coordinates: Coordinates(coords),
+        charges: 0,
+        _pad: 0,
+    };
+    canonical_account(&e)
+}
+
+/// Creates element at custom coordinates holding one charge of `balance`, bound through its
+/// curve so that moving it back out is exact; returns the element and that charge's share
+pub fn element_bound_at(atomic: u64, coords: u64, balance: u64) -> (AccountWithPubkey, u32) {
+    let mut e = Element {
+        pot: Gluon::ZERO,
+        index: elem_index(atomic),
+        curve: Curve {
+            capacity: Gluon(1_000_000_000_000),
+            ..Curve::zeroed()
+        },
+        coordinates: Coordinates(coords),
+        charges: 0,
+        _pad: 0,
+    };
+    let mut charge = Charge {
+        balance: Gluon(balance),
+        ..Charge::zeroed()
+    };
+    action::bind(&mut charge, &mut e, &mut Board::zeroed()).unwrap();
+    (canonical_account(&e), charge.share.0)
+}
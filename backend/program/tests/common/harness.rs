@@ -5,15 +5,26 @@ use super::prelude::*;
 
 /// Creates the test mollusk instance
 pub fn mollusk() -> Mollusk {
+    mollusk_with(&deploy_path("tokamak_program.so"))
+}
+
+/// Creates a mollusk instance running the program ELF at `elf_path`
+pub fn mollusk_with(elf_path: &std::path::Path) -> Mollusk {
     let mut m = Mollusk::default();
-    let elf_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("../target/deploy/tokamak_program.so");
-    let elf = std::fs::read(&elf_path).expect("Run `cargo build-sbf` first.");
+    let elf = std::fs::read(elf_path).expect("Run `cargo build-sbf` first.");
     m.add_program_with_loader_and_elf(&PROGRAM_ID, &solana_sdk::bpf_loader::id(), &elf);
+    mollusk_svm_programs_token::token::add_program(&mut m);
     m.warp_to_slot(2000);
     m
 }
 
+/// Path of `file` in the `cargo build-sbf` output directory
+pub fn deploy_path(file: &str) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/deploy")
+        .join(file)
+}
+
 /// Execute instruction with accounts and checks
 #[macro_export]
 macro_rules! test_run {
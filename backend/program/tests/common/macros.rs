@@ -15,6 +15,17 @@ macro_rules! ix {
     }};
 }
 
+/// Build instruction from variant, Pod data (e.g. `Guards`) and metas
+#[macro_export]
+macro_rules! ix_pod {
+    ($variant:expr, $value:expr, $metas:expr) => {{
+        let mut data = ::std::vec::Vec::new();
+        data.extend_from_slice(&($variant as u64).to_le_bytes());
+        data.extend_from_slice(::bytemuck::bytes_of(&$value));
+        ::solana_sdk::instruction::Instruction::new_with_bytes(tokamak_program::ID, &data, $metas)
+    }};
+}
+
 /// Build account metas: first arg is signer (writable), rest are writable
 #[macro_export]
 macro_rules! metas {
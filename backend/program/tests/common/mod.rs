@@ -12,6 +12,7 @@ mod pdas;
 mod prelude;
 mod types;
 mod utils;
+mod vaults;
 
 // Re-export everything
 pub use accounts::*;
@@ -25,3 +26,4 @@ pub use pdas::*;
 pub use prelude::*;
 pub use types::*;
 pub use utils::*;
+pub use vaults::*;
@@ -1,8 +1,11 @@
 //! PDA derivation and helpers.
 
+use super::accounts::program_account;
 use super::constants::PROGRAM_ID;
 use super::prelude::*;
 use super::types::AccountWithPubkey;
+use super::utils::stored_with_bump;
+use nucleus::{header::Discriminated, seeds::Canonical, types::ElementIndex};
 
 /// Derive wallet PDA and bump from signer and mint
 pub fn derive_wallet_pda(signer: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
@@ -14,6 +17,33 @@ pub fn derive_charge_pda(signer: &Pubkey, mint: &[u8; 32], index: u32) -> (Pubke
     Pubkey::find_program_address(&[signer.as_ref(), mint, &index.to_le_bytes()], &PROGRAM_ID)
 }
 
+/// Derive the board singleton PDA and bump
+pub fn derive_board_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"board"], &PROGRAM_ID)
+}
+
+/// Derive element PDA and bump from atomic number
+pub fn derive_element_pda(atomic: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"element", &[atomic]], &PROGRAM_ID)
+}
+
+/// Derive artefact PDA and bump from element index
+pub fn derive_artefact_pda(index: ElementIndex) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"artefact", &index.0.to_le_bytes()], &PROGRAM_ID)
+}
+
+/// Creates a program account at the canonical PDA derived from its contents
+pub fn canonical_account<T>(value: &T) -> AccountWithPubkey
+where
+    T: bytemuck::Pod + Discriminated + Canonical,
+{
+    let (address, bump) = value.seeds().find(&PROGRAM_ID.to_bytes());
+    AccountWithPubkey {
+        pubkey: Pubkey::new_from_array(address),
+        account: program_account(stored_with_bump(value, bump)),
+    }
+}
+
 /// Creates an uninitialized PDA account (no data, owned by system program)
 pub fn pda_account(pda: Pubkey) -> AccountWithPubkey {
     AccountWithPubkey {
@@ -28,3 +58,14 @@ pub fn pda_account(pda: Pubkey) -> AccountWithPubkey {
         },
     }
 }
+
+/// System program account, required for the CreateAccount CPI of the init instructions and Split
+pub fn system_program() -> AccountWithPubkey {
+    mollusk_svm::program::keyed_account_for_system_program().into()
+}
+
+/// Appends the system program to `metas`, for instructions that create an account
+pub fn with_system_program(mut metas: Vec<AccountMeta>) -> Vec<AccountMeta> {
+    metas.push(AccountMeta::new_readonly(system_program().pubkey, false));
+    metas
+}
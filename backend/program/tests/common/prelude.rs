@@ -7,3 +7,4 @@ pub use solana_sdk::instruction::{AccountMeta, Instruction};
 pub use solana_sdk::program_error::ProgramError;
 pub use solana_sdk::{account::Account, pubkey::Pubkey};
 pub use tokamak_program::instruction::TokamakInstruction;
+pub use tokamak_program::TokamakError;
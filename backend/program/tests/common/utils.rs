@@ -3,13 +3,41 @@
 use super::prelude::*;
 use nucleus::{
     board::{Board, Element},
+    header::{Discriminated, Header},
     player::Charge,
     types::ElementIndex,
 };
 
-/// Reads account data into type T
+/// Serializes a value as stored on-chain: header followed by body
+pub fn stored<T: bytemuck::Pod + Discriminated>(value: &T) -> Vec<u8> {
+    stored_with_bump(value, 0)
+}
+
+/// Serializes a value as stored on-chain, recording its PDA bump in the header
+pub fn stored_with_bump<T: bytemuck::Pod + Discriminated>(value: &T, bump: u8) -> Vec<u8> {
+    let mut header = T::HEADER;
+    header.bump = bump;
+    let mut data = bytes_of(&header).to_vec();
+    data.extend_from_slice(bytes_of(value));
+    data
+}
+
+/// Reads account data (after the header) into type T
 pub fn read<T: bytemuck::Pod + Copy>(account: &Account) -> T {
-    *bytemuck::from_bytes(&account.data[..size_of::<T>()])
+    *bytemuck::from_bytes(&account.data[Header::LEN..Header::LEN + size_of::<T>()])
+}
+
+/// Reads the account header
+pub fn read_header(account: &Account) -> Header {
+    *bytemuck::from_bytes(&account.data[..Header::LEN])
+}
+
+/// Split instruction data: the amount, then the new charge's PDA bump
+pub fn split_ix(amount: u64, bump: u8, metas: Vec<AccountMeta>) -> Instruction {
+    let mut data = (TokamakInstruction::Split as u64).to_le_bytes().to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(bump);
+    Instruction::new_with_bytes(tokamak_program::ID, &data, metas)
 }
 
 /// Adjacent element coordinates for testing
@@ -35,9 +63,9 @@ pub fn assert_charge_bal(
 ) {
     let c: Charge = read(&result.resulting_accounts[idx].1);
     assert_eq!(
-        c.balance, expected,
+        c.balance.0, expected,
         "Expected charge balance {}, got {}",
-        expected, c.balance
+        expected, c.balance.0
     );
 }
 
@@ -45,9 +73,9 @@ pub fn assert_charge_bal(
 pub fn assert_pot(result: &mollusk_svm::result::InstructionResult, idx: usize, expected: u64) {
     let e: Element = read(&result.resulting_accounts[idx].1);
     assert_eq!(
-        e.pot, expected,
+        e.pot.0, expected,
         "Expected element pot {}, got {}",
-        expected, e.pot
+        expected, e.pot.0
     );
 }
 
@@ -0,0 +1,120 @@
+//! Token mint, token account and vault factories for custody tests.
+
+use super::constants::*;
+use super::prelude::*;
+use super::types::AccountWithPubkey;
+use mollusk_svm_programs_token::token;
+use nucleus::consts::DECIMALS;
+use solana_sdk::program_option::COption;
+use spl_token_interface::state::{Account as TokenAccount, AccountState, Mint};
+use tokamak_program::addresses::{ASSOCIATED_TOKEN_PROGRAM, VAULT_SEED};
+
+/// Token program account, required for transfer CPIs
+pub fn token_program() -> AccountWithPubkey {
+    token::keyed_account().into()
+}
+
+/// Creates an initialized mint account with program decimals
+pub fn mint(address: [u8; 32], supply: u64) -> AccountWithPubkey {
+    let m = Mint {
+        mint_authority: COption::None,
+        supply,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    (
+        Pubkey::new_from_array(address),
+        token::create_account_for_mint(m),
+    )
+        .into()
+}
+
+/// Creates a token account holding `amount` of `mint`, owned by `owner`
+pub fn token_account(owner: &Pubkey, mint: &Pubkey, amount: u64) -> AccountWithPubkey {
+    let t = TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    (
+        Pubkey::new_unique(),
+        token::create_account_for_token_account(t),
+    )
+        .into()
+}
+
+/// Derive the vault authority PDA and bump for a mint
+pub fn derive_vault_authority(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, mint.as_ref()], &PROGRAM_ID)
+}
+
+/// Derive the vault ATA (associated token account of the vault authority)
+pub fn derive_vault_ata(mint: &Pubkey) -> Pubkey {
+    let (authority, _) = derive_vault_authority(mint);
+    let seeds = [authority.as_ref(), token::ID.as_ref(), mint.as_ref()];
+    let ata_program = Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM);
+    Pubkey::find_program_address(&seeds, &ata_program).0
+}
+
+/// Creates the vault token account for a mint holding `amount`
+pub fn vault(mint: &Pubkey, amount: u64) -> AccountWithPubkey {
+    let (authority, _) = derive_vault_authority(mint);
+    let mut vault = token_account(&authority, mint, amount);
+    vault.pubkey = derive_vault_ata(mint);
+    vault
+}
+
+/// Creates the (data-less) vault authority account for a mint
+pub fn vault_authority(mint: &Pubkey) -> AccountWithPubkey {
+    let (authority, _) = derive_vault_authority(mint);
+    (authority, Account::default()).into()
+}
+
+/// Reads the token amount of a token account at result index
+pub fn token_amount(result: &mollusk_svm::result::InstructionResult, idx: usize) -> u64 {
+    use solana_sdk::program_pack::Pack;
+    TokenAccount::unpack(&result.resulting_accounts[idx].1.data)
+        .unwrap()
+        .amount
+}
+
+/// Infuse metas: signer, wallet, src, mint, vault, token program
+pub fn infuse_metas(
+    signer: &AccountWithPubkey,
+    wallet: &AccountWithPubkey,
+    src: &AccountWithPubkey,
+    mint: &AccountWithPubkey,
+    vault: &AccountWithPubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(signer.pubkey, true),
+        AccountMeta::new(wallet.pubkey, false),
+        AccountMeta::new(src.pubkey, false),
+        AccountMeta::new_readonly(mint.pubkey, false),
+        AccountMeta::new(vault.pubkey, false),
+        AccountMeta::new_readonly(token_program().pubkey, false),
+    ]
+}
+
+/// Extract metas: signer, wallet, vault, mint, dst, vault authority, token program
+pub fn extract_metas(
+    signer: &AccountWithPubkey,
+    wallet: &AccountWithPubkey,
+    vault: &AccountWithPubkey,
+    mint: &AccountWithPubkey,
+    dst: &AccountWithPubkey,
+    authority: &AccountWithPubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(signer.pubkey, true),
+        AccountMeta::new(wallet.pubkey, false),
+        AccountMeta::new(vault.pubkey, false),
+        AccountMeta::new_readonly(mint.pubkey, false),
+        AccountMeta::new(dst.pubkey, false),
+        AccountMeta::new_readonly(authority.pubkey, false),
+        AccountMeta::new_readonly(token_program().pubkey, false),
+    ]
+}
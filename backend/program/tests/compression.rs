@@ -5,6 +5,7 @@ use common::*;
 
 use nucleus::board::Element;
 use nucleus::player::Charge;
+use nucleus::types::Gluon;
 
 // ============================================================================
 // COMPRESS INSTRUCTION TESTS
@@ -19,7 +20,7 @@ fn compress_success_inward() {
     let src_index = elem_index(2);
     let charge = charge_shared(&signer.pubkey, src_index);
     let src = element_with_shares_at(2, src_coord, SHARE_ONE, src_pot, SHARE_ONE);
-    let dst = element_edge(5);
+    let dst = element_edge(3);
 
     let result = test_run!(
         ix!(
@@ -34,11 +35,11 @@ fn compress_success_inward() {
     let src_elem: Element = result.get(2);
     let dst_elem: Element = result.get(3);
     assert_eq!(c.index, dst_elem.index);
-    assert_eq!(src_elem.pot, 0);
-    assert!(dst_elem.pot >= src_pot);
+    assert_eq!(src_elem.pot, Gluon::ZERO);
+    assert!(dst_elem.pot >= Gluon(src_pot));
 }
 
-/// Compress fails when compressing outward (Custom(42))
+/// Compress fails when compressing outward (CompressionNotInward)
 #[test]
 fn compress_fails_outward() {
     let signer = signer();
@@ -54,11 +55,48 @@ fn compress_fails_outward() {
             metas!(signer, charge, src, dst)
         ),
         &[signer.into(), charge.into(), src.into(), dst.into()],
-        &[Check::err(ProgramError::Custom(42))]
+        &[Check::err(TokamakError::CompressionNotInward.into())]
+    );
+}
+
+/// Compress fails when the destination is deeper but not a neighbour (NotAdjacent)
+#[test]
+fn compress_fails_not_adjacent() {
+    let signer = signer();
+    let (src_coord, _dst_coord) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares_at(2, src_coord, SHARE_ONE, AMT_HALF, SHARE_ONE);
+    let dst = element_edge(5);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Compress,
+            metas!(signer, charge, src, dst)
+        ),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::err(TokamakError::NotAdjacent.into())]
     );
 }
 
-/// Compress fails when charge is not bound to source element (Custom(1))
+/// Compress fails when the same element is passed as source and destination: the two would
+/// alias one account, and moving the pot onto itself would wipe it
+#[test]
+fn compress_fails_same_element() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares(2, EDGE_COORD, SHARE_ONE, AMT_HALF, SHARE_ONE);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Compress,
+            metas!(signer, charge, src, src)
+        ),
+        &[signer.into(), charge.into(), src.into()],
+        &[Check::err(ProgramError::AccountBorrowFailed)]
+    );
+}
+
+/// Compress fails when charge is not bound to source element (ChargeNotInElement)
 #[test]
 fn compress_fails_charge_not_in_source() {
     let signer = signer();
@@ -66,7 +104,7 @@ fn compress_fails_charge_not_in_source() {
     let other_index = elem_index(10);
     let charge = charge_high_with_index(&signer.pubkey, other_index);
     let src = element_at(2, src_coord);
-    let dst = element_edge(5);
+    let dst = element_edge(3);
 
     test_run!(
         ix!(
@@ -74,6 +112,6 @@ fn compress_fails_charge_not_in_source() {
             metas!(signer, charge, src, dst)
         ),
         &[signer.into(), charge.into(), src.into(), dst.into()],
-        &[Check::err(ProgramError::Custom(1))]
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
     );
 }
@@ -1,9 +1,16 @@
-//! Cross-cutting tests for authority validation and speed tax.
+//! Cross-cutting tests for authority validation, speed tax and move guards.
 
 mod common;
 use common::*;
 
-use nucleus::player::Charge;
+use mollusk_svm::result::InstructionResult;
+use nucleus::{
+    board::Element,
+    guards::Guards,
+    player::Charge,
+    seeds::Seeds,
+    types::{ElementIndex, Gluon, Q824},
+};
 
 // ============================================================================
 // AUTHORITY VALIDATION TESTS
@@ -31,6 +38,150 @@ fn authority_fails_missing_signature() {
     );
 }
 
+// ============================================================================
+// ACCOUNT HEADER TESTS
+// ============================================================================
+
+/// An Element account passed where a Charge is expected is rejected by its header
+#[test]
+fn header_rejects_wrong_account_kind() {
+    let signer = signer();
+    let elem = element_edge(3);
+    let target = element_edge(4);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, elem, target)
+        ),
+        &[signer.into(), elem.into(), target.into()],
+        &[Check::err(TokamakError::WrongAccountKind.into())]
+    );
+}
+
+/// A program account that was never written (zero header) is rejected
+#[test]
+fn header_rejects_uninitialized_account() {
+    let signer = signer();
+    let charge = AccountWithPubkey {
+        pubkey: Pubkey::new_unique(),
+        account: program_account(vec![0u8; 256]),
+    };
+    let target = element_edge(3);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, charge, target)
+        ),
+        &[signer.into(), charge.into(), target.into()],
+        &[Check::err(ProgramError::UninitializedAccount)]
+    );
+}
+
+/// A correctly tagged account owned by another program is rejected
+#[test]
+fn header_rejects_foreign_owner() {
+    let signer = signer();
+    let mut charge = charge_min(&signer.pubkey);
+    charge.account.owner = SYSTEM_PROGRAM_ID;
+    let target = element_edge(3);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, charge, target)
+        ),
+        &[signer.into(), charge.into(), target.into()],
+        &[Check::err(ProgramError::InvalidAccountOwner)]
+    );
+}
+
+// ============================================================================
+// CANONICAL ADDRESS TESTS
+// ============================================================================
+
+/// A well-formed Element at an address other than its PDA is rejected
+#[test]
+fn canonical_rejects_element_at_foreign_address() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(3));
+    let mut target = element_edge(3);
+    target.pubkey = Pubkey::new_unique();
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, charge, target)
+        ),
+        &[signer.into(), charge.into(), target.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// An Element at another element's PDA (spoofed atomic number) is rejected
+#[test]
+fn canonical_rejects_element_with_foreign_seeds() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(3));
+    let mut target = element_edge(3);
+    target.pubkey = element_edge(5).pubkey;
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, charge, target)
+        ),
+        &[signer.into(), charge.into(), target.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// A stored bump that differs from the canonical one is rejected
+#[test]
+fn canonical_rejects_wrong_stored_bump() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(3));
+    let mut target = element_edge(3);
+    target.account.data[2] = target.account.data[2].wrapping_sub(1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Vent,
+            AMT_HALF,
+            metas!(signer, charge, target)
+        ),
+        &[signer.into(), charge.into(), target.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// Nucleus seeds derive the same addresses as the SDK
+#[test]
+fn canonical_seeds_match_sdk_derivation() {
+    let program = PROGRAM_ID.to_bytes();
+    let index = ElementIndex::new(7, 2);
+
+    let (board, bump) = Seeds::board().find(&program);
+    assert_eq!((Pubkey::new_from_array(board), bump), derive_board_pda());
+    let (element, bump) = Seeds::element(7).find(&program);
+    assert_eq!(
+        (Pubkey::new_from_array(element), bump),
+        derive_element_pda(7)
+    );
+    let (artefact, bump) = Seeds::artefact(index).find(&program);
+    assert_eq!(
+        (Pubkey::new_from_array(artefact), bump),
+        derive_artefact_pda(index)
+    );
+    assert_eq!(Seeds::artefact(index).derive(bump, &program), artefact);
+}
+
 // ============================================================================
 // SPEED TAX TESTS
 // ============================================================================
@@ -44,17 +195,17 @@ fn speed_tax_higher_fee_for_rapid_actions() {
 
     // Both charges bound at slot 0; speed tax depends on current slot (elapsed time)
     let charge_base = Charge {
-        balance: BAL_MAX,
+        balance: Gluon(BAL_MAX),
         timestamp: 0,
         index: elem_index,
-        share: SHARE_ONE,
+        share: Q824(SHARE_ONE),
         authority: signer.pubkey.to_bytes(),
         mint: [0u8; 32],
         _pad: 0,
     };
     let charge_acc = Account {
         lamports: BAL_MIN,
-        data: bytes_of(&charge_base).to_vec(),
+        data: stored(&charge_base),
         owner: PROGRAM_ID,
         executable: false,
         rent_epoch: 0,
@@ -111,3 +262,322 @@ fn speed_tax_higher_fee_for_rapid_actions() {
         charge_patient_result.balance
     );
 }
+
+// ============================================================================
+// VALUE CONSERVATION TESTS
+// ============================================================================
+// Program-side counterparts of the sim invariant suite's per-action rules: whatever the
+// mover pays lands in an element pot, and compression grows the pot it merges into.
+
+/// Gluon the charge at `idx` paid, given its balance before the instruction
+fn paid(result: &InstructionResult, idx: usize, balance: u64) -> Gluon {
+    let c: Charge = result.get(idx);
+    Gluon(balance) - c.balance
+}
+
+/// Bind's fee is not burned: it all lands in the destination pot
+#[test]
+fn bind_fee_lands_in_pot() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let board = board_empty();
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Bind,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let e: Element = result.get(2);
+    let fee = paid(&result, 1, BAL_HIGH);
+    assert!(fee > Gluon::ZERO);
+    assert_eq!(e.pot, fee);
+}
+
+/// Unbind's fee is not burned: it all lands in the source pot
+#[test]
+fn unbind_fee_lands_in_pot() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let elem = element_with_shares_at(1, EDGE_COORD, SHARE_ONE, BAL_MIN, SHARE_ONE);
+    let board = board_with_count(1);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Unbind,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let e: Element = result.get(2);
+    let fee = paid(&result, 1, BAL_HIGH);
+    assert!(fee > Gluon::ZERO);
+    assert_eq!(e.pot, Gluon(BAL_MIN) + fee);
+}
+
+/// An inward Rebind's fee is not burned: it all lands in the destination pot
+#[test]
+fn rebind_fee_lands_in_pot() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares_at(2, src_coord, 0, 0, SHARE_ONE);
+    let dst = element_at(3, dst_coord);
+
+    let result = test_run!(
+        ix!(TokamakInstruction::Rebind, metas!(signer, charge, src, dst)),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::success()]
+    );
+
+    let (s, d): (Element, Element) = (result.get(2), result.get(3));
+    let fee = paid(&result, 1, BAL_HIGH);
+    assert!(fee > Gluon::ZERO);
+    assert_eq!((s.pot, d.pot), (Gluon::ZERO, fee));
+}
+
+/// Compress moves the whole source pot and both fees into the destination, which ends up
+/// strictly larger than the two pots before
+#[test]
+fn compress_grows_destination_pot() {
+    let signer = signer();
+    let (src_coord, _) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares_at(2, src_coord, SHARE_ONE, AMT_HALF, SHARE_ONE);
+    let dst = element(3, EDGE_COORD, 0, BAL_MIN);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Compress,
+            metas!(signer, charge, src, dst)
+        ),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::success()]
+    );
+
+    let (s, d): (Element, Element) = (result.get(2), result.get(3));
+    let fee = paid(&result, 1, BAL_HIGH);
+    assert!(fee > Gluon::ZERO);
+    assert_eq!(s.pot, Gluon::ZERO);
+    assert_eq!(d.pot, Gluon(AMT_HALF + BAL_MIN) + fee);
+}
+
+// ============================================================================
+// GUARD TESTS
+// ============================================================================
+
+/// A move within its guards succeeds like an unguarded one
+#[test]
+fn guards_within_limits_succeed() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let board = board_empty();
+    let guards = Guards {
+        max_fee: Gluon(BAL_HIGH),
+        deadline_slot: 2000,
+        min_share: Q824(1),
+        _pad: 0,
+    };
+
+    let result = test_run!(
+        ix_pod!(
+            TokamakInstruction::Bind,
+            guards,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let e: Element = result.get(2);
+    assert_eq!(c.index, e.index);
+}
+
+/// Bind fails when the fee exceeds `max_fee` (FeeAboveLimit)
+#[test]
+fn guards_reject_fee_above_limit() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let board = board_empty();
+    let guards = Guards {
+        max_fee: Gluon(1),
+        ..Guards::NONE
+    };
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::Bind,
+            guards,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::err(TokamakError::FeeAboveLimit.into())]
+    );
+}
+
+/// Compress fails when its combined fee exceeds `max_fee` (FeeAboveLimit)
+#[test]
+fn guards_reject_compress_fee_above_limit() {
+    let signer = signer();
+    let (src_coord, _) = adjacent_coords();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+    let src = element_with_shares_at(2, src_coord, SHARE_ONE, AMT_HALF, SHARE_ONE);
+    let dst = element_edge(3);
+    let guards = Guards {
+        max_fee: Gluon(1),
+        ..Guards::NONE
+    };
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::Compress,
+            guards,
+            metas!(signer, charge, src, dst)
+        ),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::err(TokamakError::FeeAboveLimit.into())]
+    );
+}
+
+/// Rebind fails when the destination share falls short of `min_share` (ShareBelowLimit)
+#[test]
+fn guards_reject_share_below_limit() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let dst = element_at(2, dst_coord);
+    let guards = Guards {
+        min_share: Q824(u32::MAX),
+        ..Guards::NONE
+    };
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::Rebind,
+            guards,
+            metas!(signer, charge, src, dst)
+        ),
+        &[signer.into(), charge.into(), src.into(), dst.into()],
+        &[Check::err(TokamakError::ShareBelowLimit.into())]
+    );
+}
+
+/// Unbind fails after `deadline_slot` (DeadlinePassed)
+#[test]
+fn guards_reject_past_deadline() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let elem = element_with_shares_at(1, EDGE_COORD, SHARE_ONE, BAL_MIN, SHARE_ONE);
+    let board = board_with_count(1);
+    let guards = Guards {
+        deadline_slot: 1999,
+        ..Guards::NONE
+    };
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::Unbind,
+            guards,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::err(TokamakError::DeadlinePassed.into())]
+    );
+}
+
+/// Guard data is all or nothing: a truncated tail is rejected
+#[test]
+fn guards_reject_truncated_data() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let board = board_empty();
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Bind,
+            u64::MAX,
+            metas!(signer, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::err(ProgramError::InvalidInstructionData)]
+    );
+}
+
+/// RebindPath from H through two inward hops, with `guards` after the hop count
+fn run_guarded_path(guards: Guards, check: Check) -> InstructionResult {
+    let signer = signer();
+    let (src, share) = element_bound_at(1, 0x01, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let mid = element_at(2, 0x02);
+    let dst = element_at(3, 0x04);
+
+    let mut ix = ix!(
+        TokamakInstruction::RebindPath,
+        2u8,
+        metas!(signer, charge, src, mid, dst)
+    );
+    ix.data.extend_from_slice(bytes_of(&guards));
+    test_run!(
+        ix,
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            mid.into(),
+            dst.into()
+        ],
+        &[check]
+    )
+}
+
+/// RebindPath checks `max_fee` against the fee of all hops together, not hop by hop
+#[test]
+fn guards_bound_rebind_path_fee_total() {
+    let result = run_guarded_path(Guards::NONE, Check::success());
+    let fee = paid(&result, 1, BAL_HIGH);
+    let (mid, dst): (Element, Element) = (result.get(3), result.get(4));
+    // One unit short of the total still covers either hop alone
+    assert!(mid.pot.max(dst.pot) < fee - Gluon(1));
+
+    let exact = Guards {
+        max_fee: fee,
+        ..Guards::NONE
+    };
+    run_guarded_path(exact, Check::success());
+    let short = Guards {
+        max_fee: fee - Gluon(1),
+        ..Guards::NONE
+    };
+    run_guarded_path(short, Check::err(TokamakError::FeeAboveLimit.into()));
+}
+
+/// RebindPath checks `min_share` against the share in the last element (ShareBelowLimit)
+#[test]
+fn guards_reject_rebind_path_share_below_limit() {
+    let guards = Guards {
+        min_share: Q824(u32::MAX),
+        ..Guards::NONE
+    };
+    run_guarded_path(guards, Check::err(TokamakError::ShareBelowLimit.into()));
+}
+
+/// RebindPath fails after `deadline_slot` (DeadlinePassed)
+#[test]
+fn guards_reject_rebind_path_past_deadline() {
+    let guards = Guards {
+        deadline_slot: 1999,
+        ..Guards::NONE
+    };
+    run_guarded_path(guards, Check::err(TokamakError::DeadlinePassed.into()));
+}
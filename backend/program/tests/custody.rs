@@ -0,0 +1,437 @@
+//! Tests for Infuse and Extract instructions (stablecoin custody).
+
+mod common;
+use common::*;
+
+use std::collections::HashMap;
+
+use nucleus::{
+    board::{Artefact, Board, Element},
+    player::{Charge, Wallet},
+    types::Gluon,
+};
+use proptest::prelude::*;
+use tokamak_program::addresses::{Vault, USDC_MINT, USDT_MINT};
+
+fn infuse_ok(mint_address: [u8; 32]) {
+    let signer = signer();
+    let mint = mint(mint_address, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, mint_address, 0);
+    let src = token_account(&signer.pubkey, &mint.pubkey, BAL_HIGH);
+    let vault = vault(&mint.pubkey, 0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Infuse,
+            AMT_HALF,
+            infuse_metas(&signer, &wallet, &src, &mint, &vault)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            src.into(),
+            mint.into(),
+            vault.into(),
+            token_program().into()
+        ],
+        &[Check::success()]
+    );
+
+    assert_eq!(result.get::<Wallet>(1).balance, Gluon(AMT_HALF));
+    assert_eq!(token_amount(&result, 2), BAL_HIGH - AMT_HALF);
+    assert_eq!(token_amount(&result, 4), AMT_HALF);
+}
+
+fn extract_ok(mint_address: [u8; 32]) {
+    let signer = signer();
+    let mint = mint(mint_address, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, mint_address, BAL_HIGH);
+    let vault = vault(&mint.pubkey, BAL_HIGH);
+    let dst = token_account(&signer.pubkey, &mint.pubkey, 0);
+    let authority = vault_authority(&mint.pubkey);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Extract,
+            AMT_HALF,
+            extract_metas(&signer, &wallet, &vault, &mint, &dst, &authority)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            vault.into(),
+            mint.into(),
+            dst.into(),
+            authority.into(),
+            token_program().into()
+        ],
+        &[Check::success()]
+    );
+
+    assert_eq!(result.get::<Wallet>(1).balance, Gluon(BAL_HIGH - AMT_HALF));
+    assert_eq!(token_amount(&result, 2), BAL_HIGH - AMT_HALF);
+    assert_eq!(token_amount(&result, 4), AMT_HALF);
+}
+
+// ============================================================================
+// INFUSE INSTRUCTION TESTS
+// ============================================================================
+
+/// Infuse moves USDC into the vault and credits the wallet 1:1
+#[test]
+fn infuse_deposits_usdc() {
+    infuse_ok(USDC_MINT);
+}
+
+/// Infuse moves USDT into the vault and credits the wallet 1:1
+#[test]
+fn infuse_deposits_usdt() {
+    infuse_ok(USDT_MINT);
+}
+
+/// Infuse fails for a mint without a vault (UnsupportedMint)
+#[test]
+fn infuse_fails_unsupported_mint() {
+    let signer = signer();
+    let mint = mint(Pubkey::new_unique().to_bytes(), BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, mint.pubkey.to_bytes(), 0);
+    let src = token_account(&signer.pubkey, &mint.pubkey, BAL_HIGH);
+    let vault = vault(&mint.pubkey, 0);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Infuse,
+            AMT_HALF,
+            infuse_metas(&signer, &wallet, &src, &mint, &vault)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            src.into(),
+            mint.into(),
+            vault.into(),
+            token_program().into()
+        ],
+        &[Check::err(TokamakError::UnsupportedMint.into())]
+    );
+}
+
+/// Infuse fails when the vault is not the mint's vault ATA
+#[test]
+fn infuse_fails_foreign_vault() {
+    let signer = signer();
+    let mint = mint(USDC_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDC_MINT, 0);
+    let src = token_account(&signer.pubkey, &mint.pubkey, BAL_HIGH);
+    let vault = token_account(&signer.pubkey, &mint.pubkey, 0);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Infuse,
+            AMT_HALF,
+            infuse_metas(&signer, &wallet, &src, &mint, &vault)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            src.into(),
+            mint.into(),
+            vault.into(),
+            token_program().into()
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// Infuse fails when the wallet is denominated in another mint (MintMismatch)
+#[test]
+fn infuse_fails_mint_mismatch() {
+    let signer = signer();
+    let mint = mint(USDC_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDT_MINT, 0);
+    let src = token_account(&signer.pubkey, &mint.pubkey, BAL_HIGH);
+    let vault = vault(&mint.pubkey, 0);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Infuse,
+            AMT_HALF,
+            infuse_metas(&signer, &wallet, &src, &mint, &vault)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            src.into(),
+            mint.into(),
+            vault.into(),
+            token_program().into()
+        ],
+        &[Check::err(TokamakError::MintMismatch.into())]
+    );
+}
+
+// ============================================================================
+// EXTRACT INSTRUCTION TESTS
+// ============================================================================
+
+/// Extract pays USDC out of the vault and debits the wallet 1:1
+#[test]
+fn extract_withdraws_usdc() {
+    extract_ok(USDC_MINT);
+}
+
+/// Extract pays USDT out of the vault and debits the wallet 1:1
+#[test]
+fn extract_withdraws_usdt() {
+    extract_ok(USDT_MINT);
+}
+
+/// Extract fails when the authority is not the vault authority PDA
+#[test]
+fn extract_fails_wrong_vault_authority() {
+    let signer = signer();
+    let mint = mint(USDT_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDT_MINT, BAL_HIGH);
+    let vault = vault(&mint.pubkey, BAL_HIGH);
+    let dst = token_account(&signer.pubkey, &mint.pubkey, 0);
+    let authority = vault_authority(&Pubkey::new_from_array(USDC_MINT));
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Extract,
+            AMT_HALF,
+            extract_metas(&signer, &wallet, &vault, &mint, &dst, &authority)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            vault.into(),
+            mint.into(),
+            dst.into(),
+            authority.into(),
+            token_program().into()
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)]
+    );
+}
+
+/// Extract fails when the wallet is denominated in another mint (MintMismatch)
+#[test]
+fn extract_fails_mint_mismatch() {
+    let signer = signer();
+    let mint = mint(USDC_MINT, BAL_MAX);
+    let wallet = wallet_for_mint(&signer.pubkey, USDT_MINT, BAL_HIGH);
+    let vault = vault(&mint.pubkey, BAL_HIGH);
+    let dst = token_account(&signer.pubkey, &mint.pubkey, 0);
+    let authority = vault_authority(&mint.pubkey);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Extract,
+            AMT_HALF,
+            extract_metas(&signer, &wallet, &vault, &mint, &dst, &authority)
+        ),
+        &[
+            signer.into(),
+            wallet.into(),
+            vault.into(),
+            mint.into(),
+            dst.into(),
+            authority.into(),
+            token_program().into()
+        ],
+        &[Check::err(TokamakError::MintMismatch.into())]
+    );
+}
+
+/// Vault addresses differ per mint
+#[test]
+fn vault_pdas_differ_by_mint() {
+    let usdc = Pubkey::new_from_array(USDC_MINT);
+    let usdt = Pubkey::new_from_array(USDT_MINT);
+    assert_ne!(derive_vault_authority(&usdc), derive_vault_authority(&usdt));
+    assert_ne!(derive_vault_ata(&usdc), derive_vault_ata(&usdt));
+}
+
+/// Program-side vault derivation matches the SDK and rejects other mints
+#[test]
+fn vault_derivation_matches_sdk() {
+    for mint in [USDC_MINT, USDT_MINT] {
+        let key = Pubkey::new_from_array(mint);
+        let vault = Vault::derive(&mint).unwrap();
+        let (authority, bump) = derive_vault_authority(&key);
+        assert_eq!((vault.authority, vault.bump), (authority.to_bytes(), bump));
+        assert_eq!(vault.ata, derive_vault_ata(&key).to_bytes());
+    }
+    assert!(Vault::derive(&Pubkey::new_unique().to_bytes()).is_none());
+}
+
+// ============================================================================
+// CUSTODY INVARIANT TESTS
+// ============================================================================
+
+/// Player action against a small world of one wallet, two charges and two edge elements
+#[derive(Clone, Debug)]
+enum Op {
+    Infuse(u64),
+    Extract(u64),
+    Charge(usize, u64),
+    Discharge(usize, u64),
+    Bind(usize, usize),
+    Unbind(usize, usize),
+    Vent(usize, usize, u64),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let amount = 1..BAL_MIN;
+    prop_oneof![
+        amount.clone().prop_map(Op::Infuse),
+        amount.clone().prop_map(Op::Extract),
+        (0..2usize, amount.clone()).prop_map(|(c, a)| Op::Charge(c, a)),
+        (0..2usize, amount.clone()).prop_map(|(c, a)| Op::Discharge(c, a)),
+        (0..2usize, 0..2usize).prop_map(|(c, e)| Op::Bind(c, e)),
+        (0..2usize, 0..2usize).prop_map(|(c, e)| Op::Unbind(c, e)),
+        (0..2usize, 0..2usize, amount).prop_map(|(c, e, a)| Op::Vent(c, e, a)),
+    ]
+}
+
+/// Accounts of the custody world; balances live in the mollusk account store
+struct World {
+    signer: AccountWithPubkey,
+    wallet: AccountWithPubkey,
+    src: AccountWithPubkey,
+    mint: AccountWithPubkey,
+    vault: AccountWithPubkey,
+    authority: AccountWithPubkey,
+    charges: [AccountWithPubkey; 2],
+    elements: [AccountWithPubkey; 2],
+    artefact: AccountWithPubkey,
+    board: AccountWithPubkey,
+}
+
+impl World {
+    fn new() -> Self {
+        let signer = signer();
+        let mint = mint(USDC_MINT, BAL_MAX);
+        Self {
+            wallet: wallet_for_mint(&signer.pubkey, USDC_MINT, 0),
+            src: token_account(&signer.pubkey, &mint.pubkey, BAL_MAX),
+            vault: vault(&mint.pubkey, 0),
+            authority: vault_authority(&mint.pubkey),
+            charges: [0, 1].map(|_| charge_for_mint(&signer.pubkey, USDC_MINT, 0)),
+            elements: [1, 2].map(element_edge),
+            artefact: artefact(elem_index(1)),
+            board: board_empty(),
+            signer,
+            mint,
+        }
+    }
+
+    fn accounts(&self) -> HashMap<Pubkey, Account> {
+        [
+            &self.signer,
+            &self.wallet,
+            &self.src,
+            &self.mint,
+            &self.vault,
+            &self.authority,
+            &self.charges[0],
+            &self.charges[1],
+            &self.elements[0],
+            &self.elements[1],
+            &self.artefact,
+            &self.board,
+        ]
+        .into_iter()
+        .map(|a| (a.pubkey, a.account.clone()))
+        .collect()
+    }
+
+    fn instruction(&self, op: &Op) -> Instruction {
+        let (signer, wallet) = (&self.signer, &self.wallet);
+        match *op {
+            Op::Infuse(a) => ix!(
+                TokamakInstruction::Infuse,
+                a,
+                infuse_metas(signer, wallet, &self.src, &self.mint, &self.vault)
+            ),
+            Op::Extract(a) => ix!(
+                TokamakInstruction::Extract,
+                a,
+                extract_metas(
+                    signer,
+                    wallet,
+                    &self.vault,
+                    &self.mint,
+                    &self.src,
+                    &self.authority
+                )
+            ),
+            Op::Charge(c, a) => ix!(
+                TokamakInstruction::Charge,
+                a,
+                metas!(signer, self.charges[c], wallet)
+            ),
+            Op::Discharge(c, a) => ix!(
+                TokamakInstruction::Discharge,
+                a,
+                metas!(signer, self.charges[c], wallet)
+            ),
+            Op::Bind(c, e) => ix!(
+                TokamakInstruction::Bind,
+                metas!(signer, self.charges[c], self.elements[e], self.board)
+            ),
+            Op::Unbind(c, e) => ix!(
+                TokamakInstruction::Unbind,
+                metas!(signer, self.charges[c], self.elements[e], self.board)
+            ),
+            Op::Vent(c, e, a) => ix!(
+                TokamakInstruction::Vent,
+                a,
+                metas!(signer, self.charges[c], self.elements[e])
+            ),
+        }
+    }
+}
+
+/// In-game value (wallet, charges, element pots, artefact pot, quantum pocket) and vault token balance
+fn custody(store: &HashMap<Pubkey, Account>, world: &World) -> (Gluon, u64) {
+    let body = |key: &Pubkey| &store[key];
+    let mut gluon = read::<Wallet>(body(&world.wallet.pubkey)).balance;
+    gluon += world
+        .charges
+        .iter()
+        .map(|c| read::<Charge>(body(&c.pubkey)).balance)
+        .sum::<Gluon>();
+    gluon += world
+        .elements
+        .iter()
+        .map(|e| read::<Element>(body(&e.pubkey)).pot)
+        .sum::<Gluon>();
+    gluon += read::<Artefact>(body(&world.artefact.pubkey)).pot;
+    gluon += read::<Board>(body(&world.board.pubkey)).quantum_pocket;
+
+    use solana_sdk::program_pack::Pack;
+    let vault = body(&world.vault.pubkey);
+    let tokens = spl_token_interface::state::Account::unpack(&vault.data)
+        .unwrap()
+        .amount;
+    (gluon, tokens)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// Every Gluon in the game is backed 1:1 by a token in the vault, after any action sequence
+    #[test]
+    fn custody_gluon_equals_vault_balance(ops in prop::collection::vec(op(), 1..24)) {
+        let world = World::new();
+        let context = mollusk().with_context(world.accounts());
+
+        for op in &ops {
+            context.process_instruction(&world.instruction(op));
+            let (gluon, tokens) = custody(&context.account_store.borrow(), &world);
+            prop_assert_eq!(gluon, Gluon(tokens), "custody broken after {:?}", op);
+        }
+    }
+}
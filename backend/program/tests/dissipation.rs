@@ -5,6 +5,7 @@ use common::*;
 
 use nucleus::board::Element;
 use nucleus::player::Charge;
+use nucleus::types::Gluon;
 
 // ============================================================================
 // VENT INSTRUCTION TESTS
@@ -32,8 +33,8 @@ fn vent_success_to_element_pot() {
 
     let c: Charge = result.get(1);
     let e: Element = result.get(2);
-    assert_eq!(c.balance, initial - vent_amount);
-    assert_eq!(e.pot, vent_amount);
+    assert_eq!(c.balance, Gluon(initial - vent_amount));
+    assert_eq!(e.pot, Gluon(vent_amount));
 }
 
 /// Vent fails when amount is zero
@@ -70,7 +71,7 @@ fn vent_fails_insufficient_balance() {
     );
 }
 
-/// Vent fails when element doesn't match charge index (Custom(32))
+/// Vent fails when element doesn't match charge index (ChargeNotInElement)
 #[test]
 fn vent_fails_wrong_target_element() {
     let signer = signer();
@@ -85,6 +86,6 @@ fn vent_fails_wrong_target_element() {
             metas!(signer, charge, elem)
         ),
         &[signer.into(), charge.into(), elem.into()],
-        &[Check::err(ProgramError::Custom(32))]
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
     );
 }
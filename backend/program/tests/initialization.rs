@@ -1,12 +1,37 @@
-//! Tests for InitWallet and InitCharge instructions (PDA account creation).
+//! Tests for InitWallet, InitCharge and genesis (InitBoard, InitElement, InitArtefact)
+//! instructions (PDA account creation).
 //!
-//! Note: These tests focus on account validation and error paths.
-//! Full CPI flow testing (CreateAccount) requires solana-program-test or similar.
+//! Genesis success tests pass the system program as a trailing account so the
+//! CreateAccount CPI resolves.
 
 mod common;
 use common::*;
 
-use nucleus::player::Wallet;
+use mollusk_svm::result::InstructionResult;
+use nucleus::{
+    board::{Artefact, Board, Element},
+    header::{AccountKind, Discriminated},
+    player::Wallet,
+    types::{ElementIndex, Gluon, Q824},
+};
+
+/// Asserts the account at `idx` was created at `address`, program-owned and sized for `T`,
+/// behind `T`'s header carrying the canonical `bump`
+fn assert_created<T: Discriminated>(
+    result: &InstructionResult,
+    idx: usize,
+    address: Pubkey,
+    bump: u8,
+) {
+    let (key, account) = &result.resulting_accounts[idx];
+    assert_eq!(*key, address);
+    assert_eq!(account.owner, PROGRAM_ID);
+    assert_eq!(account.data.len(), T::SPACE);
+    let header = read_header(account);
+    assert_eq!(header.discriminator, T::KIND as u8);
+    assert_eq!(header.version, T::VERSION);
+    assert_eq!(header.bump, bump);
+}
 
 // ============================================================================
 // INITWALLET INSTRUCTION TESTS
@@ -40,6 +65,34 @@ fn init_wallet_fails_missing_signature() {
     );
 }
 
+/// InitWallet fails for a mint the program does not custody
+#[test]
+fn init_wallet_fails_unsupported_mint() {
+    let signer = signer();
+    let signer_key = signer.pubkey;
+    let mint_key = Pubkey::new_unique();
+    let mint = AccountWithPubkey {
+        pubkey: mint_key,
+        account: Account::default(),
+    };
+    let (wallet_pda, bump) = derive_wallet_pda(&signer_key, &mint_key);
+    let wallet = pda_account(wallet_pda);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::InitWallet,
+            bump,
+            vec![
+                AccountMeta::new(signer_key, true),
+                AccountMeta::new(wallet_pda, false),
+                AccountMeta::new_readonly(mint_key, false),
+            ]
+        ),
+        &[signer.into(), wallet.into(), mint.into()],
+        &[Check::err(TokamakError::UnsupportedMint.into())]
+    );
+}
+
 // ============================================================================
 // PDA DERIVATION TESTS
 // ============================================================================
@@ -86,7 +139,7 @@ fn charge_pda_differs_by_index() {
 // ACCOUNT VALIDATION TESTS
 // ============================================================================
 
-/// InitCharge fails when wallet is not owned by the program
+/// InitCharge fails when the wallet account carries another account kind's header
 #[test]
 fn init_charge_fails_invalid_wallet_data() {
     let signer = signer();
@@ -95,15 +148,18 @@ fn init_charge_fails_invalid_wallet_data() {
 
     let (wallet_pda, _) = derive_wallet_pda(&signer_key, &mint);
     let wallet_data = Wallet {
-        balance: 0,
+        balance: Gluon::ZERO,
         authority: signer_key.to_bytes(),
         mint: mint.to_bytes(),
         charges: 0,
-        _pad: 0,
+        open: 0,
     };
+    // Wallet body behind a Charge header
+    let mut data = stored(&wallet_data);
+    data[0] = AccountKind::Charge as u8;
     let wallet = AccountWithPubkey {
         pubkey: wallet_pda,
-        account: program_account(bytes_of(&wallet_data).to_vec()),
+        account: program_account(data),
     };
 
     let (charge_pda, bump) = derive_charge_pda(&signer_key, &mint.to_bytes(), 0);
@@ -116,7 +172,7 @@ fn init_charge_fails_invalid_wallet_data() {
             metas!(signer, charge, wallet)
         ),
         &[signer.into(), charge.into(), wallet.into()],
-        &[Check::err(ProgramError::InvalidAccountData)]
+        &[Check::err(TokamakError::WrongAccountKind.into())]
     );
 }
 
@@ -129,14 +185,14 @@ fn init_charge_fails_invalid_wallet_owner() {
 
     let (wallet_pda, _) = derive_wallet_pda(&signer_key, &mint);
     let wallet_data = Wallet {
-        balance: 0,
+        balance: Gluon::ZERO,
         authority: signer_key.to_bytes(),
         mint: mint.to_bytes(),
         charges: 0,
-        _pad: 0,
+        open: 0,
     };
     // Create wallet with wrong owner (system program instead of our program)
-    let mut wallet_account = program_account(bytes_of(&wallet_data).to_vec());
+    let mut wallet_account = program_account(stored(&wallet_data));
     wallet_account.owner = SYSTEM_PROGRAM_ID;
 
     let wallet = AccountWithPubkey {
@@ -154,6 +210,203 @@ fn init_charge_fails_invalid_wallet_owner() {
             metas!(signer, charge, wallet)
         ),
         &[signer.into(), charge.into(), wallet.into()],
-        &[Check::err(ProgramError::InvalidAccountData)]
+        &[Check::err(ProgramError::InvalidAccountOwner)]
     );
 }
+
+// ============================================================================
+// GENESIS INSTRUCTION TESTS
+// ============================================================================
+
+/// InitBoard creates the zeroed board at its canonical PDA
+#[test]
+fn init_board_success() {
+    let signer = signer();
+    let (board_pda, bump) = derive_board_pda();
+    let board = pda_account(board_pda);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::InitBoard,
+            with_system_program(metas!(signer, board))
+        ),
+        &[signer.into(), board.into(), system_program().into()],
+        &[Check::success()]
+    );
+
+    assert_created::<Board>(&result, 1, board_pda, bump);
+    let b: Board = result.get(1);
+    assert_eq!(b.tvl, Gluon::ZERO);
+    assert_eq!(b.quantum_pocket, Gluon::ZERO);
+    assert_eq!(b.charge_count, 0);
+    assert_eq!(b.quantum_index, 0);
+}
+
+/// InitElement creates the genesis element at its canonical PDA
+#[test]
+fn init_element_success() {
+    for atomic in [1u8, 26] {
+        let signer = signer();
+        let (element_pda, bump) = derive_element_pda(atomic);
+        let element = pda_account(element_pda);
+
+        let result = test_run!(
+            ix!(
+                TokamakInstruction::InitElement,
+                atomic,
+                with_system_program(metas!(signer, element))
+            ),
+            &[signer.into(), element.into(), system_program().into()],
+            &[Check::success()]
+        );
+
+        assert_created::<Element>(&result, 1, element_pda, bump);
+        let e: Element = result.get(1);
+        let genesis = Element::genesis(atomic as u64).unwrap();
+        assert_eq!(e.index, ElementIndex::new(atomic as u64, 0));
+        assert_eq!(e.pot, Gluon::ZERO);
+        assert_eq!(e.charges, 0);
+        assert_eq!(e.curve.capacity, genesis.curve.capacity);
+        assert_eq!(e.curve.tvl, Gluon::ZERO);
+        assert_eq!(e.curve.shares, Q824::ZERO);
+        assert_eq!(bytes_of(&e), bytes_of(&genesis));
+    }
+}
+
+/// InitArtefact creates the empty artefact for the element's current generation
+#[test]
+fn init_artefact_success() {
+    let signer = signer();
+    let index = ElementIndex::new(3, 0);
+    let element = canonical_account(&Element::genesis(3).unwrap());
+    let (artefact_pda, bump) = derive_artefact_pda(index);
+    let artefact = pda_account(artefact_pda);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::InitArtefact,
+            with_system_program(metas!(signer, element, artefact))
+        ),
+        &[
+            signer.into(),
+            element.into(),
+            artefact.into(),
+            system_program().into()
+        ],
+        &[Check::success()]
+    );
+
+    assert_created::<Artefact>(&result, 2, artefact_pda, bump);
+    let a: Artefact = result.get(2);
+    assert_eq!(a.index, index);
+    assert_eq!(a.pot, Gluon::ZERO);
+    assert_eq!(a.tvl, Gluon::ZERO);
+    assert_eq!(a.shares, Q824::ZERO);
+    assert_eq!(a.charges, 0);
+}
+
+/// InitBoard fails when payer is not marked as signer
+#[test]
+fn init_board_fails_missing_signature() {
+    let signer = signer();
+    let (board_pda, _) = derive_board_pda();
+    let board = pda_account(board_pda);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::InitBoard,
+            vec![
+                AccountMeta::new(signer.pubkey, false), // Not a signer!
+                AccountMeta::new(board_pda, false),
+            ]
+        ),
+        &[signer.into(), board.into()],
+        &[Check::err(ProgramError::MissingRequiredSignature)]
+    );
+}
+
+/// InitBoard fails when the account is not the canonical board PDA
+#[test]
+fn init_board_fails_wrong_pda() {
+    let signer = signer();
+    let board = pda_account(Pubkey::new_unique());
+
+    test_run!(
+        ix!(TokamakInstruction::InitBoard, metas!(signer, board)),
+        &[signer.into(), board.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// InitElement rejects atomic numbers off the board (0 and > 26)
+#[test]
+fn init_element_fails_invalid_atomic() {
+    for atomic in [0u8, 27] {
+        let signer = signer();
+        let (element_pda, _) = derive_element_pda(atomic);
+        let element = pda_account(element_pda);
+
+        test_run!(
+            ix!(
+                TokamakInstruction::InitElement,
+                atomic,
+                metas!(signer, element)
+            ),
+            &[signer.into(), element.into()],
+            &[Check::err(ProgramError::InvalidArgument)]
+        );
+    }
+}
+
+/// InitElement fails when the account is another element's PDA
+#[test]
+fn init_element_fails_wrong_pda() {
+    let signer = signer();
+    let (element_pda, _) = derive_element_pda(2);
+    let element = pda_account(element_pda);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::InitElement,
+            1u8,
+            metas!(signer, element)
+        ),
+        &[signer.into(), element.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// InitArtefact fails when the account is not the PDA of the element's current index
+#[test]
+fn init_artefact_fails_stale_generation() {
+    let signer = signer();
+    let element = element_edge(3);
+    // Fixture elements are at generation 1; generation 0 is stale.
+    let (artefact_pda, _) = derive_artefact_pda(ElementIndex::new(3, 0));
+    let artefact = pda_account(artefact_pda);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::InitArtefact,
+            metas!(signer, element, artefact)
+        ),
+        &[signer.into(), element.into(), artefact.into()],
+        &[Check::err(ProgramError::InvalidSeeds)]
+    );
+}
+
+/// Element PDAs are distinct per atomic number
+#[test]
+fn element_pda_differs_by_atomic() {
+    let (h, _) = derive_element_pda(1);
+    let (fe, _) = derive_element_pda(26);
+    assert_ne!(h, fe, "PDA should differ by atomic number");
+}
+
+/// Artefact PDAs are distinct per generation of the same element
+#[test]
+fn artefact_pda_differs_by_generation() {
+    let (gen0, _) = derive_artefact_pda(ElementIndex::new(26, 0));
+    let (gen1, _) = derive_artefact_pda(ElementIndex::new(26, 1));
+    assert_ne!(gen0, gen1, "PDA should differ by generation");
+}
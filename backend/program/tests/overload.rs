@@ -3,18 +3,27 @@
 mod common;
 use common::*;
 
+use nucleus::{
+    board::{Artefact, Board, Element},
+    consts::MAX_ATOMIC_NUMBER,
+    guards::Guards,
+    player::Charge,
+    quantum,
+    types::{ElementIndex, Gluon, Q824},
+};
+
 // ============================================================================
 // OVERLOAD INSTRUCTION TESTS
 // ============================================================================
 
-/// Overload fails when element is not at max saturation (Custom(1))
+/// Overload fails when element is not at max saturation (BelowOverloadThreshold)
 #[test]
 fn overload_fails_below_max_saturation() {
     let signer = signer();
     let elem_index = elem_index(3);
     let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
-    let elem = element_with_shares_at(3, EDGE_COORD, MAX_SATURATION - 1, AMT_HALF, 0);
-    let art = artefact(0);
+    let elem = element_with_shares_at(3, EDGE_COORD, MAX_SATURATION.0 - 1, AMT_HALF, 0);
+    let art = artefact(elem_index);
     let board = board_with_count(5);
 
     test_run!(
@@ -29,6 +38,463 @@ fn overload_fails_below_max_saturation() {
             art.into(),
             board.into()
         ],
-        &[Check::err(ProgramError::Custom(1))]
+        &[Check::err(TokamakError::BelowOverloadThreshold.into())]
+    );
+}
+
+/// Overload fails when the artefact belongs to another generation (ArtefactMismatch)
+#[test]
+fn overload_fails_stale_artefact() {
+    let signer = signer();
+    let elem_index = elem_index(3);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    let elem = element_with_shares_at(3, EDGE_COORD, MAX_SATURATION.0, AMT_HALF, 0);
+    let art = artefact(ElementIndex::new(3, 0));
+    let board = board_with_count(5);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::ArtefactMismatch.into())]
+    );
+}
+
+/// Overload fails when the triggering charge is not bound to the target (ChargeNotInElement)
+#[test]
+fn overload_fails_charge_not_in_element() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(2));
+    let elem = element_overloaded(3, AMT_HALF, BAL_MIN, 1);
+    let art = artefact(elem_index(3));
+    let board = board_with_count(5);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
+    );
+}
+
+/// Overload ejects the other charges into the artefact; board totals stay exact
+#[test]
+fn overload_ejects_other_charges_into_artefact() {
+    let signer = signer();
+    let elem_index = elem_index(3);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    // Trigger plus two ejected charges holding BAL_MIN each (no residue)
+    let elem = element_overloaded(3, AMT_HALF, 3 * BAL_MIN, 3);
+    let art = artefact(elem_index);
+    let board = board(BAL_HIGH, 5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let e: Element = result.get(2);
+    let a: Artefact = result.get(3);
+    let b: Board = result.get(4);
+    assert_eq!((a.tvl, a.charges), (Gluon(2 * BAL_MIN), 2));
+    assert_eq!((e.curve.tvl, e.charges), (Gluon(BAL_MIN), 1));
+    assert_eq!(e.index.generation(), elem_index.generation() + 1);
+    // Trigger holds no share: no reward, so the board total is unchanged
+    assert_eq!((b.tvl, b.charge_count), (Gluon(BAL_HIGH), 5));
+}
+
+/// Fe overload diverts the quantum yield from its pot into the board's pocket
+#[test]
+fn overload_fe_injects_into_quantum_pocket() {
+    let signer = signer();
+    let elem_index = elem_index(MAX_ATOMIC_NUMBER);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    let elem = element_overloaded(MAX_ATOMIC_NUMBER, BAL_MIN, BAL_MIN, 1);
+    let art = artefact(elem_index);
+    let board = board_with_count(5);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let injected = quantum::injection(Gluon(BAL_MIN));
+    let a: Artefact = result.get(3);
+    let b: Board = result.get(4);
+    assert_eq!(a.pot, Gluon(BAL_MIN) - injected);
+    assert_eq!(b.quantum_pocket, injected);
+    assert_eq!(b.quantum_index, 0);
+}
+
+/// Overload at the next depth adds its pocket unlock to the artefact pot
+#[test]
+fn overload_next_depth_unlocks_quantum_pocket() {
+    let signer = signer();
+    let elem_index = elem_index(3);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    let elem = element_overloaded(3, AMT_HALF, BAL_MIN, 1);
+    let art = artefact(elem_index);
+    let board = board_with_pocket(BAL_HIGH, 5, BAL_MIN, 2);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let unlocked = quantum::unlock_amount(Gluon(BAL_MIN), 2);
+    let a: Artefact = result.get(3);
+    let b: Board = result.get(4);
+    assert_eq!(a.pot, Gluon(AMT_HALF) + unlocked);
+    assert_eq!(b.quantum_pocket, Gluon(BAL_MIN) - unlocked);
+    assert_eq!(b.quantum_index, 3);
+}
+
+/// Overload out of schedule order leaves the pocket untouched
+#[test]
+fn overload_out_of_order_skips_quantum_pocket() {
+    let signer = signer();
+    let elem_index = elem_index(3);
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index);
+    let elem = element_overloaded(3, AMT_HALF, BAL_MIN, 1);
+    let art = artefact(elem_index);
+    let board = board_with_pocket(BAL_HIGH, 5, BAL_MIN, 0);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Overload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let a: Artefact = result.get(3);
+    let b: Board = result.get(4);
+    assert_eq!(a.pot, Gluon(AMT_HALF));
+    assert_eq!(b.quantum_pocket, Gluon(BAL_MIN));
+    assert_eq!(b.quantum_index, 0);
+}
+
+// ============================================================================
+// COMPOUND INSTRUCTION TESTS
+// ============================================================================
+
+/// BindAndOverload binds into an edge element, saturating it, and resets it in one call
+#[test]
+fn bind_and_overload_resets_element() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_with_capacity(1, EDGE_COORD, BAL_MIN);
+    let art = artefact(elem_index(1));
+    let board = board_empty();
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::BindAndOverload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let e: Element = result.get(2);
+    let a: Artefact = result.get(3);
+    let b: Board = result.get(4);
+    assert_eq!(e.index.generation(), elem_index(1).generation() + 1);
+    assert_eq!((c.index, e.charges), (e.index, 1));
+    // Sole shareholder: the bind fee comes straight back as the reset reward
+    assert_eq!(
+        (c.balance, a.pot, a.charges),
+        (Gluon(BAL_HIGH), Gluon::ZERO, 0)
+    );
+    assert_eq!((b.tvl, b.charge_count), (Gluon(BAL_HIGH), 1));
+}
+
+/// BindAndOverload fails as a whole when the bind leaves the element below the threshold
+#[test]
+fn bind_and_overload_fails_below_threshold() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_edge(1);
+    let art = artefact(elem_index(1));
+    let board = board_empty();
+
+    test_run!(
+        ix!(
+            TokamakInstruction::BindAndOverload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::BelowOverloadThreshold.into())]
+    );
+}
+
+/// BindAndOverload fails before binding when the element already reset (race lost)
+#[test]
+fn bind_and_overload_fails_lost_race() {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_with_capacity(1, EDGE_COORD, BAL_MIN);
+    let art = artefact(ElementIndex::new(1, 0));
+    let board = board_empty();
+
+    test_run!(
+        ix!(
+            TokamakInstruction::BindAndOverload,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::ArtefactMismatch.into())]
+    );
+}
+
+/// RebindAndOverload rebinds into an adjacent element, saturating it, and resets it
+#[test]
+fn rebind_and_overload_resets_destination() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let dst = element_with_capacity(2, dst_coord, BAL_MIN);
+    let art = artefact(elem_index(2));
+    let board = board(BAL_HIGH, 1);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::RebindAndOverload,
+            metas!(signer, charge, src, dst, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            dst.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let s: Element = result.get(2);
+    let d: Element = result.get(3);
+    assert_eq!(s.charges, 0);
+    assert_eq!(d.index.generation(), elem_index(2).generation() + 1);
+    assert_eq!((c.index, d.charges), (d.index, 1));
+}
+
+/// RebindAndOverload fails when the same element is passed as source and destination
+#[test]
+fn rebind_and_overload_fails_same_element() {
+    let signer = signer();
+    let (src_coord, _dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let art = artefact(elem_index(1));
+    let board = board(BAL_HIGH, 1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::RebindAndOverload,
+            metas!(signer, charge, src, src, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(ProgramError::AccountBorrowFailed)]
+    );
+}
+
+/// RebindAndOverload fails as a whole when the destination stays below the threshold
+#[test]
+fn rebind_and_overload_fails_below_threshold() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let dst = element_at(2, dst_coord);
+    let art = artefact(elem_index(2));
+    let board = board(BAL_HIGH, 1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::RebindAndOverload,
+            metas!(signer, charge, src, dst, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            dst.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::BelowOverloadThreshold.into())]
+    );
+}
+
+/// Run a saturating BindAndOverload into H with `guards`
+fn run_guarded_bind_and_overload(guards: Guards, check: Check) {
+    let signer = signer();
+    let charge = charge_high(&signer.pubkey);
+    let elem = element_with_capacity(1, EDGE_COORD, BAL_MIN);
+    let art = artefact(elem_index(1));
+    let board = board_empty();
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::BindAndOverload,
+            guards,
+            metas!(signer, charge, elem, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            elem.into(),
+            art.into(),
+            board.into()
+        ],
+        &[check]
+    );
+}
+
+/// BindAndOverload within its guards succeeds like an unguarded one
+#[test]
+fn bind_and_overload_guards_within_limits() {
+    let guards = Guards {
+        max_fee: Gluon(BAL_HIGH),
+        deadline_slot: 2000,
+        min_share: Q824(1),
+        _pad: 0,
+    };
+    run_guarded_bind_and_overload(guards, Check::success());
+}
+
+/// BindAndOverload fails when the bind fee exceeds `max_fee` (FeeAboveLimit)
+#[test]
+fn bind_and_overload_guards_reject_fee_above_limit() {
+    let guards = Guards {
+        max_fee: Gluon(1),
+        ..Guards::NONE
+    };
+    run_guarded_bind_and_overload(guards, Check::err(TokamakError::FeeAboveLimit.into()));
+}
+
+/// BindAndOverload fails after `deadline_slot` (DeadlinePassed)
+#[test]
+fn bind_and_overload_guards_reject_past_deadline() {
+    let guards = Guards {
+        deadline_slot: 1999,
+        ..Guards::NONE
+    };
+    run_guarded_bind_and_overload(guards, Check::err(TokamakError::DeadlinePassed.into()));
+}
+
+/// RebindAndOverload fails when the share bought in the destination falls short of
+/// `min_share` (ShareBelowLimit)
+#[test]
+fn rebind_and_overload_guards_reject_share_below_limit() {
+    let signer = signer();
+    let (src_coord, dst_coord) = adjacent_coords();
+    let (src, share) = element_bound_at(1, src_coord, BAL_HIGH);
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), share);
+    let dst = element_with_capacity(2, dst_coord, BAL_MIN);
+    let art = artefact(elem_index(2));
+    let board = board(BAL_HIGH, 1);
+    let guards = Guards {
+        min_share: Q824(u32::MAX),
+        ..Guards::NONE
+    };
+
+    test_run!(
+        ix_pod!(
+            TokamakInstruction::RebindAndOverload,
+            guards,
+            metas!(signer, charge, src, dst, art, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            src.into(),
+            dst.into(),
+            art.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::ShareBelowLimit.into())]
     );
 }
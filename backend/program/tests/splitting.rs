@@ -0,0 +1,254 @@
+//! Tests for Split (part of a bound charge into a new charge PDA) and Merge (two bound charges
+//! into one) instructions.
+
+mod common;
+use common::*;
+
+use nucleus::{
+    board::{Board, Element},
+    player::{Charge, Wallet},
+    types::{Gluon, Q824},
+};
+
+/// Run Split of `amount` from `charge` on element 1, paired with a fresh wallet
+fn run_split(signer: AccountWithPubkey, charge: AccountWithPubkey, amount: u64, check: Check) {
+    let wallet = wallet_min(&signer.pubkey);
+    let (pda, bump) = derive_charge_pda(&signer.pubkey, &[0u8; 32], 0);
+    let part = pda_account(pda);
+    let elem = element_edge_shared(1, SHARE_ONE);
+    let board = board_with_count(1);
+
+    test_run!(
+        split_ix(
+            amount,
+            bump,
+            metas!(signer, charge, wallet, part, elem, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            wallet.into(),
+            part.into(),
+            elem.into(),
+            board.into()
+        ],
+        &[check]
+    );
+}
+
+// ============================================================================
+// SPLIT INSTRUCTION TESTS
+// ============================================================================
+
+/// Split moves part of the balance and a proportional share into the wallet's next charge,
+/// bound to the same element; the curve does not move, only the counts grow
+#[test]
+fn split_success_creates_bound_part() {
+    let signer = signer();
+    let signer_key = signer.pubkey;
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), SHARE_TWO);
+    let wallet = wallet_min(&signer.pubkey);
+    let (pda, bump) = derive_charge_pda(&signer.pubkey, &[0u8; 32], 0);
+    let part = pda_account(pda);
+    let elem = element_overloaded(1, 0, BAL_HIGH, 1);
+    let board = board_with_count(1);
+
+    let result = test_run!(
+        split_ix(
+            BAL_HIGH / 4,
+            bump,
+            with_system_program(metas!(signer, charge, wallet, part, elem, board))
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            wallet.into(),
+            part.into(),
+            elem.into(),
+            board.into(),
+            system_program().into()
+        ],
+        &[Check::success()]
+    );
+
+    // A quarter of the balance carries a quarter of the share
+    let c: Charge = result.get(1);
+    let p: Charge = result.get(3);
+    assert_eq!(c.balance, Gluon(BAL_HIGH - BAL_HIGH / 4));
+    assert_eq!(p.balance, Gluon(BAL_HIGH / 4));
+    assert_eq!(c.share, Q824(SHARE_TWO - SHARE_TWO / 4));
+    assert_eq!(p.share, Q824(SHARE_TWO / 4));
+    assert_eq!(p.index, c.index);
+    assert_eq!(p.authority, signer_key.to_bytes());
+    assert_eq!(result.resulting_accounts[3].1.owner, PROGRAM_ID);
+    let w: Wallet = result.get(2);
+    assert_eq!((w.charges, w.open), (1, 1));
+    let e: Element = result.get(4);
+    assert_eq!(e.charges, 2);
+    assert_eq!(e.curve.shares, Q824(SHARE_FOUR));
+    assert_eq!(e.curve.tvl, Gluon(BAL_HIGH));
+    let b: Board = result.get(5);
+    assert_eq!(b.charge_count, 2);
+}
+
+/// Split must reject zero amount
+#[test]
+fn split_fails_zero_amount() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+
+    run_split(signer, charge, 0, Check::err(ProgramError::InvalidArgument));
+}
+
+/// Split must leave a balance on both parts
+#[test]
+fn split_fails_whole_balance() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+
+    run_split(
+        signer,
+        charge,
+        BAL_HIGH,
+        Check::err(ProgramError::InvalidArgument),
+    );
+}
+
+/// Split fails when the charge is not bound to the target element
+#[test]
+fn split_fails_charge_not_in_element() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(2));
+
+    run_split(
+        signer,
+        charge,
+        AMT_HALF,
+        Check::err(TokamakError::ChargeNotInElement.into()),
+    );
+}
+
+/// Split fails when the charge belongs to another player than the wallet
+#[test]
+fn split_fails_foreign_charge() {
+    let signer = signer();
+    let charge = charge_shared(&Pubkey::new_unique(), elem_index(1));
+
+    run_split(
+        signer,
+        charge,
+        AMT_HALF,
+        Check::err(ProgramError::IncorrectAuthority),
+    );
+}
+
+// ============================================================================
+// MERGE INSTRUCTION TESTS
+// ============================================================================
+
+/// Merge adds balance and share into the first charge and leaves the second empty and unbound
+#[test]
+fn merge_success_folds_charges() {
+    let signer = signer();
+    let charge = charge_with_share(&signer.pubkey, BAL_HIGH, elem_index(1), SHARE_ONE);
+    let other = charge_with_share(&signer.pubkey, BAL_MIN, elem_index(1), SHARE_ONE);
+    let elem = element_overloaded(1, 0, BAL_HIGH + BAL_MIN, 2);
+    let board = board_with_count(2);
+
+    let result = test_run!(
+        ix!(
+            TokamakInstruction::Merge,
+            metas!(signer, charge, other, elem, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            other.into(),
+            elem.into(),
+            board.into()
+        ],
+        &[Check::success()]
+    );
+
+    let c: Charge = result.get(1);
+    let o: Charge = result.get(2);
+    assert_eq!(c.balance, Gluon(BAL_HIGH + BAL_MIN));
+    assert_eq!(c.share, Q824(SHARE_TWO));
+    assert_eq!((o.balance, o.share), (Gluon::ZERO, Q824::ZERO));
+    assert!(o.index.is_zero());
+    // The curve does not move; only the counts drop
+    let e: Element = result.get(3);
+    assert_eq!(e.charges, 1);
+    assert_eq!(e.curve.shares, Q824(SHARE_FOUR));
+    assert_eq!(e.curve.tvl, Gluon(BAL_HIGH + BAL_MIN));
+    let b: Board = result.get(4);
+    assert_eq!(b.charge_count, 1);
+}
+
+/// Merge fails when the same charge is passed twice
+#[test]
+fn merge_fails_same_charge() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let elem = element_overloaded(1, 0, BAL_HIGH, 1);
+    let board = board_with_count(1);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Merge,
+            metas!(signer, charge, charge, elem, board)
+        ),
+        &[signer.into(), charge.into(), elem.into(), board.into()],
+        &[Check::err(ProgramError::AccountBorrowFailed)]
+    );
+}
+
+/// Merge fails when the second charge is bound elsewhere
+#[test]
+fn merge_fails_charge_not_in_element() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let other = charge_shared(&signer.pubkey, elem_index(2));
+    let elem = element_overloaded(1, 0, BAL_HIGH, 1);
+    let board = board_with_count(2);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Merge,
+            metas!(signer, charge, other, elem, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            other.into(),
+            elem.into(),
+            board.into()
+        ],
+        &[Check::err(TokamakError::ChargeNotInElement.into())]
+    );
+}
+
+/// Merge fails when the second charge belongs to another player
+#[test]
+fn merge_fails_foreign_charge() {
+    let signer = signer();
+    let charge = charge_shared(&signer.pubkey, elem_index(1));
+    let other = charge_shared(&Pubkey::new_unique(), elem_index(1));
+    let elem = element_overloaded(1, 0, 2 * BAL_HIGH, 2);
+    let board = board_with_count(2);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Merge,
+            metas!(signer, charge, other, elem, board)
+        ),
+        &[
+            signer.into(),
+            charge.into(),
+            other.into(),
+            elem.into(),
+            board.into()
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)]
+    );
+}
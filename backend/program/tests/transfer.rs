@@ -3,7 +3,8 @@
 mod common;
 use common::*;
 
-use nucleus::player::Wallet;
+use nucleus::{player::Wallet, types::Gluon};
+use tokamak_program::addresses::USDC_MINT;
 
 // ============================================================================
 // CHARGE INSTRUCTION TESTS
@@ -28,7 +29,7 @@ fn charge_success_transfer_from_wallet_to_charge() {
 
     assert_charge_bal(&result, 1, AMT_HALF);
     let w: Wallet = result.get(2);
-    assert_eq!(w.balance, AMT_HALF);
+    assert_eq!(w.balance, Gluon(AMT_HALF));
 }
 
 /// Charge instruction must reject zero amount
@@ -86,6 +87,43 @@ fn charge_fails_wrong_authority() {
     );
 }
 
+/// Charge fails when the charge belongs to another player
+#[test]
+fn charge_fails_foreign_charge() {
+    let signer = signer();
+    let other = Pubkey::new_unique();
+    let charge = charge_min(&other);
+    let wallet = wallet_min(&signer.pubkey);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Charge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::err(ProgramError::IncorrectAuthority)]
+    );
+}
+
+/// Charge fails when charge and wallet are denominated in different mints (MintMismatch)
+#[test]
+fn charge_fails_mint_mismatch() {
+    let signer = signer();
+    let charge = charge_min(&signer.pubkey);
+    let wallet = wallet_for_mint(&signer.pubkey, USDC_MINT, BAL_MIN);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Charge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::err(TokamakError::MintMismatch.into())]
+    );
+}
+
 /// Charge adds to existing charge balance
 #[test]
 fn charge_accumulates_to_existing_balance() {
@@ -106,6 +144,24 @@ fn charge_accumulates_to_existing_balance() {
     assert_charge_bal(&result, 1, 300_000);
 }
 
+/// Charge fails when charge is bound to an element (ChargeStillBound)
+#[test]
+fn charge_fails_charge_is_bound() {
+    let signer = signer();
+    let charge = charge(&signer.pubkey, BAL_MIN, elem_index(1));
+    let wallet = wallet_min(&signer.pubkey);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Charge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::err(TokamakError::ChargeStillBound.into())]
+    );
+}
+
 // ============================================================================
 // DISCHARGE INSTRUCTION TESTS
 // ============================================================================
@@ -129,7 +185,7 @@ fn discharge_success_transfer_from_charge_to_wallet() {
 
     assert_charge_bal(&result, 1, AMT_HALF);
     let w: Wallet = result.get(2);
-    assert_eq!(w.balance, AMT_HALF);
+    assert_eq!(w.balance, Gluon(AMT_HALF));
 }
 
 /// Discharge instruction must reject zero amount
@@ -168,7 +224,7 @@ fn discharge_fails_insufficient_charge_balance() {
     );
 }
 
-/// Discharge fails when charge is bound to an element (Custom(50))
+/// Discharge fails when charge is bound to an element (ChargeStillBound)
 #[test]
 fn discharge_fails_charge_is_bound() {
     let signer = signer();
@@ -183,7 +239,7 @@ fn discharge_fails_charge_is_bound() {
             metas!(signer, charge, wallet)
         ),
         &[signer.into(), charge.into(), wallet.into()],
-        &[Check::err(ProgramError::Custom(50))]
+        &[Check::err(TokamakError::ChargeStillBound.into())]
     );
 }
 
@@ -205,3 +261,22 @@ fn discharge_fails_wrong_authority() {
         &[Check::err(ProgramError::IncorrectAuthority)]
     );
 }
+
+/// Discharge fails when the charge belongs to another player (cannot drain foreign charges)
+#[test]
+fn discharge_fails_foreign_charge() {
+    let signer = signer();
+    let other = Pubkey::new_unique();
+    let charge = charge(&other, BAL_MIN, ZERO_INDEX);
+    let wallet = wallet(&signer.pubkey, 0);
+
+    test_run!(
+        ix!(
+            TokamakInstruction::Discharge,
+            AMT_HALF,
+            metas!(signer, charge, wallet)
+        ),
+        &[signer.into(), charge.into(), wallet.into()],
+        &[Check::err(ProgramError::IncorrectAuthority)]
+    );
+}
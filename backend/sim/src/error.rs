@@ -0,0 +1,52 @@
+//! Typed failures of a simulated instruction.
+
+use core::fmt;
+
+use nucleus::error::TokamakError;
+
+/// Why an instruction was rejected. Game rules map 1:1 onto [`TokamakError`]; the other
+/// variants mirror the `ProgramError`s the processors return for the same condition.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SimError {
+    /// Game rule violation, identical to the on-chain custom error.
+    Game(TokamakError),
+    /// Referenced account has not been initialized.
+    UninitializedAccount,
+    /// Account to initialize already exists.
+    AccountAlreadyInitialized,
+    /// The same element was passed as both source and destination.
+    AccountBorrowFailed,
+    /// Signer is not the account's authority.
+    IncorrectAuthority,
+    /// Zero amount or an atomic number off the board.
+    InvalidArgument,
+    /// Balance too small for the requested amount.
+    InsufficientFunds,
+    /// Balance too small to cover an amount or fee.
+    ArithmeticOverflow,
+}
+
+impl From<TokamakError> for SimError {
+    fn from(err: TokamakError) -> Self {
+        Self::Game(err)
+    }
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use SimError::*;
+        let msg = match self {
+            Game(err) => return err.fmt(f),
+            UninitializedAccount => "account is not initialized",
+            AccountAlreadyInitialized => "account is already initialized",
+            AccountBorrowFailed => "same account passed twice",
+            IncorrectAuthority => "signer is not the account authority",
+            InvalidArgument => "invalid instruction argument",
+            InsufficientFunds => "insufficient funds",
+            ArithmeticOverflow => "arithmetic overflow",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for SimError {}
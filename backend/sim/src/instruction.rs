@@ -0,0 +1,167 @@
+//! Simulated instructions: one variant per `TokamakInstruction`, naming accounts by identity.
+//!
+//! Where the program takes an account, the simulator takes the key it is stored under:
+//! players by address, wallets by [`WalletKey`], charges by [`ChargeKey`], elements by
+//! atomic number (always the live generation) and artefacts by [`ElementIndex`].
+
+use nucleus::{
+    guards::Guards,
+    topology::DIAMETER,
+    types::{AddressBytes, ElementIndex, Gluon},
+};
+
+use crate::state::{ChargeKey, WalletKey};
+
+/// A game instruction with its accounts and data, as submitted by `signer`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    /// Initialize the next charge of `wallet` (id = `wallet.charges`).
+    InitCharge {
+        signer: AddressBytes,
+        wallet: WalletKey,
+    },
+    /// Initialize the signer's wallet for a supported `mint`.
+    InitWallet {
+        signer: AddressBytes,
+        mint: AddressBytes,
+    },
+    /// Move `amount` from wallet to charge.
+    Charge {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        amount: Gluon,
+    },
+    /// Claim an ejected charge's reward from `artefact` and leave the board.
+    Claim {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        artefact: ElementIndex,
+    },
+    /// Carry the pot of `src` into deeper `dst`, rebinding the charge.
+    Compress {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        guards: Guards,
+    },
+    /// Convert `amount` Gluon back to `mint` tokens from the vault.
+    Extract {
+        signer: AddressBytes,
+        wallet: WalletKey,
+        mint: AddressBytes,
+        amount: Gluon,
+    },
+    /// Move `amount` from an unbound charge back to its wallet.
+    Discharge {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        amount: Gluon,
+    },
+    /// Move a bound charge from `src` to adjacent `dst`.
+    Rebind {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        guards: Guards,
+    },
+    /// Take a charge off the board from edge element `src`.
+    Unbind {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        guards: Guards,
+    },
+    /// Put an unbound charge on the board into edge element `dst`.
+    Bind {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        dst: u8,
+        guards: Guards,
+    },
+    /// Reset saturated `target` into `artefact`, re-binding the triggering charge.
+    Overload {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        target: u8,
+        artefact: ElementIndex,
+    },
+    /// Deposit `amount` of `mint` tokens into the vault and credit the wallet 1:1.
+    Infuse {
+        signer: AddressBytes,
+        wallet: WalletKey,
+        mint: AddressBytes,
+        amount: Gluon,
+    },
+    /// Donate `amount` of a bound charge to the pot of `target`.
+    Vent {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        target: u8,
+        amount: Gluon,
+    },
+    /// Rebind into adjacent `dst` and reset it with the same charge, atomically. `guards`
+    /// bound the rebind.
+    RebindAndOverload {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        artefact: ElementIndex,
+        guards: Guards,
+    },
+    /// Bind into edge element `dst` and reset it with the same charge, atomically. `guards`
+    /// bound the bind.
+    BindAndOverload {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        dst: u8,
+        artefact: ElementIndex,
+        guards: Guards,
+    },
+    /// Move a bound charge from `src` along `path`, one rebind per hop. The path ends at its
+    /// first zero; `guards` bound the fee of all hops and the share in the last element.
+    RebindPath {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        path: [u8; DIAMETER as usize],
+        guards: Guards,
+    },
+    /// Move `amount` of a charge bound to `target` into the wallet's next charge (id =
+    /// `wallet.charges`), bound to the same element.
+    Split {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        target: u8,
+        amount: Gluon,
+    },
+    /// Fold `other` into `charge`; both bound to `target`.
+    Merge {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        other: ChargeKey,
+        target: u8,
+    },
+    /// Close an empty, unbound charge; its id is not reissued.
+    CloseCharge {
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+    },
+    /// Close an empty wallet with no open charges.
+    CloseWallet {
+        signer: AddressBytes,
+        wallet: WalletKey,
+    },
+    /// Initialize the board singleton.
+    InitBoard,
+    /// Initialize element `atomic` at generation 0.
+    InitElement { atomic: u8 },
+    /// Initialize the artefact for the current generation of element `atomic`.
+    InitArtefact { atomic: u8 },
+}
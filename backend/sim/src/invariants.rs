@@ -0,0 +1,826 @@
+//! Property tests for the System Invariants of `docs/MECHANICS.md`.
+//!
+//! Random action sequences by three players (two charges each) run over the genesis board;
+//! after every step, accepted or rejected, the whole state is checked against the invariants
+//! and the step's effect against the rules for its action. Actions pick their elements from
+//! the charge's position (edge elements, neighbours, its own element) so that most of them
+//! are legal and the board actually saturates and resets. Charges can be closed along the way,
+//! so steps on a closed charge are skipped.
+//!
+//! The suite runs against the simulator only: it holds for the program as far as the sim
+//! mirrors the processors. Account-level checks the sim cannot express (the same account
+//! passed twice) and the cost and compression rules on the program itself are covered by
+//! `program/tests` (`cross_cutting.rs`, value conservation).
+
+use std::collections::BTreeMap;
+
+use bytemuck::bytes_of;
+use curve::{delta::Delta, shape::CurveShape};
+use nucleus::{
+    board::Element,
+    consts::{MAX_ATOMIC_NUMBER, MAX_DELTA_TIMESTAMP, MAX_SATURATION},
+    guards::Guards,
+    topology::{self, ElementSet, DIAMETER, EDGE, NEIGHBOURS},
+    types::{AddressBytes, ElementIndex, Gluon, Q824},
+};
+use program::addresses::USDC_MINT;
+use proptest::prelude::*;
+
+use crate::{ChargeKey, GameState, Instruction, ManualClock, WalletKey};
+
+const PLAYERS: [AddressBytes; 3] = [[1; 32], [2; 32], [3; 32]];
+const CHARGES_PER_PLAYER: u32 = 2;
+const CHARGES: usize = PLAYERS.len() * CHARGES_PER_PLAYER as usize;
+/// Largest single Infuse, in whole Gluon: enough for a few charges to saturate an element.
+const MAX_INFUSE: u64 = 40_000;
+/// Saturation may drift from its TVL position by one step per curve move (rounding).
+const DRIFT_PER_MOVE: u32 = 1;
+
+/// One player action. Amounts are fractions (`n / 256`) of the balance they draw from;
+/// `pick` selects among the elements the action can reach from the charge's position.
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Infuse { player: usize, gluon: u64 },
+    Extract { player: usize, fraction: u8 },
+    Charge { charge: usize, fraction: u8 },
+    Discharge { charge: usize, fraction: u8 },
+    Bind { charge: usize, pick: usize },
+    Unbind { charge: usize },
+    Rebind { charge: usize, pick: usize },
+    Compress { charge: usize, pick: usize },
+    RebindPath { charge: usize, target: u8 },
+    Vent { charge: usize, fraction: u8 },
+    Overload { charge: usize },
+    RebindAndOverload { charge: usize, pick: usize },
+    BindAndOverload { charge: usize, pick: usize },
+    Claim { charge: usize },
+    Split { charge: usize, fraction: u8 },
+    Merge { charge: usize, pick: usize },
+    Close { player: usize, pick: usize },
+    Wait { slots: u64 },
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    let player = 0..PLAYERS.len();
+    let charge = 0..CHARGES;
+    let player_close = player.clone();
+    prop_oneof![
+        2 => (player.clone(), 1..=MAX_INFUSE).prop_map(|(player, gluon)| Step::Infuse { player, gluon }),
+        1 => (player.clone(), any::<u8>()).prop_map(|(player, fraction)| Step::Extract { player, fraction }),
+        3 => (charge.clone(), any::<u8>()).prop_map(|(charge, fraction)| Step::Charge { charge, fraction }),
+        1 => (charge.clone(), any::<u8>()).prop_map(|(charge, fraction)| Step::Discharge { charge, fraction }),
+        // Entries cluster on two edge elements, so resets usually have charges to eject
+        4 => (charge.clone(), 0..2usize).prop_map(|(charge, pick)| Step::Bind { charge, pick }),
+        1 => charge.clone().prop_map(|charge| Step::Unbind { charge }),
+        4 => (charge.clone(), any::<usize>()).prop_map(|(charge, pick)| Step::Rebind { charge, pick }),
+        2 => (charge.clone(), any::<usize>()).prop_map(|(charge, pick)| Step::Compress { charge, pick }),
+        2 => (charge.clone(), 1..=MAX_ATOMIC_NUMBER as u8).prop_map(|(charge, target)| Step::RebindPath { charge, target }),
+        1 => (charge.clone(), any::<u8>()).prop_map(|(charge, fraction)| Step::Vent { charge, fraction }),
+        4 => charge.clone().prop_map(|charge| Step::Overload { charge }),
+        2 => (charge.clone(), any::<usize>()).prop_map(|(charge, pick)| Step::RebindAndOverload { charge, pick }),
+        2 => (charge.clone(), 0..2usize).prop_map(|(charge, pick)| Step::BindAndOverload { charge, pick }),
+        6 => charge.clone().prop_map(|charge| Step::Claim { charge }),
+        1 => (charge.clone(), any::<u8>()).prop_map(|(charge, fraction)| Step::Split { charge, fraction }),
+        1 => (charge, any::<usize>()).prop_map(|(charge, pick)| Step::Merge { charge, pick }),
+        1 => (player_close, any::<usize>()).prop_map(|(player, pick)| Step::Close { player, pick }),
+        2 => (0..=MAX_DELTA_TIMESTAMP).prop_map(|slots| Step::Wait { slots }),
+    ]
+}
+
+// Helpers
+fn wallet(player: usize) -> WalletKey {
+    WalletKey {
+        authority: PLAYERS[player],
+        mint: USDC_MINT,
+    }
+}
+
+fn charge_key(charge: usize) -> ChargeKey {
+    let player = charge / CHARGES_PER_PLAYER as usize;
+    wallet(player).charge(charge as u32 % CHARGES_PER_PLAYER)
+}
+
+/// `fraction / 256` of `balance`, at least one base unit.
+fn portion(balance: Gluon, fraction: u8) -> Gluon {
+    Gluon((balance.0 / 256 * fraction as u64).max(1))
+}
+
+/// Atomic number of the live element `index` refers to, if it is the live generation.
+fn live_atomic(state: &GameState, index: ElementIndex) -> Option<u8> {
+    let atomic = index.atomic() as u8;
+    state
+        .element(atomic)
+        .filter(|e| !index.is_zero() && e.index == index)
+        .map(|_| atomic)
+}
+
+/// The `pick`-th member of `set` (wrapping), if it has any.
+fn nth(set: ElementSet, pick: usize) -> Option<u8> {
+    let atomic = set.iter().nth(pick.checked_rem(set.len())?)?;
+    Some(atomic as u8)
+}
+
+/// Game instruction for `step`, with elements resolved from the current state.
+/// Overloads first initialize the target's artefact, as a client would.
+fn instruction(state: &mut GameState, step: Step) -> Option<Instruction> {
+    use Instruction::*;
+    let signer_of = |charge: usize| PLAYERS[charge / CHARGES_PER_PLAYER as usize];
+    let at = |state: &GameState, charge: usize| {
+        let c = state.charges.get(&charge_key(charge));
+        c.map(|c| c.index)
+    };
+    let ix = match step {
+        Step::Infuse { player, gluon } => Infuse {
+            signer: PLAYERS[player],
+            wallet: wallet(player),
+            mint: USDC_MINT,
+            amount: Gluon(gluon * Gluon::UNIT),
+        },
+        Step::Extract { player, fraction } => Extract {
+            signer: PLAYERS[player],
+            wallet: wallet(player),
+            mint: USDC_MINT,
+            amount: portion(state.wallets[&wallet(player)].balance, fraction),
+        },
+        Step::Charge { charge, fraction } => {
+            let w = wallet(charge / CHARGES_PER_PLAYER as usize);
+            Charge {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                wallet: w,
+                amount: portion(state.wallets[&w].balance, fraction),
+            }
+        }
+        Step::Discharge { charge, fraction } => Discharge {
+            signer: signer_of(charge),
+            charge: charge_key(charge),
+            wallet: wallet(charge / CHARGES_PER_PLAYER as usize),
+            amount: portion(state.charges.get(&charge_key(charge))?.balance, fraction),
+        },
+        Step::Bind { charge, pick } => {
+            at(state, charge)?;
+            Bind {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                dst: nth(EDGE, pick)?,
+                guards: Guards::NONE,
+            }
+        }
+        Step::Unbind { charge } => Unbind {
+            signer: signer_of(charge),
+            charge: charge_key(charge),
+            src: at(state, charge)?.atomic() as u8,
+            guards: Guards::NONE,
+        },
+        Step::Rebind { charge, pick } | Step::Compress { charge, pick } => {
+            let src = at(state, charge)?.atomic() as u8;
+            let neighbours = NEIGHBOURS.get((src as usize).checked_sub(1)?)?;
+            let (signer, charge, dst) = (
+                signer_of(charge),
+                charge_key(charge),
+                nth(*neighbours, pick)?,
+            );
+            match step {
+                Step::Rebind { .. } => Rebind {
+                    signer,
+                    charge,
+                    src,
+                    dst,
+                    guards: Guards::NONE,
+                },
+                _ => Compress {
+                    signer,
+                    charge,
+                    src,
+                    dst,
+                    guards: Guards::NONE,
+                },
+            }
+        }
+        // A shortest path to `target`; empty (and rejected) when already there
+        Step::RebindPath { charge, target } => {
+            let src = at(state, charge)?.atomic() as u8;
+            state.element(src)?;
+            let mut path = [0; DIAMETER as usize];
+            let shortest = topology::path(src as u64, target as u64);
+            for (hop, atomic) in path.iter_mut().zip(shortest) {
+                *hop = atomic as u8;
+            }
+            RebindPath {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                src,
+                path,
+                guards: Guards::NONE,
+            }
+        }
+        Step::Vent { charge, fraction } => Vent {
+            signer: signer_of(charge),
+            charge: charge_key(charge),
+            target: at(state, charge)?.atomic() as u8,
+            amount: portion(state.charges.get(&charge_key(charge))?.balance, fraction),
+        },
+        Step::Overload { charge } => {
+            let target = at(state, charge)?.atomic() as u8;
+            Overload {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                target,
+                artefact: artefact_of(state, target)?,
+            }
+        }
+        Step::RebindAndOverload { charge, pick } => {
+            let src = at(state, charge)?.atomic() as u8;
+            let neighbours = NEIGHBOURS.get((src as usize).checked_sub(1)?)?;
+            let dst = nth(*neighbours, pick)?;
+            RebindAndOverload {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                src,
+                dst,
+                artefact: artefact_of(state, dst)?,
+                guards: Guards::NONE,
+            }
+        }
+        Step::BindAndOverload { charge, pick } => {
+            at(state, charge)?;
+            let dst = nth(EDGE, pick)?;
+            BindAndOverload {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                dst,
+                artefact: artefact_of(state, dst)?,
+                guards: Guards::NONE,
+            }
+        }
+        Step::Claim { charge } => Claim {
+            signer: signer_of(charge),
+            charge: charge_key(charge),
+            artefact: at(state, charge)?,
+        },
+        Step::Split { charge, fraction } => {
+            let c = *state.charges.get(&charge_key(charge))?;
+            Split {
+                signer: signer_of(charge),
+                charge: charge_key(charge),
+                wallet: wallet(charge / CHARGES_PER_PLAYER as usize),
+                target: c.index.atomic() as u8,
+                amount: portion(c.balance, fraction),
+            }
+        }
+        // Any other open charge of the same wallet, split parts included
+        Step::Merge { charge, pick } => {
+            let w = wallet(charge / CHARGES_PER_PLAYER as usize);
+            let key = charge_key(charge);
+            let target = at(state, charge)?.atomic() as u8;
+            let others = open_ids(state, w).filter(|&id| id != key.id);
+            let id = others.clone().nth(pick.checked_rem(others.count())?)?;
+            Merge {
+                signer: signer_of(charge),
+                charge: key,
+                other: w.charge(id),
+                target,
+            }
+        }
+        // Any open charge of the player, emptied first if it is off the board
+        Step::Close { player, pick } => {
+            let w = wallet(player);
+            let ids = open_ids(state, w);
+            let id = ids.clone().nth(pick.checked_rem(ids.count())?)?;
+            drain(state, w.charge(id));
+            CloseCharge {
+                signer: PLAYERS[player],
+                charge: w.charge(id),
+                wallet: w,
+            }
+        }
+        Step::Wait { slots } => {
+            state.clock.advance(slots);
+            return None;
+        }
+    };
+    Some(ix)
+}
+
+/// Ids of the wallet's charges that are still open.
+fn open_ids(state: &GameState, wallet: WalletKey) -> impl Iterator<Item = u32> + Clone + '_ {
+    let issued = state.wallets.get(&wallet).map_or(0, |w| w.charges);
+    (0..issued).filter(move |&id| state.charges.contains_key(&wallet.charge(id)))
+}
+
+/// Discharge the whole balance of an unbound charge, as a client closing it would.
+fn drain(state: &mut GameState, charge: ChargeKey) {
+    let c = state.charges[&charge];
+    if c.index.is_zero() && c.balance > Gluon::ZERO {
+        let (signer, mint) = (c.authority, c.mint);
+        let wallet = WalletKey {
+            authority: signer,
+            mint,
+        };
+        let amount = c.balance;
+        state
+            .apply(Instruction::Discharge {
+                signer,
+                charge,
+                wallet,
+                amount,
+            })
+            .unwrap();
+    }
+}
+
+/// Artefact of the live generation of `atomic`, initialized if it does not exist yet.
+fn artefact_of(state: &mut GameState, atomic: u8) -> Option<ElementIndex> {
+    let index = state.element(atomic)?.index;
+    if !state.artefacts.contains_key(&index) {
+        state.apply(Instruction::InitArtefact { atomic }).unwrap();
+    }
+    Some(index)
+}
+
+/// Genesis board with every player's wallet and charges initialized (all empty).
+fn setup() -> GameState {
+    let mut state = GameState::genesis(ManualClock::default());
+    for (player, &signer) in PLAYERS.iter().enumerate() {
+        let mint = USDC_MINT;
+        state
+            .apply(Instruction::InitWallet { signer, mint })
+            .unwrap();
+        for _ in 0..CHARGES_PER_PLAYER {
+            let wallet = wallet(player);
+            state
+                .apply(Instruction::InitCharge { signer, wallet })
+                .unwrap();
+        }
+    }
+    state
+}
+
+/// Every account's bytes, for "nothing changed" comparisons (the clock excluded).
+fn snapshot(state: &GameState) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(bytes_of(&state.board.unwrap()));
+    for element in state.elements.iter().flatten() {
+        bytes.extend_from_slice(bytes_of(element));
+    }
+    for artefact in state.artefacts.values() {
+        bytes.extend_from_slice(bytes_of(artefact));
+    }
+    for wallet in state.wallets.values() {
+        bytes.extend_from_slice(bytes_of(wallet));
+    }
+    for charge in state.charges.values() {
+        bytes.extend_from_slice(bytes_of(charge));
+    }
+    for vault in state.vaults.values() {
+        bytes.extend_from_slice(bytes_of(vault));
+    }
+    bytes
+}
+
+/// Gluon held inside the game: wallets, charges, element and artefact pots, quantum pocket.
+fn in_game(state: &GameState) -> Gluon {
+    state.wallets.values().map(|w| w.balance).sum::<Gluon>()
+        + state.charges.values().map(|c| c.balance).sum::<Gluon>()
+        + shared(state)
+}
+
+/// Gluon held as shared value: element and artefact pots plus the quantum pocket.
+fn shared(state: &GameState) -> Gluon {
+    state
+        .elements
+        .iter()
+        .flatten()
+        .map(|e| e.pot)
+        .sum::<Gluon>()
+        + state.artefacts.values().map(|a| a.pot).sum::<Gluon>()
+        + state.board.unwrap().quantum_pocket
+}
+
+fn vaults(state: &GameState) -> Gluon {
+    state.vaults.values().copied().sum()
+}
+
+/// Saturation a fresh curve would reach holding the element's TVL in one deposit.
+fn saturation_for_tvl(element: &Element) -> Q824 {
+    let curve = &element.curve;
+    let (dx, _) = curve
+        .shape()
+        .try_dx_for_dc(
+            Q824::ZERO,
+            Default::default(),
+            Delta::Deposit(curve.tvl.0),
+            curve.capacity.0,
+        )
+        .unwrap();
+    dx.magnitude()
+}
+
+/// Invariants of a single state, independent of how it was reached.
+fn check_state(state: &GameState, moves: &[u32]) -> Result<(), TestCaseError> {
+    let board = state.board.unwrap();
+
+    // Gluon is fully backed
+    prop_assert_eq!(
+        in_game(state),
+        vaults(state),
+        "in-game Gluon differs from the vaults"
+    );
+
+    // Board totals: live curves plus outstanding artefacts
+    let live = state.elements.iter().flatten();
+    let tvl = live.clone().map(|e| e.curve.tvl).sum::<Gluon>()
+        + state.artefacts.values().map(|a| a.tvl).sum::<Gluon>();
+    let count = live.map(|e| e.charges).sum::<u32>()
+        + state.artefacts.values().map(|a| a.charges).sum::<u32>();
+    prop_assert_eq!((board.tvl, board.charge_count), (tvl, count));
+
+    // Binding is binary: off the board, on one live element, or ejected into one artefact
+    for (key, charge) in &state.charges {
+        if charge.index.is_zero() {
+            prop_assert_eq!(charge.share, Q824::ZERO, "unbound {:?} holds a share", key);
+        } else if live_atomic(state, charge.index).is_none() {
+            let artefact = state.artefacts.get(&charge.index);
+            prop_assert!(
+                artefact.is_some_and(|a| a.charges > 0),
+                "{:?} points at {:?}, neither live nor claimable",
+                key,
+                charge.index
+            );
+        }
+    }
+
+    // Wallets count their open charges, and every charge's id was issued by its wallet
+    for (key, wallet) in &state.wallets {
+        let open = state
+            .charges
+            .keys()
+            .filter(|c| c.authority == key.authority && c.mint == key.mint);
+        prop_assert_eq!(wallet.open as usize, open.clone().count(), "{:?}", key);
+        prop_assert!(open.into_iter().all(|c| c.id < wallet.charges));
+    }
+
+    // Saturation is live: counts and shares are exactly those of the charges bound now
+    for (slot, element) in state.elements.iter().enumerate() {
+        let element = element.as_ref().unwrap();
+        let bound = state.charges.values().filter(|c| c.index == element.index);
+        prop_assert_eq!(element.charges as usize, bound.clone().count());
+        let shares = bound.map(|c| c.share.0 as u64).sum::<u64>();
+        prop_assert_eq!(element.curve.shares.0 as u64, shares);
+
+        // ...and saturation is the curve position of the TVL it holds, whatever the path
+        let drift = saturation_for_tvl(element)
+            .0
+            .abs_diff(element.curve.saturation.0);
+        prop_assert!(
+            drift <= moves[slot] * DRIFT_PER_MOVE,
+            "element {} saturation drifted {} after {} moves",
+            slot + 1,
+            drift,
+            moves[slot]
+        );
+    }
+
+    // Ejected charges: only those bound at the reset, holding exactly the unclaimed shares
+    for (index, artefact) in &state.artefacts {
+        let empty = (0, Gluon::ZERO, Gluon::ZERO);
+        if live_atomic(state, *index).is_some() {
+            prop_assert_eq!((artefact.charges, artefact.tvl, artefact.pot), empty);
+            continue;
+        }
+        let ejected = state.charges.values().filter(|c| c.index == *index);
+        prop_assert_eq!(artefact.charges as usize, ejected.clone().count());
+        let shares = ejected.map(|c| c.share.0 as u64).sum::<u64>();
+        prop_assert_eq!(artefact.shares.0 as u64, shares);
+        // ...and once all have claimed, nothing is stranded: the last took pot and residue
+        if artefact.charges == 0 {
+            prop_assert_eq!(
+                (artefact.charges, artefact.tvl, artefact.pot),
+                empty,
+                "claimed artefact {:?} kept value",
+                index
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Rules for the effect of one accepted instruction, comparing the state before and after.
+fn check_effect(
+    before: &GameState,
+    after: &GameState,
+    ix: Instruction,
+) -> Result<(), TestCaseError> {
+    use Instruction::*;
+
+    // Only Infuse and Extract move tokens, 1:1 with the wallet
+    let minted = vaults(after).0 as i128 - vaults(before).0 as i128;
+    let expected = match ix {
+        Infuse { amount, .. } => amount.0 as i128,
+        Extract { amount, .. } => -(amount.0 as i128),
+        _ => 0,
+    };
+    prop_assert_eq!(minted, expected, "vaults moved by {} on {:?}", minted, ix);
+
+    // Costs only on voluntary actions: no other charge's balance moves (resets are free exits)
+    let actor = match ix {
+        Charge { charge, .. }
+        | Discharge { charge, .. }
+        | Bind { charge, .. }
+        | Unbind { charge, .. }
+        | Rebind { charge, .. }
+        | Compress { charge, .. }
+        | RebindPath { charge, .. }
+        | Vent { charge, .. }
+        | Overload { charge, .. }
+        | RebindAndOverload { charge, .. }
+        | BindAndOverload { charge, .. }
+        | Claim { charge, .. }
+        | Split { charge, .. }
+        | Merge { charge, .. } => Some(charge),
+        _ => None,
+    };
+    let other = match ix {
+        Merge { other, .. } => Some(other),
+        _ => None,
+    };
+    for (key, charge) in &after.charges {
+        // Split creates the charge it moves stake into
+        let Some(prior) = before.charges.get(key) else {
+            continue;
+        };
+        if Some(*key) != actor && Some(*key) != other {
+            prop_assert_eq!(charge.balance, prior.balance, "{:?} paid", key);
+        }
+    }
+
+    // Costs are never burned: whatever the mover pays lands in shared value
+    if let Bind { charge, .. }
+    | Unbind { charge, .. }
+    | Rebind { charge, .. }
+    | Compress { charge, .. }
+    | RebindPath { charge, .. }
+    | Vent { charge, .. } = ix
+    {
+        let paid = before.charges[&charge].balance - after.charges[&charge].balance;
+        prop_assert_eq!(
+            shared(after),
+            shared(before) + paid,
+            "{:?} burned value",
+            ix
+        );
+        prop_assert!(paid > Gluon::ZERO, "{:?} was free", ix);
+    }
+
+    // Pots never move alone: only Compress and resets take value out of an element pot
+    let pot = |state: &GameState, atomic: u8| state.element(atomic).unwrap().pot;
+    for atomic in 1..=MAX_ATOMIC_NUMBER as u8 {
+        let moved = match ix {
+            Compress { src, .. } => src == atomic,
+            Overload { target, .. }
+            | RebindAndOverload { dst: target, .. }
+            | BindAndOverload { dst: target, .. } => target == atomic,
+            _ => false,
+        };
+        if !moved {
+            prop_assert!(
+                pot(after, atomic) >= pot(before, atomic),
+                "pot {} shrank",
+                atomic
+            );
+        }
+    }
+
+    let saturation =
+        |state: &GameState, atomic: u8| state.element(atomic).unwrap().curve.saturation;
+    match ix {
+        // Entry increases saturation, exit decreases it
+        Bind { dst, .. } => prop_assert!(saturation(after, dst) >= saturation(before, dst)),
+        Unbind { src, .. } => prop_assert!(saturation(after, src) <= saturation(before, src)),
+        Rebind { src, dst, .. } => {
+            prop_assert!(saturation(after, src) <= saturation(before, src));
+            prop_assert!(saturation(after, dst) >= saturation(before, dst));
+        }
+        RebindPath { src, path, .. } => {
+            let dst = hops(path).last().unwrap();
+            prop_assert!(saturation(after, src) <= saturation(before, src));
+            prop_assert!(saturation(after, dst) >= saturation(before, dst));
+        }
+        // Compression requires a Z increase and always increases the pot it merges into
+        Compress { src, dst, .. } => {
+            prop_assert!(dst > src, "compressed outward from {} to {}", src, dst);
+            prop_assert_eq!(pot(after, src), Gluon::ZERO);
+            prop_assert!(pot(after, dst) > pot(before, src) + pot(before, dst));
+        }
+        // Resets need a saturated target; every charge bound at that instant is ejected
+        Overload {
+            charge,
+            target,
+            artefact,
+            ..
+        } => {
+            prop_assert!(saturation(before, target) >= MAX_SATURATION);
+            check_reset(before, after, charge, target, artefact)?;
+        }
+        // ...including when the trigger's own move saturated it
+        RebindAndOverload {
+            charge,
+            dst,
+            artefact,
+            ..
+        }
+        | BindAndOverload {
+            charge,
+            dst,
+            artefact,
+            ..
+        } => check_reset(before, after, charge, dst, artefact)?,
+        // Split and merge regroup an element's stake between charges: the curve stays put
+        Split { target, .. } | Merge { target, .. } => {
+            let (b, a) = (
+                before.element(target).unwrap(),
+                after.element(target).unwrap(),
+            );
+            let curve = |e: &Element| (e.curve.tvl, e.curve.saturation, e.curve.shares);
+            prop_assert_eq!(curve(a), curve(b));
+            let stake = |state: &GameState| {
+                let bound = state.charges.values().filter(|c| c.index == b.index);
+                bound.map(|c| c.balance).sum::<Gluon>()
+            };
+            prop_assert_eq!(stake(after), stake(before));
+            prop_assert_eq!(shared(after), shared(before));
+        }
+        // Only empty, unbound charges close, and closing moves no value
+        CloseCharge { charge, .. } => {
+            let c = before.charges[&charge];
+            prop_assert!(c.index.is_zero() && c.balance == Gluon::ZERO);
+            prop_assert!(!after.charges.contains_key(&charge));
+            prop_assert_eq!(in_game(after), in_game(before));
+        }
+        // Entitlement at reset only: a claim needs the exact index of a past generation
+        Claim {
+            charge, artefact, ..
+        } => {
+            prop_assert_eq!(before.charges[&charge].index, artefact);
+            prop_assert!(live_atomic(before, artefact).is_none());
+            prop_assert!(after.charges[&charge].index.is_zero());
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// An artefact as its reset left it for the ejected charges, and the claims made on it since.
+#[derive(Clone, Copy, Debug)]
+struct Reset {
+    pot: Gluon,
+    shares: Q824,
+    claims: u32,
+}
+
+/// Rewards follow the shares at reset: each Claim pays `pot · share / shares` of the artefact
+/// as the reset left it, whatever the order, up to a unit of rounding per earlier claim.
+fn check_payout(
+    before: &GameState,
+    after: &GameState,
+    ix: Instruction,
+    resets: &mut BTreeMap<ElementIndex, Reset>,
+) -> Result<(), TestCaseError> {
+    use Instruction::*;
+    match ix {
+        Overload { artefact, .. }
+        | RebindAndOverload { artefact, .. }
+        | BindAndOverload { artefact, .. } => {
+            let a = after.artefacts[&artefact];
+            let (pot, shares) = (a.pot, a.shares);
+            resets.insert(
+                artefact,
+                Reset {
+                    pot,
+                    shares,
+                    claims: 0,
+                },
+            );
+        }
+        Claim {
+            charge, artefact, ..
+        } => {
+            let reset = resets.get_mut(&artefact).expect("claim before its reset");
+            let (prior, claimed) = (before.charges[&charge], after.charges[&charge]);
+            let reward = (claimed.balance - prior.balance).0 as i128;
+            let (pot, shares) = (reset.pot.0 as i128, reset.shares.0 as i128);
+            let share = prior.share.0 as i128;
+            // |reward - pot · share / shares| <= claims + 1, scaled by shares
+            let off = (reward * shares - pot * share).abs();
+            prop_assert!(
+                off <= (reset.claims as i128 + 1) * shares.max(1),
+                "{:?} claimed {} of {:?} for share {}",
+                charge,
+                reward,
+                reset,
+                share
+            );
+            reset.claims += 1;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Only the trigger stays on the reset element; charges bound to `artefact` are ejected.
+fn check_reset(
+    before: &GameState,
+    after: &GameState,
+    trigger: ChargeKey,
+    target: u8,
+    artefact: ElementIndex,
+) -> Result<(), TestCaseError> {
+    for (key, c) in &after.charges {
+        let was_bound = before.charges[key].index == artefact;
+        if *key == trigger {
+            prop_assert_eq!(c.index, after.element(target).unwrap().index);
+        } else if was_bound {
+            prop_assert_eq!(c.index, artefact, "{:?} missed the reset", key);
+        } else {
+            prop_assert_eq!(c.index, before.charges[key].index);
+        }
+    }
+    Ok(())
+}
+
+/// The hops of a zero-terminated path.
+fn hops(path: [u8; DIAMETER as usize]) -> impl Iterator<Item = u8> {
+    path.into_iter().take_while(|&atomic| atomic != 0)
+}
+
+/// Curve moves each instruction makes, by element slot (for the saturation drift bound).
+fn record_moves(moves: &mut [u32], ix: Instruction) {
+    use Instruction::*;
+    let mut touch = |atomic: u8| moves[atomic as usize - 1] += 1;
+    match ix {
+        Bind { dst, .. } => touch(dst),
+        Unbind { src, .. } => touch(src),
+        Rebind { src, dst, .. } | Compress { src, dst, .. } => {
+            touch(src);
+            touch(dst);
+        }
+        // The reset starts a fresh curve holding only the trigger
+        Overload { target, .. } | BindAndOverload { dst: target, .. } => {
+            moves[target as usize - 1] = 1
+        }
+        RebindAndOverload { src, dst, .. } => {
+            touch(src);
+            moves[dst as usize - 1] = 1;
+        }
+        // Every hop moves out of one element and into the next
+        RebindPath { src, path, .. } => {
+            let mut at = src;
+            for hop in hops(path) {
+                touch(at);
+                touch(hop);
+                at = hop;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn run(steps: Vec<Step>) -> Result<(), TestCaseError> {
+    let mut state = setup();
+    let mut moves = [0u32; MAX_ATOMIC_NUMBER as usize];
+    let mut resets = BTreeMap::new();
+    check_state(&state, &moves)?;
+    for step in steps {
+        let Some(ix) = instruction(&mut state, step) else {
+            check_state(&state, &moves)?;
+            continue;
+        };
+        let before = state.clone();
+        match state.apply(ix) {
+            Ok(()) => {
+                record_moves(&mut moves, ix);
+                check_effect(&before, &state, ix)?;
+                check_payout(&before, &state, ix, &mut resets)?;
+            }
+            Err(err) => prop_assert_eq!(
+                snapshot(&state),
+                snapshot(&before),
+                "rejected {:?} ({}) changed the state",
+                ix,
+                err
+            ),
+        }
+        check_state(&state, &moves)?;
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    /// Every documented invariant holds after every step of any action sequence.
+    #[test]
+    fn invariants_hold_after_every_step(steps in prop::collection::vec(step(), 1..200)) {
+        run(steps)?;
+    }
+}
@@ -0,0 +1,50 @@
+//! # Sim: TOKAMAK64 without Solana
+//!
+//! A `GameState` holds every account the program would (Board, 26 Elements, Artefacts,
+//! Wallets, Charges and token vaults) and applies each `TokamakInstruction` with the same
+//! checks as the on-chain processors, returning typed [`SimError`]s. Time comes from an
+//! injected [`Clock`], so strategies, replays and economic fuzzing run as plain Rust.
+
+mod error;
+mod instruction;
+mod processors;
+mod state;
+
+#[cfg(test)]
+mod invariants;
+#[cfg(test)]
+mod tests;
+
+pub use error::SimError;
+pub use instruction::Instruction;
+pub use state::{ChargeKey, GameState, WalletKey};
+
+/// Slot source, standing in for the Clock sysvar (drives the speed tax).
+pub trait Clock {
+    fn slot(&self) -> u64;
+}
+
+/// Clock advanced explicitly by the caller.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ManualClock {
+    pub slot: u64,
+}
+
+impl ManualClock {
+    /// Move time forward by `slots`.
+    pub fn advance(&mut self, slots: u64) {
+        self.slot += slots;
+    }
+}
+
+impl Clock for ManualClock {
+    fn slot(&self) -> u64 {
+        self.slot
+    }
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn slot(&self) -> u64 {
+        self()
+    }
+}
@@ -0,0 +1,962 @@
+//! Instruction processing, mirroring `program::processors` check for check.
+//!
+//! Each processor works on copies of the accounts it touches and writes them back only on
+//! success, so a rejected instruction leaves the state untouched, like a failed transaction.
+
+use bytemuck::Zeroable;
+use nucleus::{
+    action,
+    board::{Artefact, Board, Element},
+    consts::MAX_SATURATION,
+    error::TokamakError,
+    fees::{bind_fee, compression_fee, fee_multiplier, rebind_fee, unbind_fee},
+    guards::Guards,
+    player::{Charge, Wallet},
+    topology::{self, DIAMETER},
+    types::{AddressBytes, ElementIndex, Gluon, Q824},
+};
+use program::addresses;
+
+use crate::{
+    state::{ChargeKey, WalletKey},
+    Clock, GameState, Instruction, SimError,
+};
+
+impl<C: Clock> GameState<C> {
+    /// Apply one instruction at the clock's current slot.
+    /// On error nothing changes, as if the transaction had failed.
+    pub fn apply(&mut self, ix: Instruction) -> Result<(), SimError> {
+        use Instruction::*;
+        match ix {
+            InitCharge { signer, wallet } => self.init_charge(signer, wallet),
+            InitWallet { signer, mint } => self.init_wallet(signer, mint),
+            Charge {
+                signer,
+                charge,
+                wallet,
+                amount,
+            } => self.charge(signer, charge, wallet, amount),
+            Claim {
+                signer,
+                charge,
+                artefact,
+            } => self.claim(signer, charge, artefact),
+            Compress {
+                signer,
+                charge,
+                src,
+                dst,
+                guards,
+            } => self.compress(signer, charge, src, dst, guards),
+            Extract {
+                signer,
+                wallet,
+                mint,
+                amount,
+            } => self.extract(signer, wallet, mint, amount),
+            Discharge {
+                signer,
+                charge,
+                wallet,
+                amount,
+            } => self.discharge(signer, charge, wallet, amount),
+            Rebind {
+                signer,
+                charge,
+                src,
+                dst,
+                guards,
+            } => self.rebind(signer, charge, src, dst, guards),
+            Unbind {
+                signer,
+                charge,
+                src,
+                guards,
+            } => self.unbind(signer, charge, src, guards),
+            Bind {
+                signer,
+                charge,
+                dst,
+                guards,
+            } => self.bind(signer, charge, dst, guards),
+            Overload {
+                signer,
+                charge,
+                target,
+                artefact,
+            } => self.overload(signer, charge, target, artefact),
+            Infuse {
+                signer,
+                wallet,
+                mint,
+                amount,
+            } => self.infuse(signer, wallet, mint, amount),
+            Vent {
+                signer,
+                charge,
+                target,
+                amount,
+            } => self.vent(signer, charge, target, amount),
+            RebindAndOverload {
+                signer,
+                charge,
+                src,
+                dst,
+                artefact,
+                guards,
+            } => self.rebind_and_overload(signer, charge, src, dst, artefact, guards),
+            BindAndOverload {
+                signer,
+                charge,
+                dst,
+                artefact,
+                guards,
+            } => self.bind_and_overload(signer, charge, dst, artefact, guards),
+            RebindPath {
+                signer,
+                charge,
+                src,
+                path,
+                guards,
+            } => self.rebind_path(signer, charge, src, path, guards),
+            Split {
+                signer,
+                charge,
+                wallet,
+                target,
+                amount,
+            } => self.split(signer, charge, wallet, target, amount),
+            Merge {
+                signer,
+                charge,
+                other,
+                target,
+            } => self.merge(signer, charge, other, target),
+            CloseCharge {
+                signer,
+                charge,
+                wallet,
+            } => self.close_charge(signer, charge, wallet),
+            CloseWallet { signer, wallet } => self.close_wallet(signer, wallet),
+            InitBoard => self.init_board(),
+            InitElement { atomic } => self.init_element(atomic),
+            InitArtefact { atomic } => self.init_artefact(atomic),
+        }
+    }
+
+    // ========================================================================
+    // INITIALIZATION
+    // ========================================================================
+
+    fn init_wallet(&mut self, signer: AddressBytes, mint: AddressBytes) -> Result<(), SimError> {
+        if !addresses::supported(&mint) {
+            return Err(TokamakError::UnsupportedMint.into());
+        }
+        let key = WalletKey {
+            authority: signer,
+            mint,
+        };
+        if self.wallets.contains_key(&key) {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        self.wallets.insert(
+            key,
+            Wallet {
+                balance: Gluon::ZERO,
+                authority: signer,
+                mint,
+                charges: 0,
+                open: 0,
+            },
+        );
+        Ok(())
+    }
+
+    fn init_charge(&mut self, signer: AddressBytes, wallet: WalletKey) -> Result<(), SimError> {
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+
+        let key = ChargeKey {
+            authority: signer,
+            mint: w.mint,
+            id: w.charges,
+        };
+        if self.charges.contains_key(&key) {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        w.charges += 1;
+        w.open += 1;
+
+        self.charges.insert(
+            key,
+            Charge {
+                balance: Gluon::ZERO,
+                timestamp: 0,
+                index: ElementIndex(0),
+                share: Q824::ZERO,
+                authority: signer,
+                mint: w.mint,
+                _pad: 0,
+            },
+        );
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn init_board(&mut self) -> Result<(), SimError> {
+        if self.board.is_some() {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        // Genesis board: zero TVL, no charges, empty pocket
+        self.board = Some(Board::zeroed());
+        Ok(())
+    }
+
+    fn init_element(&mut self, atomic: u8) -> Result<(), SimError> {
+        let genesis = Element::genesis(atomic as u64).ok_or(SimError::InvalidArgument)?;
+        let slot = &mut self.elements[atomic as usize - 1];
+        if slot.is_some() {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        *slot = Some(genesis);
+        Ok(())
+    }
+
+    fn init_artefact(&mut self, atomic: u8) -> Result<(), SimError> {
+        let index = self.element_mut(atomic)?.index;
+        if index.is_zero() {
+            return Err(SimError::UninitializedAccount);
+        }
+        if self.artefacts.contains_key(&index) {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        self.artefacts.insert(
+            index,
+            Artefact {
+                pot: Gluon::ZERO,
+                index,
+                tvl: Gluon::ZERO,
+                shares: Q824::ZERO,
+                charges: 0,
+            },
+        );
+        Ok(())
+    }
+
+    // ========================================================================
+    // WALLET & BALANCE MANAGEMENT
+    // ========================================================================
+
+    fn infuse(
+        &mut self,
+        signer: AddressBytes,
+        wallet: WalletKey,
+        mint: AddressBytes,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        custody(&w, &mint)?;
+
+        if amount == Gluon::ZERO {
+            return Err(SimError::InvalidArgument);
+        }
+
+        // Token transfer: player -> vault, credited 1:1
+        let vault = self.vaults.get(&mint).copied().unwrap_or_default();
+        let vault = vault
+            .checked_add(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+        w.balance = w
+            .balance
+            .checked_add(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+
+        self.vaults.insert(mint, vault);
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn extract(
+        &mut self,
+        signer: AddressBytes,
+        wallet: WalletKey,
+        mint: AddressBytes,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        custody(&w, &mint)?;
+
+        if amount == Gluon::ZERO {
+            return Err(SimError::InvalidArgument);
+        }
+        if w.balance < amount {
+            return Err(SimError::InsufficientFunds);
+        }
+        w.balance -= amount;
+
+        // Token transfer: vault -> player
+        let vault = self.vaults.get(&mint).copied().unwrap_or_default();
+        let vault = vault
+            .checked_sub(amount)
+            .ok_or(SimError::InsufficientFunds)?;
+
+        self.vaults.insert(mint, vault);
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn charge(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        pair(&c, &w)?;
+
+        if amount == Gluon::ZERO {
+            return Err(SimError::InvalidArgument);
+        }
+        if c.index.atomic() != 0 {
+            return Err(TokamakError::ChargeStillBound.into());
+        }
+
+        w.balance = w
+            .balance
+            .checked_sub(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+        c.balance = c
+            .balance
+            .checked_add(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+
+        self.charges.insert(charge, c);
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn discharge(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        pair(&c, &w)?;
+
+        if amount == Gluon::ZERO {
+            return Err(SimError::InvalidArgument);
+        }
+        if c.index.atomic() != 0 {
+            return Err(TokamakError::ChargeStillBound.into());
+        }
+        if c.balance < amount {
+            return Err(SimError::InsufficientFunds);
+        }
+
+        c.balance -= amount;
+        w.balance = w
+            .balance
+            .checked_add(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+
+        self.charges.insert(charge, c);
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn close_charge(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+    ) -> Result<(), SimError> {
+        let c = self.charge_account(&charge)?;
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        pair(&c, &w)?;
+
+        if !c.index.is_zero() {
+            return Err(TokamakError::ChargeStillBound.into());
+        }
+        if c.balance != Gluon::ZERO {
+            return Err(TokamakError::AccountNotEmpty.into());
+        }
+        w.open = w.open.checked_sub(1).ok_or(SimError::ArithmeticOverflow)?;
+
+        // Rent goes back to the signer; the id stays spent in `w.charges`
+        self.charges.remove(&charge);
+        self.wallets.insert(wallet, w);
+        Ok(())
+    }
+
+    fn close_wallet(&mut self, signer: AddressBytes, wallet: WalletKey) -> Result<(), SimError> {
+        let w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+
+        if w.balance != Gluon::ZERO || w.open != 0 {
+            return Err(TokamakError::AccountNotEmpty.into());
+        }
+
+        self.wallets.remove(&wallet);
+        Ok(())
+    }
+
+    // ========================================================================
+    // MOVEMENT
+    // ========================================================================
+
+    fn bind(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        dst: u8,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut d = *self.element_mut(dst)?;
+        let mut board = self.board_account()?;
+        guards.check_deadline(self.clock.slot())?;
+
+        let fee = self.bind_into(&mut c, &mut d, &mut board)?;
+        guards.check_fee(fee)?;
+        guards.check_share(c.share)?;
+
+        self.charges.insert(charge, c);
+        *self.element_mut(dst)? = d;
+        self.board = Some(board);
+        Ok(())
+    }
+
+    /// Bind rules and effects on account copies; returns the fee taken.
+    fn bind_into(
+        &self,
+        c: &mut Charge,
+        d: &mut Element,
+        board: &mut Board,
+    ) -> Result<Gluon, SimError> {
+        if !d.coordinates.on_edge() {
+            return Err(TokamakError::NotOnEdge.into());
+        }
+        if !c.index.is_zero() {
+            return Err(TokamakError::ChargeAlreadyBound.into());
+        }
+
+        let base_fee = bind_fee(c, d);
+        let fee = self.charge_fee(c, base_fee)?;
+        action::bind(c, d, board)?;
+        d.pot += fee;
+        Ok(fee)
+    }
+
+    fn unbind(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut s = *self.element_mut(src)?;
+        let mut board = self.board_account()?;
+        guards.check_deadline(self.clock.slot())?;
+
+        if !s.coordinates.on_edge() {
+            return Err(TokamakError::NotOnEdge.into());
+        }
+        if c.index != s.index {
+            return Err(TokamakError::ChargeNotInElement.into());
+        }
+
+        let base_fee = unbind_fee(&c, &s);
+        let fee = self.charge_fee(&mut c, base_fee)?;
+        guards.check_fee(fee)?;
+        action::unbind(&mut c, &mut s, &mut board)?;
+        s.pot += fee;
+
+        self.charges.insert(charge, c);
+        *self.element_mut(src)? = s;
+        self.board = Some(board);
+        Ok(())
+    }
+
+    fn rebind(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let (s, d) = self.elements_mut(src, dst)?;
+        let (mut s, mut d) = (*s, *d);
+        guards.check_deadline(self.clock.slot())?;
+
+        let fee = self.rebind_into(&mut c, &mut s, &mut d)?;
+        guards.check_fee(fee)?;
+        guards.check_share(c.share)?;
+
+        self.charges.insert(charge, c);
+        self.store_pair(src, s, dst, d)
+    }
+
+    /// Rebind rules and effects on account copies; returns the fee taken.
+    fn rebind_into(
+        &self,
+        c: &mut Charge,
+        s: &mut Element,
+        d: &mut Element,
+    ) -> Result<Gluon, SimError> {
+        let multiplier = self.speed_multiplier(c);
+        hop_into(c, s, d, multiplier)
+    }
+
+    fn rebind_path(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        path: [u8; DIAMETER as usize],
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        self.element(src).ok_or(SimError::UninitializedAccount)?;
+        let hops = path.iter().copied().take_while(|&atomic| atomic != 0);
+        if path[0] == 0 {
+            return Err(SimError::InvalidArgument);
+        }
+        guards.check_deadline(self.clock.slot())?;
+
+        // One speed tax for the whole path, read before its first hop
+        let multiplier = self.speed_multiplier(&mut c);
+        let mut fee = Gluon::ZERO;
+        let mut elements = self.elements;
+        let slot = |atomic: u8| (atomic as usize).wrapping_sub(1);
+        let mut at = src;
+        for dst in hops {
+            match elements.get_disjoint_mut([slot(at), slot(dst)]) {
+                Ok([Some(s), Some(d)]) => {
+                    fee += hop_into(&mut c, s, d, multiplier)?;
+                }
+                Err(core::slice::GetDisjointMutError::OverlappingIndices) => {
+                    return Err(TokamakError::NotAdjacent.into());
+                }
+                _ => return Err(SimError::UninitializedAccount),
+            }
+            at = dst;
+        }
+        guards.check_fee(fee)?;
+        guards.check_share(c.share)?;
+
+        self.charges.insert(charge, c);
+        self.elements = elements;
+        Ok(())
+    }
+
+    fn compress(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let (s, d) = self.elements_mut(src, dst)?;
+        let (mut s, mut d) = (*s, *d);
+        guards.check_deadline(self.clock.slot())?;
+
+        if c.index != s.index {
+            return Err(TokamakError::ChargeNotInElement.into());
+        }
+        if s.index > d.index {
+            return Err(TokamakError::CompressionNotInward.into());
+        }
+        if !topology::compressible(s.index.atomic(), d.index.atomic()) {
+            return Err(TokamakError::NotAdjacent.into());
+        }
+
+        let base_fee = rebind_fee(&c, &s, &d) + compression_fee(&s);
+        let fee = self.charge_fee(&mut c, base_fee)?;
+        guards.check_fee(fee)?;
+        action::compress(&mut c, &mut s, &mut d)?;
+        guards.check_share(c.share)?;
+        d.pot += fee;
+
+        self.charges.insert(charge, c);
+        self.store_pair(src, s, dst, d)
+    }
+
+    fn vent(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        target: u8,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut t = *self.element_mut(target)?;
+
+        if c.index != t.index {
+            return Err(TokamakError::ChargeNotInElement.into());
+        }
+        if amount == Gluon::ZERO {
+            return Err(SimError::InvalidArgument);
+        }
+
+        c.balance = c
+            .balance
+            .checked_sub(amount)
+            .ok_or(SimError::ArithmeticOverflow)?;
+        t.pot += amount;
+
+        self.charges.insert(charge, c);
+        *self.element_mut(target)? = t;
+        Ok(())
+    }
+
+    fn split(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        wallet: WalletKey,
+        target: u8,
+        amount: Gluon,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        let mut w = self.wallet(&wallet)?;
+        authorize(signer, &w.authority)?;
+        pair(&c, &w)?;
+        let mut t = *self.element_mut(target)?;
+        let mut board = self.board_account()?;
+
+        if amount == Gluon::ZERO || amount >= c.balance {
+            return Err(SimError::InvalidArgument);
+        }
+        if c.index != t.index {
+            return Err(TokamakError::ChargeNotInElement.into());
+        }
+
+        let key = wallet.charge(w.charges);
+        if self.charges.contains_key(&key) {
+            return Err(SimError::AccountAlreadyInitialized);
+        }
+        w.charges += 1;
+        w.open += 1;
+        let mut part = Charge {
+            authority: signer,
+            mint: w.mint,
+            ..Charge::zeroed()
+        };
+        action::split(&mut c, &mut part, amount, &mut t, &mut board);
+
+        self.charges.insert(charge, c);
+        self.charges.insert(key, part);
+        self.wallets.insert(wallet, w);
+        *self.element_mut(target)? = t;
+        self.board = Some(board);
+        Ok(())
+    }
+
+    fn merge(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        other: ChargeKey,
+        target: u8,
+    ) -> Result<(), SimError> {
+        if charge == other {
+            return Err(SimError::AccountBorrowFailed);
+        }
+        let mut c = self.charge_account(&charge)?;
+        let mut o = self.charge_account(&other)?;
+        authorize(signer, &c.authority)?;
+        authorize(signer, &o.authority)?;
+        if c.mint != o.mint {
+            return Err(TokamakError::MintMismatch.into());
+        }
+        let mut t = *self.element_mut(target)?;
+        let mut board = self.board_account()?;
+
+        if c.index != t.index || o.index != t.index {
+            return Err(TokamakError::ChargeNotInElement.into());
+        }
+
+        action::merge(&mut c, &mut o, &mut t, &mut board);
+
+        self.charges.insert(charge, c);
+        self.charges.insert(other, o);
+        *self.element_mut(target)? = t;
+        self.board = Some(board);
+        Ok(())
+    }
+
+    // ========================================================================
+    // BREAKING & REWARDS
+    // ========================================================================
+
+    fn overload(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        target: u8,
+        artefact: ElementIndex,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut t = *self.element_mut(target)?;
+        let mut a = self.artefact(artefact)?;
+        let mut board = self.board_account()?;
+
+        overload_into(&mut c, &mut t, &mut a, &mut board)?;
+
+        self.charges.insert(charge, c);
+        *self.element_mut(target)? = t;
+        self.artefacts.insert(artefact, a);
+        self.board = Some(board);
+        Ok(())
+    }
+
+    fn rebind_and_overload(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        src: u8,
+        dst: u8,
+        artefact: ElementIndex,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let (s, d) = self.elements_mut(src, dst)?;
+        let (mut s, mut d) = (*s, *d);
+        let mut a = self.artefact(artefact)?;
+        let mut board = self.board_account()?;
+        guards.check_deadline(self.clock.slot())?;
+
+        // Race lost before the move: dst already reset past the artefact's generation
+        if a.index != d.index {
+            return Err(TokamakError::ArtefactMismatch.into());
+        }
+
+        let fee = self.rebind_into(&mut c, &mut s, &mut d)?;
+        guards.check_fee(fee)?;
+        guards.check_share(c.share)?;
+        overload_into(&mut c, &mut d, &mut a, &mut board)?;
+
+        self.charges.insert(charge, c);
+        self.artefacts.insert(artefact, a);
+        self.board = Some(board);
+        self.store_pair(src, s, dst, d)
+    }
+
+    fn bind_and_overload(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        dst: u8,
+        artefact: ElementIndex,
+        guards: Guards,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut d = *self.element_mut(dst)?;
+        let mut a = self.artefact(artefact)?;
+        let mut board = self.board_account()?;
+        guards.check_deadline(self.clock.slot())?;
+
+        // Race lost before the move: dst already reset past the artefact's generation
+        if a.index != d.index {
+            return Err(TokamakError::ArtefactMismatch.into());
+        }
+
+        let fee = self.bind_into(&mut c, &mut d, &mut board)?;
+        guards.check_fee(fee)?;
+        guards.check_share(c.share)?;
+        overload_into(&mut c, &mut d, &mut a, &mut board)?;
+
+        self.charges.insert(charge, c);
+        *self.element_mut(dst)? = d;
+        self.artefacts.insert(artefact, a);
+        self.board = Some(board);
+        Ok(())
+    }
+
+    fn claim(
+        &mut self,
+        signer: AddressBytes,
+        charge: ChargeKey,
+        artefact: ElementIndex,
+    ) -> Result<(), SimError> {
+        let mut c = self.charge_account(&charge)?;
+        authorize(signer, &c.authority)?;
+        let mut a = self.artefact(artefact)?;
+        let mut board = self.board_account()?;
+
+        if c.index != a.index {
+            return Err(TokamakError::ArtefactMismatch.into());
+        }
+        if a.charges == 0 {
+            return Err(TokamakError::NothingToClaim.into());
+        }
+
+        action::release(&mut c, &mut a, &mut board);
+
+        self.charges.insert(charge, c);
+        self.artefacts.insert(artefact, a);
+        self.board = Some(board);
+        Ok(())
+    }
+
+    // ========================================================================
+    // HELPERS
+    // ========================================================================
+
+    /// Apply the speed tax at the current slot and deduct the fee from the charge.
+    fn charge_fee(&self, charge: &mut Charge, base_fee: Gluon) -> Result<Gluon, SimError> {
+        let fee = base_fee.saturating_mul(self.speed_multiplier(charge));
+        deduct_fee(charge, fee)?;
+        Ok(fee)
+    }
+
+    /// Speed tax multiplier at the current slot; stamps the charge.
+    fn speed_multiplier(&self, charge: &mut Charge) -> u64 {
+        let now = self.clock.slot();
+        let multiplier = fee_multiplier(charge, now);
+        charge.timestamp = now;
+        multiplier
+    }
+
+    fn wallet(&self, key: &WalletKey) -> Result<Wallet, SimError> {
+        self.wallets
+            .get(key)
+            .copied()
+            .ok_or(SimError::UninitializedAccount)
+    }
+
+    fn charge_account(&self, key: &ChargeKey) -> Result<Charge, SimError> {
+        self.charges
+            .get(key)
+            .copied()
+            .ok_or(SimError::UninitializedAccount)
+    }
+
+    fn artefact(&self, index: ElementIndex) -> Result<Artefact, SimError> {
+        self.artefacts
+            .get(&index)
+            .copied()
+            .ok_or(SimError::UninitializedAccount)
+    }
+
+    fn board_account(&self) -> Result<Board, SimError> {
+        self.board.ok_or(SimError::UninitializedAccount)
+    }
+
+    fn store_pair(&mut self, a: u8, ea: Element, b: u8, eb: Element) -> Result<(), SimError> {
+        let (slot_a, slot_b) = self.elements_mut(a, b)?;
+        (*slot_a, *slot_b) = (ea, eb);
+        Ok(())
+    }
+}
+
+/// One rebind step on account copies, paying the rebind fee times the speed tax `multiplier`;
+/// returns the fee.
+fn hop_into(
+    c: &mut Charge,
+    s: &mut Element,
+    d: &mut Element,
+    multiplier: u64,
+) -> Result<Gluon, SimError> {
+    if !s.coordinates.adjacent(d.coordinates) {
+        return Err(TokamakError::NotAdjacent.into());
+    }
+    if c.index != s.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
+
+    let fee = rebind_fee(c, s, d).saturating_mul(multiplier);
+    deduct_fee(c, fee)?;
+    action::rebind(c, s, d)?;
+    // Outward fees stay with the departing element; inward fees fund the deeper one
+    if s.index > d.index {
+        s.pot += fee;
+    } else {
+        d.pot += fee;
+    }
+    Ok(fee)
+}
+
+/// Deduct `fee` from the charge balance, failing if it cannot cover it.
+fn deduct_fee(charge: &mut Charge, fee: Gluon) -> Result<(), SimError> {
+    charge.balance = charge
+        .balance
+        .checked_sub(fee)
+        .ok_or(SimError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Overload rules and effects on account copies.
+fn overload_into(
+    c: &mut Charge,
+    t: &mut Element,
+    a: &mut Artefact,
+    board: &mut Board,
+) -> Result<(), SimError> {
+    if t.curve.saturation < MAX_SATURATION {
+        return Err(TokamakError::BelowOverloadThreshold.into());
+    }
+    if c.index != t.index {
+        return Err(TokamakError::ChargeNotInElement.into());
+    }
+    if a.index != t.index {
+        return Err(TokamakError::ArtefactMismatch.into());
+    }
+
+    action::overload(c, t, a, board)?;
+    Ok(())
+}
+
+/// Signer must be the stored authority.
+fn authorize(signer: AddressBytes, authority: &AddressBytes) -> Result<(), SimError> {
+    if signer != *authority {
+        return Err(SimError::IncorrectAuthority);
+    }
+    Ok(())
+}
+
+/// Charge and wallet must belong to the same player and mint.
+fn pair(charge: &Charge, wallet: &Wallet) -> Result<(), SimError> {
+    if charge.authority != wallet.authority {
+        return Err(SimError::IncorrectAuthority);
+    }
+    if charge.mint != wallet.mint {
+        return Err(TokamakError::MintMismatch.into());
+    }
+    Ok(())
+}
+
+/// Wallet must be denominated in `mint`, which must be a supported stablecoin.
+fn custody(wallet: &Wallet, mint: &AddressBytes) -> Result<(), SimError> {
+    if wallet.mint != *mint {
+        return Err(TokamakError::MintMismatch.into());
+    }
+    if !addresses::supported(mint) {
+        return Err(TokamakError::UnsupportedMint.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! In-memory game state: every account the program would hold, keyed by identity.
+
+use std::collections::BTreeMap;
+
+use bytemuck::Zeroable;
+
+use nucleus::{
+    board::{Artefact, Board, Element},
+    consts::MAX_ATOMIC_NUMBER,
+    player::{Charge, Wallet},
+    types::{AddressBytes, ElementIndex, Gluon},
+};
+
+use crate::{Clock, ManualClock, SimError};
+
+/// Wallet PDA identity: `[authority, mint]`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct WalletKey {
+    pub authority: AddressBytes,
+    pub mint: AddressBytes,
+}
+
+/// Charge PDA identity: `[authority, mint, id]`, where `id` is the wallet's charge counter.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct ChargeKey {
+    pub authority: AddressBytes,
+    pub mint: AddressBytes,
+    pub id: u32,
+}
+
+impl WalletKey {
+    /// Key of this wallet's charge number `id`.
+    pub fn charge(&self, id: u32) -> ChargeKey {
+        ChargeKey {
+            authority: self.authority,
+            mint: self.mint,
+            id,
+        }
+    }
+}
+
+/// Whole game without Solana: Board, 26 Elements, Artefacts, Wallets, Charges and vaults.
+/// Instructions are applied with [`GameState::apply`]; time comes from the injected clock.
+#[derive(Clone, Debug)]
+pub struct GameState<C = ManualClock> {
+    /// Slot source for the speed tax.
+    pub clock: C,
+    pub board: Option<Board>,
+    /// Live generation of each element, indexed by `atomic - 1`.
+    pub elements: [Option<Element>; MAX_ATOMIC_NUMBER as usize],
+    pub artefacts: BTreeMap<ElementIndex, Artefact>,
+    pub wallets: BTreeMap<WalletKey, Wallet>,
+    pub charges: BTreeMap<ChargeKey, Charge>,
+    /// Token balance of each mint's vault.
+    pub vaults: BTreeMap<AddressBytes, Gluon>,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new(ManualClock::default())
+    }
+}
+
+impl<C: Clock> GameState<C> {
+    /// Empty state (nothing initialized) reading time from `clock`.
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            board: None,
+            elements: [None; MAX_ATOMIC_NUMBER as usize],
+            artefacts: BTreeMap::new(),
+            wallets: BTreeMap::new(),
+            charges: BTreeMap::new(),
+            vaults: BTreeMap::new(),
+        }
+    }
+
+    /// State after InitBoard and InitElement for all 26 elements.
+    pub fn genesis(clock: C) -> Self {
+        let mut state = Self::new(clock);
+        state.board = Some(Board::zeroed());
+        for (slot, atomic) in state.elements.iter_mut().zip(1..) {
+            *slot = Element::genesis(atomic);
+        }
+        state
+    }
+
+    /// Live element with atomic number `atomic`, if initialized.
+    pub fn element(&self, atomic: u8) -> Option<&Element> {
+        let slot = (atomic as usize).checked_sub(1)?;
+        self.elements.get(slot)?.as_ref()
+    }
+
+    pub(crate) fn element_mut(&mut self, atomic: u8) -> Result<&mut Element, SimError> {
+        (atomic as usize)
+            .checked_sub(1)
+            .and_then(|slot| self.elements.get_mut(slot)?.as_mut())
+            .ok_or(SimError::UninitializedAccount)
+    }
+
+    /// Two distinct live elements, mutably.
+    pub(crate) fn elements_mut(
+        &mut self,
+        a: u8,
+        b: u8,
+    ) -> Result<(&mut Element, &mut Element), SimError> {
+        let slot = |atomic: u8| (atomic as usize).wrapping_sub(1);
+        match self.elements.get_disjoint_mut([slot(a), slot(b)]) {
+            Ok([Some(a), Some(b)]) => Ok((a, b)),
+            Err(core::slice::GetDisjointMutError::OverlappingIndices) => {
+                Err(SimError::AccountBorrowFailed)
+            }
+            _ => Err(SimError::UninitializedAccount),
+        }
+    }
+}
@@ -0,0 +1,1122 @@
+use bytemuck::bytes_of;
+use nucleus::{
+    consts::{MAX_ATOMIC_NUMBER, MAX_DELTA_TIMESTAMP, MAX_SATURATION},
+    error::TokamakError,
+    fees::{bind_fee, fee_multiplier},
+    guards::Guards,
+    quote::share_for_deposit,
+    topology::{self, DIAMETER},
+    types::{AddressBytes, Gluon, Q824},
+};
+use program::addresses::{USDC_MINT, USDT_MINT};
+
+use crate::{ChargeKey, GameState, Instruction::*, ManualClock, SimError, WalletKey};
+
+const ALICE: AddressBytes = [1; 32];
+const BOB: AddressBytes = [2; 32];
+
+// Helpers
+/// `n` whole Gluon.
+fn gluon(n: u64) -> Gluon {
+    Gluon(n * Gluon::UNIT)
+}
+
+fn wallet(authority: AddressBytes) -> WalletKey {
+    WalletKey {
+        authority,
+        mint: USDC_MINT,
+    }
+}
+
+/// Infuse `amount`, open charge 0 and fund it fully.
+fn funded_charge(state: &mut GameState, signer: AddressBytes, amount: Gluon) -> ChargeKey {
+    let wallet = wallet(signer);
+    let mint = USDC_MINT;
+    state.apply(InitWallet { signer, mint }).unwrap();
+    state
+        .apply(Infuse {
+            signer,
+            wallet,
+            mint,
+            amount,
+        })
+        .unwrap();
+    state.apply(InitCharge { signer, wallet }).unwrap();
+    let charge = wallet.charge(0);
+    state
+        .apply(Charge {
+            signer,
+            charge,
+            wallet,
+            amount,
+        })
+        .unwrap();
+    charge
+}
+
+/// Every Gluon in the game is backed by the vault.
+fn assert_backed(state: &GameState) {
+    let gluon = state.wallets.values().map(|w| w.balance).sum::<Gluon>()
+        + state.charges.values().map(|c| c.balance).sum::<Gluon>()
+        + state
+            .elements
+            .iter()
+            .flatten()
+            .map(|e| e.pot)
+            .sum::<Gluon>()
+        + state.artefacts.values().map(|a| a.pot).sum::<Gluon>()
+        + state.board.unwrap().quantum_pocket;
+    assert_eq!(gluon, state.vaults.values().sum::<Gluon>());
+}
+
+/// Board totals equal live curves plus outstanding artefacts.
+fn assert_board_totals(state: &GameState) {
+    let board = state.board.unwrap();
+    let live = state.elements.iter().flatten();
+    let tvl = live.clone().map(|e| e.curve.tvl).sum::<Gluon>()
+        + state.artefacts.values().map(|a| a.tvl).sum::<Gluon>();
+    let count = live.map(|e| e.charges).sum::<u32>()
+        + state.artefacts.values().map(|a| a.charges).sum::<u32>();
+    assert_eq!((board.tvl, board.charge_count), (tvl, count));
+}
+
+// === Initialization Tests ===
+
+#[test]
+fn genesis_matches_init_instructions() {
+    let mut state = GameState::default();
+    state.apply(InitBoard).unwrap();
+    for atomic in 1..=MAX_ATOMIC_NUMBER as u8 {
+        state.apply(InitElement { atomic }).unwrap();
+    }
+    let genesis = GameState::genesis(ManualClock::default());
+    for atomic in 1..=MAX_ATOMIC_NUMBER as u8 {
+        let (a, b) = (
+            state.element(atomic).unwrap(),
+            genesis.element(atomic).unwrap(),
+        );
+        assert_eq!((a.index, a.curve.capacity), (b.index, b.curve.capacity));
+    }
+}
+
+#[test]
+fn init_rejects_duplicates_and_off_board_elements() {
+    let mut state = GameState::genesis(ManualClock::default());
+    assert_eq!(
+        state.apply(InitBoard),
+        Err(SimError::AccountAlreadyInitialized)
+    );
+    assert_eq!(
+        state.apply(InitElement { atomic: 1 }),
+        Err(SimError::AccountAlreadyInitialized)
+    );
+    assert_eq!(
+        state.apply(InitElement { atomic: 27 }),
+        Err(SimError::InvalidArgument)
+    );
+    state.apply(InitArtefact { atomic: 1 }).unwrap();
+    assert_eq!(
+        state.apply(InitArtefact { atomic: 1 }),
+        Err(SimError::AccountAlreadyInitialized)
+    );
+}
+
+#[test]
+fn init_wallet_rejects_unsupported_mint() {
+    let mut state = GameState::default();
+    let mint = [9; 32];
+    assert_eq!(
+        state.apply(InitWallet {
+            signer: ALICE,
+            mint
+        }),
+        Err(TokamakError::UnsupportedMint.into())
+    );
+}
+
+// === Validation Tests ===
+
+#[test]
+fn custody_rejects_foreign_signer_and_mint() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let charge = funded_charge(&mut state, ALICE, gluon(10));
+    let wallet = wallet(ALICE);
+    let ix = Discharge {
+        signer: BOB,
+        charge,
+        wallet,
+        amount: gluon(1),
+    };
+    assert_eq!(state.apply(ix), Err(SimError::IncorrectAuthority));
+    let ix = Infuse {
+        signer: ALICE,
+        wallet,
+        mint: USDT_MINT,
+        amount: gluon(1),
+    };
+    assert_eq!(state.apply(ix), Err(TokamakError::MintMismatch.into()));
+}
+
+#[test]
+fn rejected_instruction_leaves_state_untouched() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let charge = funded_charge(&mut state, ALICE, gluon(1_000));
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+
+    // Interior element: rejected after the charge and element were loaded
+    let before = state.charges[&charge];
+    let ix = Rebind {
+        signer: ALICE,
+        charge,
+        src: 1,
+        dst: 26,
+        guards: Guards::NONE,
+    };
+    assert_eq!(state.apply(ix), Err(TokamakError::NotAdjacent.into()));
+    assert_eq!(state.charges[&charge].timestamp, before.timestamp);
+    assert_eq!(state.charges[&charge].balance, before.balance);
+
+    // Fee larger than the balance fails without touching the timestamp
+    state.clock.advance(7);
+    let ix = Vent {
+        signer: ALICE,
+        charge,
+        target: 1,
+        amount: before.balance - Gluon(1),
+    };
+    state.apply(ix).unwrap();
+    let ix = Unbind {
+        signer: ALICE,
+        charge,
+        src: 1,
+        guards: Guards::NONE,
+    };
+    assert_eq!(state.apply(ix), Err(SimError::ArithmeticOverflow));
+    assert_eq!(
+        state.charges[&charge].index,
+        state.element(1).unwrap().index
+    );
+    assert_board_totals(&state);
+}
+
+#[test]
+fn charge_rejects_bound_charge() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let charge = funded_charge(&mut state, ALICE, gluon(1_000));
+    let wallet = wallet(ALICE);
+    let mint = USDC_MINT;
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    state
+        .apply(Infuse {
+            signer: ALICE,
+            wallet,
+            mint,
+            amount: gluon(1_000),
+        })
+        .unwrap();
+
+    // A bound balance is its stake on the curve: topping it up would let Unbind withdraw
+    // Gluon the curve never held
+    let ix = Charge {
+        signer: ALICE,
+        charge,
+        wallet,
+        amount: gluon(1_000),
+    };
+    assert_eq!(state.apply(ix), Err(TokamakError::ChargeStillBound.into()));
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    state
+        .apply(Unbind {
+            signer: ALICE,
+            charge,
+            src: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    assert_backed(&state);
+    assert_board_totals(&state);
+}
+
+#[test]
+fn same_element_twice_is_rejected() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let charge = funded_charge(&mut state, ALICE, gluon(1_000));
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    let ix = Compress {
+        signer: ALICE,
+        charge,
+        src: 1,
+        dst: 1,
+        guards: Guards::NONE,
+    };
+    assert_eq!(state.apply(ix), Err(SimError::AccountBorrowFailed));
+}
+
+// === Clock Tests ===
+
+#[test]
+fn speed_tax_follows_injected_clock() {
+    let fee_after = |wait: u64| {
+        let mut state = GameState::genesis(ManualClock::default());
+        let charge = funded_charge(&mut state, ALICE, gluon(1_000));
+        state.clock.advance(MAX_DELTA_TIMESTAMP);
+        state
+            .apply(Bind {
+                signer: ALICE,
+                charge,
+                dst: 1,
+                guards: Guards::NONE,
+            })
+            .unwrap();
+        state.clock.advance(wait);
+        let before = state.charges[&charge].balance;
+        state
+            .apply(Rebind {
+                signer: ALICE,
+                charge,
+                src: 1,
+                dst: 2,
+                guards: Guards::NONE,
+            })
+            .unwrap();
+        before - state.charges[&charge].balance
+    };
+    assert!(fee_after(0) > fee_after(MAX_DELTA_TIMESTAMP));
+
+    // Any closure can drive time
+    let mut state = GameState::genesis(|| 42);
+    let charge = funded_charge_with(&mut state);
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    assert_eq!(state.charges[&charge].timestamp, 42);
+}
+
+/// Infuse `amount` into an existing wallet, open charge `id` and fund it fully.
+fn funded_charge_id(
+    state: &mut GameState,
+    signer: AddressBytes,
+    id: u32,
+    amount: Gluon,
+) -> ChargeKey {
+    let (mint, wallet) = (USDC_MINT, wallet(signer));
+    state
+        .apply(Infuse {
+            signer,
+            wallet,
+            mint,
+            amount,
+        })
+        .unwrap();
+    state.apply(InitCharge { signer, wallet }).unwrap();
+    let charge = wallet.charge(id);
+    state
+        .apply(Charge {
+            signer,
+            charge,
+            wallet,
+            amount,
+        })
+        .unwrap();
+    charge
+}
+
+fn funded_charge_with<C: crate::Clock>(state: &mut GameState<C>) -> ChargeKey {
+    let (signer, mint, wallet) = (ALICE, USDC_MINT, wallet(ALICE));
+    let amount = gluon(1_000);
+    state.apply(InitWallet { signer, mint }).unwrap();
+    state
+        .apply(Infuse {
+            signer,
+            wallet,
+            mint,
+            amount,
+        })
+        .unwrap();
+    state.apply(InitCharge { signer, wallet }).unwrap();
+    let charge = wallet.charge(0);
+    state
+        .apply(Charge {
+            signer,
+            charge,
+            wallet,
+            amount,
+        })
+        .unwrap();
+    charge
+}
+
+// === Compound Tests ===
+
+#[test]
+fn bind_and_overload_race_has_one_winner() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let alice = funded_charge(&mut state, ALICE, gluon(60_000));
+    let bob = funded_charge(&mut state, BOB, gluon(60_000));
+    state.apply(InitArtefact { atomic: 1 }).unwrap();
+    let artefact = state.element(1).unwrap().index;
+
+    // Too small to saturate: nothing is bound, not even the charge
+    let small = funded_charge_id(&mut state, ALICE, 1, gluon(100));
+    let ix = BindAndOverload {
+        signer: ALICE,
+        charge: small,
+        dst: 1,
+        artefact,
+        guards: Guards::NONE,
+    };
+    let before = (state.charges[&small], state.element(1).unwrap().curve.tvl);
+    assert_eq!(
+        state.apply(ix),
+        Err(TokamakError::BelowOverloadThreshold.into())
+    );
+    assert_eq!(state.charges[&small].index, before.0.index);
+    assert_eq!(state.element(1).unwrap().curve.tvl, before.1);
+
+    // Both submit against the same generation; the first one resets it
+    let ix = |signer, charge| BindAndOverload {
+        signer,
+        charge,
+        dst: 1,
+        artefact,
+        guards: Guards::NONE,
+    };
+    state.apply(ix(ALICE, alice)).unwrap();
+    assert_eq!(state.element(1).unwrap().index.generation(), 1);
+    assert_eq!(state.charges[&alice].index, state.element(1).unwrap().index);
+
+    // The loser fails cleanly and stays off the board
+    let before = state.charges[&bob];
+    assert_eq!(
+        state.apply(ix(BOB, bob)),
+        Err(TokamakError::ArtefactMismatch.into())
+    );
+    assert_eq!(state.charges[&bob].balance, before.balance);
+    assert!(state.charges[&bob].index.is_zero());
+    assert_backed(&state);
+    assert_board_totals(&state);
+}
+
+#[test]
+fn rebind_and_overload_matches_separate_instructions() {
+    let run = |compound: bool| {
+        let mut state = GameState::genesis(ManualClock::default());
+        let alice = funded_charge(&mut state, ALICE, gluon(1_000));
+        let bob = funded_charge(&mut state, BOB, gluon(200_000));
+        for (signer, charge) in [(ALICE, alice), (BOB, bob)] {
+            let dst = if signer == ALICE { 2 } else { 1 };
+            state
+                .apply(Bind {
+                    signer,
+                    charge,
+                    dst,
+                    guards: Guards::NONE,
+                })
+                .unwrap();
+        }
+        state.clock.advance(MAX_DELTA_TIMESTAMP);
+        state.apply(InitArtefact { atomic: 2 }).unwrap();
+        let artefact = state.element(2).unwrap().index;
+        let (signer, charge, src, dst) = (BOB, bob, 1, 2);
+        if compound {
+            let ix = RebindAndOverload {
+                signer,
+                charge,
+                src,
+                dst,
+                artefact,
+                guards: Guards::NONE,
+            };
+            state.apply(ix).unwrap();
+        } else {
+            state
+                .apply(Rebind {
+                    signer,
+                    charge,
+                    src,
+                    dst,
+                    guards: Guards::NONE,
+                })
+                .unwrap();
+            let ix = Overload {
+                signer,
+                charge,
+                target: dst,
+                artefact,
+            };
+            state.apply(ix).unwrap();
+        }
+        assert_backed(&state);
+        assert_board_totals(&state);
+        (state.charges[&bob].balance, state.artefacts[&artefact].pot)
+    };
+    assert_eq!(run(true), run(false));
+}
+
+// === Path Tests ===
+
+/// Path from `src` to `dst` in the simulator's zero-terminated form.
+fn shortest_path(src: u8, dst: u8) -> [u8; DIAMETER as usize] {
+    let mut path = [0; DIAMETER as usize];
+    for (hop, atomic) in path.iter_mut().zip(topology::path(src as u64, dst as u64)) {
+        *hop = atomic as u8;
+    }
+    path
+}
+
+#[test]
+fn rebind_path_costs_rested_single_rebinds() {
+    let (src, dst) = (1, MAX_ATOMIC_NUMBER as u8);
+    let path = shortest_path(src, dst);
+    assert_eq!(
+        path.iter().filter(|&&hop| hop != 0).count(),
+        topology::distance(src as u64, dst as u64) as usize
+    );
+
+    let run = |single: bool| {
+        let mut state = GameState::genesis(ManualClock::default());
+        let signer = ALICE;
+        let charge = funded_charge(&mut state, signer, gluon(1_000));
+        state
+            .apply(Bind {
+                signer,
+                charge,
+                dst: src,
+                guards: Guards::NONE,
+            })
+            .unwrap();
+        state.clock.advance(MAX_DELTA_TIMESTAMP);
+        if single {
+            // Every hop waits out the speed tax
+            let mut at = src;
+            for &hop in path.iter().take_while(|&&hop| hop != 0) {
+                let ix = Rebind {
+                    signer,
+                    charge,
+                    src: at,
+                    dst: hop,
+                    guards: Guards::NONE,
+                };
+                state.apply(ix).unwrap();
+                state.clock.advance(MAX_DELTA_TIMESTAMP);
+                at = hop;
+            }
+        } else {
+            let ix = RebindPath {
+                signer,
+                charge,
+                src,
+                path,
+                guards: Guards::NONE,
+            };
+            state.apply(ix).unwrap();
+        }
+        assert_backed(&state);
+        assert_board_totals(&state);
+        let c = state.charges[&charge];
+        let elements: Vec<u8> = state
+            .elements
+            .iter()
+            .flatten()
+            .flat_map(|e| bytes_of(e).to_vec())
+            .collect();
+        (c.balance, c.share, c.index, elements)
+    };
+    let path_result = run(false);
+    assert_eq!(
+        path_result.2,
+        GameState::genesis(ManualClock::default())
+            .element(dst)
+            .unwrap()
+            .index
+    );
+    assert_eq!(path_result, run(true));
+}
+
+#[test]
+fn rebind_path_is_all_or_nothing() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let signer = ALICE;
+    let charge = funded_charge(&mut state, signer, gluon(1_000));
+    state
+        .apply(Bind {
+            signer,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+
+    let mut bad_tail = shortest_path(1, MAX_ATOMIC_NUMBER as u8);
+    bad_tail[1] = 12;
+    let mut repeated = shortest_path(1, MAX_ATOMIC_NUMBER as u8);
+    repeated[1] = repeated[0];
+    let cases = [
+        ([0; DIAMETER as usize], SimError::InvalidArgument),
+        (bad_tail, TokamakError::NotAdjacent.into()),
+        (repeated, TokamakError::NotAdjacent.into()),
+    ];
+    for (path, err) in cases {
+        let before = (state.charges[&charge], state.element(1).unwrap().curve.tvl);
+        let ix = RebindPath {
+            signer,
+            charge,
+            src: 1,
+            path,
+            guards: Guards::NONE,
+        };
+        assert_eq!(state.apply(ix), Err(err), "{path:?}");
+        let after = &state.charges[&charge];
+        assert_eq!(
+            (after.balance, after.index),
+            (before.0.balance, before.0.index)
+        );
+        assert_eq!(state.element(1).unwrap().curve.tvl, before.1);
+    }
+    assert_backed(&state);
+    assert_board_totals(&state);
+}
+
+// === Guard Tests ===
+
+#[test]
+fn guards_reject_front_run_bind() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let alice = funded_charge(&mut state, ALICE, gluon(1_000));
+    let bob = funded_charge(&mut state, BOB, gluon(50_000));
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+
+    // Alice quotes her bind into H against the board as she sees it
+    let c = state.charges[&alice];
+    let element = state.element(1).unwrap();
+    let fee = bind_fee(&c, element).saturating_mul(fee_multiplier(&c, state.clock.slot));
+    let share = share_for_deposit(element, c.balance - fee).unwrap();
+    let quoted = Guards {
+        max_fee: fee,
+        deadline_slot: state.clock.slot,
+        min_share: share,
+        _pad: 0,
+    };
+    let bind = |guards| Bind {
+        signer: ALICE,
+        charge: alice,
+        dst: 1,
+        guards,
+    };
+
+    // Unchallenged, the quote holds exactly
+    let mut unchallenged = state.clone();
+    unchallenged.apply(bind(quoted)).unwrap();
+    assert_eq!(unchallenged.charges[&alice].share, share);
+
+    // Bob binds first: H is fuller, so Alice would pay more for less
+    state
+        .apply(Bind {
+            signer: BOB,
+            charge: bob,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    let fee_only = Guards {
+        min_share: Q824::ZERO,
+        ..quoted
+    };
+    let share_only = Guards {
+        max_fee: Gluon::MAX,
+        ..quoted
+    };
+    let cases = [
+        (fee_only, TokamakError::FeeAboveLimit),
+        (share_only, TokamakError::ShareBelowLimit),
+    ];
+    for (guards, err) in cases {
+        let before = state.charges[&alice];
+        assert_eq!(state.apply(bind(guards)), Err(err.into()));
+        assert_eq!(state.charges[&alice].balance, before.balance);
+        assert!(state.charges[&alice].index.is_zero());
+    }
+    state.apply(bind(Guards::NONE)).unwrap();
+    assert!(state.charges[&alice].share < share);
+}
+
+#[test]
+fn guards_reject_moves_past_deadline() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let signer = ALICE;
+    let charge = funded_charge(&mut state, signer, gluon(1_000));
+    state
+        .apply(Bind {
+            signer,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    let guards = Guards {
+        deadline_slot: state.clock.slot + 10,
+        ..Guards::NONE
+    };
+    let unbind = Unbind {
+        signer,
+        charge,
+        src: 1,
+        guards,
+    };
+
+    // The deadline slot itself is still in time
+    let mut in_time = state.clone();
+    in_time.clock.advance(10);
+    in_time.apply(unbind).unwrap();
+    assert!(in_time.charges[&charge].index.is_zero());
+
+    state.clock.advance(11);
+    let before = state.charges[&charge];
+    assert_eq!(
+        state.apply(unbind),
+        Err(TokamakError::DeadlinePassed.into())
+    );
+    assert_eq!(state.charges[&charge].index, before.index);
+}
+
+#[test]
+fn guards_bound_rebind_path_as_a_whole() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let signer = ALICE;
+    let charge = funded_charge(&mut state, signer, gluon(1_000));
+    state
+        .apply(Bind {
+            signer,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    let path = shortest_path(1, MAX_ATOMIC_NUMBER as u8);
+    let rebind_path = |guards| RebindPath {
+        signer,
+        charge,
+        src: 1,
+        path,
+        guards,
+    };
+
+    // Quote the path by running it unguarded
+    let mut unguarded = state.clone();
+    unguarded.apply(rebind_path(Guards::NONE)).unwrap();
+    let (before, after) = (state.charges[&charge], unguarded.charges[&charge]);
+    let fee = before.balance - after.balance;
+    let quoted = Guards {
+        max_fee: fee,
+        deadline_slot: state.clock.slot,
+        min_share: after.share,
+        _pad: 0,
+    };
+    let mut in_limits = state.clone();
+    in_limits.apply(rebind_path(quoted)).unwrap();
+    assert_eq!(in_limits.charges[&charge].share, after.share);
+
+    // One unit short of the total is still more than any single hop pays
+    let cases = [
+        (
+            Guards {
+                max_fee: fee - Gluon(1),
+                ..quoted
+            },
+            TokamakError::FeeAboveLimit,
+        ),
+        (
+            Guards {
+                min_share: Q824(after.share.0 + 1),
+                ..quoted
+            },
+            TokamakError::ShareBelowLimit,
+        ),
+        (
+            Guards {
+                deadline_slot: state.clock.slot - 1,
+                ..quoted
+            },
+            TokamakError::DeadlinePassed,
+        ),
+    ];
+    for (guards, err) in cases {
+        assert_eq!(state.apply(rebind_path(guards)), Err(err.into()));
+        let c = state.charges[&charge];
+        assert_eq!((c.balance, c.index), (before.balance, before.index));
+    }
+}
+
+#[test]
+fn guards_bound_the_move_of_bind_and_overload() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let alice = funded_charge(&mut state, ALICE, gluon(60_000));
+    state.apply(InitArtefact { atomic: 1 }).unwrap();
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    let artefact = state.element(1).unwrap().index;
+
+    // The guards quote the bind; the overload's reward comes on top of it
+    let c = state.charges[&alice];
+    let element = state.element(1).unwrap();
+    let fee = bind_fee(&c, element).saturating_mul(fee_multiplier(&c, state.clock.slot));
+    let share = share_for_deposit(element, c.balance - fee).unwrap();
+    let quoted = Guards {
+        max_fee: fee,
+        deadline_slot: state.clock.slot,
+        min_share: share,
+        _pad: 0,
+    };
+    let bind_and_overload = |guards| BindAndOverload {
+        signer: ALICE,
+        charge: alice,
+        dst: 1,
+        artefact,
+        guards,
+    };
+
+    let cases = [
+        (
+            Guards {
+                max_fee: fee - Gluon(1),
+                ..quoted
+            },
+            TokamakError::FeeAboveLimit,
+        ),
+        (
+            Guards {
+                min_share: Q824(share.0 + 1),
+                ..quoted
+            },
+            TokamakError::ShareBelowLimit,
+        ),
+        (
+            Guards {
+                deadline_slot: state.clock.slot - 1,
+                ..quoted
+            },
+            TokamakError::DeadlinePassed,
+        ),
+    ];
+    for (guards, err) in cases {
+        assert_eq!(state.apply(bind_and_overload(guards)), Err(err.into()));
+        assert!(state.charges[&alice].index.is_zero());
+        assert_eq!(state.element(1).unwrap().index, artefact);
+    }
+    state.apply(bind_and_overload(quoted)).unwrap();
+    assert_eq!(state.element(1).unwrap().index.generation(), 1);
+}
+
+// === Split Tests ===
+
+/// Bind Alice's funded charge into H and split `amount` off into her next charge.
+fn split_in_h(state: &mut GameState, amount: Gluon) -> (ChargeKey, ChargeKey) {
+    let charge = funded_charge(state, ALICE, gluon(3_000));
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    state
+        .apply(Split {
+            signer: ALICE,
+            charge,
+            wallet: wallet(ALICE),
+            target: 1,
+            amount,
+        })
+        .unwrap();
+    (charge, wallet(ALICE).charge(1))
+}
+
+#[test]
+fn split_parts_leave_like_the_whole() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let mut whole = state.clone();
+    let charge = funded_charge(&mut whole, ALICE, gluon(3_000));
+    let bind = Bind {
+        signer: ALICE,
+        charge,
+        dst: 1,
+        guards: Guards::NONE,
+    };
+    whole.apply(bind).unwrap();
+    let (share, curve) = (
+        whole.charges[&charge].share,
+        whole.element(1).unwrap().curve,
+    );
+
+    let (rest, part) = split_in_h(&mut state, gluon(1_000));
+    let (r, p) = (state.charges[&rest], state.charges[&part]);
+    assert_eq!(r.share + p.share, share);
+    assert_eq!(
+        (r.index, p.index),
+        (p.index, state.element(1).unwrap().index)
+    );
+    let after = state.element(1).unwrap().curve;
+    assert_eq!((after.tvl, after.saturation), (curve.tvl, curve.saturation));
+    assert_eq!(state.board.unwrap().charge_count, 2);
+    assert_board_totals(&state);
+
+    // Both parts unbind on their own and take their whole stake off the curve: only the
+    // unbind fees, already in the pot, stay behind as curve residue
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    let pot = state.element(1).unwrap().pot;
+    for charge in [rest, part] {
+        let unbind = Unbind {
+            signer: ALICE,
+            charge,
+            src: 1,
+            guards: Guards::NONE,
+        };
+        state.apply(unbind).unwrap();
+    }
+    let h = state.element(1).unwrap();
+    assert_eq!((h.curve.tvl, h.curve.shares), (h.pot - pot, Q824::ZERO));
+    assert_eq!(h.charges, 0);
+    assert_backed(&state);
+    assert_board_totals(&state);
+}
+
+#[test]
+fn merge_undoes_split_on_one_element_only() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let (charge, part) = split_in_h(&mut state, gluon(1_000));
+    let bob = funded_charge(&mut state, BOB, gluon(1_000));
+    let merge = |charge, other| Merge {
+        signer: ALICE,
+        charge,
+        other,
+        target: 1,
+    };
+
+    assert_eq!(
+        state.apply(merge(charge, charge)),
+        Err(SimError::AccountBorrowFailed)
+    );
+    assert_eq!(
+        state.apply(merge(charge, bob)),
+        Err(SimError::IncorrectAuthority)
+    );
+    // Split parts are whole charges: one that moved away no longer merges
+    let mut moved = state.clone();
+    let rebind = Rebind {
+        signer: ALICE,
+        charge: part,
+        src: 1,
+        dst: topology::path(1, 2).next().unwrap() as u8,
+        guards: Guards::NONE,
+    };
+    moved.apply(rebind).unwrap();
+    assert_eq!(
+        moved.apply(merge(charge, part)),
+        Err(TokamakError::ChargeNotInElement.into())
+    );
+
+    let (c, p) = (state.charges[&charge], state.charges[&part]);
+    let whole = (c.balance + p.balance, c.share + p.share);
+    state.apply(merge(charge, part)).unwrap();
+    let (c, p) = (state.charges[&charge], state.charges[&part]);
+    assert_eq!((c.balance, c.share), whole);
+    assert_eq!((p.balance, p.share), (Gluon::ZERO, Q824::ZERO));
+    assert!(p.index.is_zero());
+    assert_eq!(state.board.unwrap().charge_count, 1);
+    assert_board_totals(&state);
+}
+
+// === Close Tests ===
+
+#[test]
+fn closed_ids_are_skipped_until_the_wallet_closes() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let charge = funded_charge(&mut state, ALICE, gluon(1_000));
+    let (signer, wallet, mint) = (ALICE, wallet(ALICE), USDC_MINT);
+    let close = |charge| CloseCharge {
+        signer,
+        charge,
+        wallet,
+    };
+    state
+        .apply(Bind {
+            signer,
+            charge,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+
+    // Only empty, unbound charges and empty wallets without open charges close
+    assert_eq!(
+        state.apply(close(charge)),
+        Err(TokamakError::ChargeStillBound.into())
+    );
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    state
+        .apply(Unbind {
+            signer,
+            charge,
+            src: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    assert_eq!(
+        state.apply(close(charge)),
+        Err(TokamakError::AccountNotEmpty.into())
+    );
+    let amount = state.charges[&charge].balance;
+    state
+        .apply(Discharge {
+            signer,
+            charge,
+            wallet,
+            amount,
+        })
+        .unwrap();
+    assert_eq!(
+        state.apply(CloseCharge {
+            signer: BOB,
+            charge,
+            wallet,
+        }),
+        Err(SimError::IncorrectAuthority)
+    );
+    assert_eq!(
+        state.apply(CloseWallet { signer, wallet }),
+        Err(TokamakError::AccountNotEmpty.into())
+    );
+    state.apply(close(charge)).unwrap();
+    assert!(!state.charges.contains_key(&charge));
+    let w = state.wallets[&wallet];
+    assert_eq!((w.charges, w.open), (1, 0));
+
+    // A closed id is never reissued while the wallet lives
+    state.apply(InitCharge { signer, wallet }).unwrap();
+    assert!(state.charges.contains_key(&wallet.charge(1)));
+    state.apply(close(wallet.charge(1))).unwrap();
+
+    // Once the wallet closes too, a new one counts from 0 again
+    assert_eq!(
+        state.apply(CloseWallet { signer, wallet }),
+        Err(TokamakError::AccountNotEmpty.into())
+    );
+    let amount = state.wallets[&wallet].balance;
+    state
+        .apply(Extract {
+            signer,
+            wallet,
+            mint,
+            amount,
+        })
+        .unwrap();
+    state.apply(CloseWallet { signer, wallet }).unwrap();
+    assert!(!state.wallets.contains_key(&wallet));
+    state.apply(InitWallet { signer, mint }).unwrap();
+    state.apply(InitCharge { signer, wallet }).unwrap();
+    assert!(state.charges.contains_key(&wallet.charge(0)));
+    assert_backed(&state);
+    assert_board_totals(&state);
+}
+
+// === Full Game Tests ===
+
+#[test]
+fn reset_cycle_keeps_value_backed_and_board_exact() {
+    let mut state = GameState::genesis(ManualClock::default());
+    let alice = funded_charge(&mut state, ALICE, gluon(1_000));
+    let bob = funded_charge(&mut state, BOB, gluon(50_000));
+
+    state
+        .apply(Bind {
+            signer: ALICE,
+            charge: alice,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    state.clock.advance(MAX_DELTA_TIMESTAMP);
+    state
+        .apply(Bind {
+            signer: BOB,
+            charge: bob,
+            dst: 1,
+            guards: Guards::NONE,
+        })
+        .unwrap();
+    assert_eq!(state.element(1).unwrap().curve.saturation, MAX_SATURATION);
+    assert_board_totals(&state);
+
+    let artefact = state.element(1).unwrap().index;
+    let ix = Overload {
+        signer: BOB,
+        charge: bob,
+        target: 1,
+        artefact,
+    };
+    assert_eq!(state.apply(ix), Err(SimError::UninitializedAccount));
+    state.apply(InitArtefact { atomic: 1 }).unwrap();
+    state.apply(ix).unwrap();
+    assert_eq!(state.element(1).unwrap().index.generation(), 1);
+    assert_eq!(state.charges[&bob].index, state.element(1).unwrap().index);
+    assert_eq!(state.board.unwrap().charge_count, 2);
+    assert_backed(&state);
+    assert_board_totals(&state);
+
+    // Alice was ejected: she claims her share and leaves the board
+    let ix = Claim {
+        signer: ALICE,
+        charge: alice,
+        artefact,
+    };
+    state.apply(ix).unwrap();
+    assert_eq!(state.apply(ix), Err(TokamakError::ArtefactMismatch.into()));
+    assert_eq!(state.board.unwrap().charge_count, 1);
+    assert_board_totals(&state);
+
+    // ...and cashes out everything she has left
+    let amount = state.charges[&alice].balance;
+    let wallet = wallet(ALICE);
+    let ix = Discharge {
+        signer: ALICE,
+        charge: alice,
+        wallet,
+        amount,
+    };
+    state.apply(ix).unwrap();
+    let ix = Extract {
+        signer: ALICE,
+        wallet,
+        mint: USDC_MINT,
+        amount,
+    };
+    state.apply(ix).unwrap();
+    assert_backed(&state);
+}